@@ -4,7 +4,9 @@ use crate::{
     generic_voter_weight::{GenericVoterWeight, GenericVoterWeightEnum},
     mint::MintMaxVoterWeight,
 };
-use anchor_lang::prelude::{Account, ProgramError, Pubkey};
+use anchor_lang::prelude::{
+    borsh, Account, AnchorDeserialize, AnchorSerialize, ProgramError, Pubkey,
+};
 use anchor_lang::solana_program::program_pack::Pack;
 use anchor_lang::{
     error, prelude::AccountInfo, require_eq, AccountDeserialize, AccountSerialize, Owner, Result,
@@ -13,6 +15,17 @@ use spl_governance::state::token_owner_record::get_token_owner_record_data_for_r
 use spl_governance_tools::account::get_account_data;
 use spl_token::state::Mint;
 
+/// Enum defining how the voter weight of the plugin is combined with the voter weight of the previous plugin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum ChainingMode {
+    /// The voter weight of the plugin is added to the voter weight of the previous plugin
+    #[default]
+    Add,
+    /// The voter weight of the previous plugin is used only if the voter has any voter weight granted by the plugin
+    /// and it's set to 0 otherwise
+    Gate,
+}
+
 pub trait RegistrarBase<'a> {
     fn get_realm(&'a self) -> &'a Pubkey;
     fn get_governance_program_id(&'a self) -> &'a Pubkey;
@@ -178,3 +191,68 @@ pub fn resolve_input_max_voter_weight<
 
     Ok(predecessor_generic_max_voter_weight_record)
 }
+
+/// Combines the voter weight of the plugin with the voter weight of the previous plugin using the given ChainingMode
+/// Returns None if the combined voter weight overflows
+pub fn combine_voter_weight(
+    chaining_mode: ChainingMode,
+    previous_voter_weight: u64,
+    voter_weight: u64,
+) -> Option<u64> {
+    match chaining_mode {
+        ChainingMode::Add => previous_voter_weight.checked_add(voter_weight),
+        ChainingMode::Gate => Some(if voter_weight > 0 {
+            previous_voter_weight
+        } else {
+            0
+        }),
+    }
+}
+
+/// Combines the max voter weight of the plugin with the max voter weight of the previous plugin using the given ChainingMode
+/// Returns None if the combined max voter weight overflows
+pub fn combine_max_voter_weight(
+    chaining_mode: ChainingMode,
+    previous_max_voter_weight: u64,
+    max_voter_weight: u64,
+) -> Option<u64> {
+    match chaining_mode {
+        ChainingMode::Add => previous_max_voter_weight.checked_add(max_voter_weight),
+        // Gating doesn't grant any additional voter weight
+        ChainingMode::Gate => Some(previous_max_voter_weight),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_combine_voter_weight() {
+        // Act + Assert
+        assert_eq!(combine_voter_weight(ChainingMode::Add, 100, 10), Some(110));
+        assert_eq!(combine_voter_weight(ChainingMode::Add, u64::MAX, 1), None);
+
+        assert_eq!(combine_voter_weight(ChainingMode::Gate, 100, 10), Some(100));
+        assert_eq!(combine_voter_weight(ChainingMode::Gate, 100, 0), Some(0));
+    }
+
+    #[test]
+    fn test_combine_max_voter_weight() {
+        // Act + Assert
+        assert_eq!(
+            combine_max_voter_weight(ChainingMode::Add, 1000, 10),
+            Some(1010)
+        );
+        assert_eq!(
+            combine_max_voter_weight(ChainingMode::Add, u64::MAX, 1),
+            None
+        );
+
+        assert_eq!(
+            combine_max_voter_weight(ChainingMode::Gate, 1000, 10),
+            Some(1000)
+        );
+    }
+}
@@ -89,7 +89,7 @@ pub enum CoreNftAttributeVoterError {
     #[msg("Attribute not found on asset")]
     AttributeNotFound,
 
-    #[msg("Invalid attribute value, must be a valid u64")]
+    #[msg("Invalid attribute value, must be a valid non-negative number")]
     InvalidAttributeValue,
 
     #[msg("Invalid weight attribute key")]
@@ -106,4 +106,61 @@ pub enum CoreNftAttributeVoterError {
 
     #[msg("Invalid GoverningTokenMint for Proposal")]
     InvalidGoverningTokenMintForProposal,
+
+    #[msg("Invalid weight formula")]
+    InvalidWeightFormula,
+
+    #[msg("Attribute value has no weight configured")]
+    AttributeValueNotWeighted,
+
+    #[msg("Attribute value can't be negative")]
+    NegativeAttributeValue,
+
+    #[msg("Invalid value decimals")]
+    InvalidValueDecimals,
+
+    #[msg("Collection account required by the missing attribute policy was not provided")]
+    MissingCollectionAccount,
+
+    #[msg("Invalid WeightCensus max age, must be greater than 0")]
+    InvalidWeightCensusMaxAge,
+
+    #[msg("Invalid WeightCensus authority")]
+    InvalidWeightCensusAuthority,
+
+    #[msg("WeightCensus not provided")]
+    WeightCensusNotProvided,
+
+    #[msg("WeightCensus is stale")]
+    WeightCensusStale,
+
+    #[msg("Invalid weight source")]
+    InvalidWeightSource,
+
+    #[msg("AppData weight is out of the plugin data bounds")]
+    InvalidAppDataWeight,
+
+    #[msg("Asset must be frozen")]
+    AssetMustBeFrozen,
+
+    #[msg("Registrar must be migrated using migrate_registrar")]
+    RegistrarMustBeMigrated,
+
+    #[msg("Registrar is already migrated")]
+    RegistrarAlreadyMigrated,
+
+    #[msg("Payer and system program must be provided to resize the Registrar")]
+    RegistrarResizePayerNotProvided,
+
+    #[msg("Invalid asset plugin data")]
+    InvalidAssetPluginData,
+
+    #[msg("Required freeze authority can't be Owner or None")]
+    InvalidRequiredFreezeAuthority,
+
+    #[msg("Asset update authority is not a verified creator of the asset")]
+    UpdateAuthorityNotVerified,
+
+    #[msg("Missing attribute policy can't be used for the collection kind")]
+    InvalidMissingAttributePolicy,
 }
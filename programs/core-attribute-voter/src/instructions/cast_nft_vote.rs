@@ -16,6 +16,10 @@ use spl_governance_tools::account::create_and_serialize_account_signed;
 /// CastNftVote instruction and NftVoteRecord are not directional. They don't record vote choice (ex Yes/No)
 /// VoteChoice is recorded by spl-gov in VoteRecord and this CastNftVote only tracks voting NFTs
 ///
+/// Remaining accounts: [collection..., (asset, asset_vote_record)...]
+/// The leading collection accounts are only required for collections with MissingAttributePolicy::CollectionAttribute
+/// NFTs skipped due to MissingAttributePolicy::Skip don't get AssetVoteRecord and their count is returned
+///
 #[derive(Accounts)]
 pub struct CastNftVote<'info> {
     /// The NFT voting registrar
@@ -58,7 +62,7 @@ pub struct CastNftVote<'info> {
 /// Casts vote with the NFT
 pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>
-) -> Result<()> {
+) -> Result<u16> {
     let registrar = &ctx.accounts.registrar;
     let proposal = &ctx.accounts.proposal;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
@@ -76,28 +80,36 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     )?;
 
     let mut voter_weight = 0u64;
+    let mut skipped_count = 0u16;
 
     // Ensure all voting nfts in the batch are unique
     let mut unique_asset_mints = vec![];
 
     let rent = Rent::get()?;
 
-    for (asset, asset_vote_record_info) in
-        ctx.remaining_accounts.iter().tuples()
-    {
+    let (collection_accounts, assets) = split_collection_accounts(ctx.remaining_accounts);
+
+    for (asset, asset_vote_record_info) in assets.iter().tuples() {
         if asset.owner != &mpl_core::ID {
             return Err(CoreNftAttributeVoterError::InvalidAccountOwner.into());
         }
 
-        let (asset_vote_weight, asset_mint) = resolve_nft_vote_weight_and_mint(
+        let (asset_vote_weight, asset_mint) = match resolve_nft_vote_weight_and_mint(
             registrar,
             &governing_token_owner,
             *asset.key,
             &BaseAssetV1::from_bytes(&asset.data.borrow())
                 .map_err(|_| CoreNftAttributeVoterError::InvalidAccountOwner)?,
             asset,
+            collection_accounts,
             &mut unique_asset_mints,
-        )?;
+        )? {
+            Some(weight_and_mint) => weight_and_mint,
+            None => {
+                skipped_count += 1;
+                continue;
+            }
+        };
 
         voter_weight = voter_weight.checked_add(asset_vote_weight)
             .ok_or(CoreNftAttributeVoterError::InvalidNftAmount)?;
@@ -158,5 +170,9 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
     voter_weight_record.weight_action_target = Some(proposal_key);
 
-    Ok(())
+    if skipped_count > 0 {
+        msg!("Skipped NFTs without weight attributes: {}", skipped_count);
+    }
+
+    Ok(skipped_count)
 }
@@ -10,16 +10,33 @@ use mpl_core::types::PluginAuthority;
 use spl_governance::state::realm;
 
 use crate::error::CoreNftAttributeVoterError;
-use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, Registrar};
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, CollectionConfigExtension,
+    CollectionKind, MissingAttributePolicy, Registrar, WeightFormula, WeightSource,
+    CURRENT_REGISTRAR_VERSION,
+};
+use crate::tools::{
+    anchor::{resize_account, DISCRIMINATOR_SIZE},
+    attribute_value::MAX_VALUE_DECIMALS,
+};
 
 /// Configures NFT voting collection which defines what NFTs can be used for governances
 /// and what weight they have (read from per-NFT attributes)
 /// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
 /// used to calculate voting quorum
+///
+/// Collections configured with weight_formula, DefaultWeight policy or AppData weight source take more space than budgeted by Registrar::get_space()
+/// and the Registrar is resized when needed, funded by the payer
+/// The payer and system_program accounts are optional and only required when the Registrar must be resized
 #[derive(Accounts)]
 pub struct ConfigureCollection<'info> {
     /// Registrar for which we configure this Collection
-    #[account(mut)]
+    /// Version 0 Registrars must be migrated using migrate_registrar first
+    #[account(
+        mut,
+        constraint = registrar.version == CURRENT_REGISTRAR_VERSION
+        @ CoreNftAttributeVoterError::RegistrarMustBeMigrated
+    )]
     pub registrar: Account<'info, Registrar>,
 
     #[account(
@@ -44,17 +61,28 @@ pub struct ConfigureCollection<'info> {
         @ CoreNftAttributeVoterError::InvalidMaxVoterWeightRecordMint,
     )]
     pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// Pays for the Registrar resize in the event the configuration doesn't fit
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn configure_collection(
     ctx: Context<ConfigureCollection>,
     max_weight: u64,
     total_weight: u64,
     weight_attribute_key: String,
     expected_attribute_authority: PluginAuthority,
+    weight_formula: Option<WeightFormula>,
+    value_decimals: u8,
+    missing_attribute_policy: MissingAttributePolicy,
+    weight_source: WeightSource,
 ) -> Result<()> {
     let collection_key = ctx.accounts.collection.key();
-    let registrar = &mut ctx.accounts.registrar;
+    let registrar = &ctx.accounts.registrar;
 
     let realm = realm::get_realm_data_for_governing_token_mint(
         &registrar.governance_program_id,
@@ -67,30 +95,76 @@ pub fn configure_collection(
         CoreNftAttributeVoterError::InvalidRealmAuthority
     );
 
+    let collection_config = CollectionConfig {
+        collection: collection_key,
+        max_weight,
+        total_weight,
+        weight_attribute_key,
+        expected_attribute_authority,
+        value_decimals,
+        use_weight_census: false,
+        kind: CollectionKind::Collection,
+        reserved: [0; 5],
+    };
+
+    let collection_config_extension = CollectionConfigExtension {
+        collection: collection_key,
+        weight_formula,
+        missing_attribute_policy,
+        weight_source,
+    };
+
+    set_collection_config(
+        &mut ctx.accounts.registrar,
+        &mut ctx.accounts.max_voter_weight_record,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        collection_config,
+        collection_config_extension,
+    )
+}
+
+/// Validates the given collection config and its extension and stores them in the Registrar
+/// The Registrar is resized when needed and MaxVoterWeightRecord is updated
+/// Used by configure_collection and configure_update_authority
+pub(crate) fn set_collection_config<'info>(
+    registrar: &mut Account<'info, Registrar>,
+    max_voter_weight_record: &mut Account<'info, MaxVoterWeightRecord>,
+    payer: &Option<Signer<'info>>,
+    system_program: &Option<Program<'info, System>>,
+    collection_config: CollectionConfig,
+    collection_config_extension: CollectionConfigExtension,
+) -> Result<()> {
+    let collection_key = collection_config.collection;
+
     require!(
-        max_weight > 0,
+        collection_config.max_weight > 0,
         CoreNftAttributeVoterError::InvalidMaxWeight
     );
 
     require!(
-        total_weight > 0,
+        collection_config.total_weight > 0,
         CoreNftAttributeVoterError::InvalidTotalWeight
     );
 
     // Validate weight_attribute_key
     require!(
-        !weight_attribute_key.is_empty() && weight_attribute_key.len() <= 32,
+        !collection_config.weight_attribute_key.is_empty()
+            && collection_config.weight_attribute_key.len() <= 32,
         CoreNftAttributeVoterError::InvalidWeightAttributeKey
     );
 
-    let collection_config = CollectionConfig {
-        collection: collection_key,
-        max_weight,
-        total_weight,
-        weight_attribute_key,
-        expected_attribute_authority,
-        reserved: [0; 8],
-    };
+    require!(
+        collection_config.value_decimals <= MAX_VALUE_DECIMALS,
+        CoreNftAttributeVoterError::InvalidValueDecimals
+    );
+
+    if let Some(weight_formula) = &collection_config_extension.weight_formula {
+        weight_formula.assert_is_valid()?;
+    }
+
+    collection_config_extension.assert_weight_source_is_valid()?;
+    collection_config_extension.assert_is_valid_for_kind(collection_config.kind)?;
 
     let collection_idx = registrar
         .collection_configs
@@ -98,20 +172,70 @@ pub fn configure_collection(
         .position(|cc| cc.collection == collection_key);
 
     if let Some(collection_idx) = collection_idx {
-        registrar.collection_configs[collection_idx] = collection_config;
+        // A collection can't be reconfigured as an update authority and vice versa
+        require!(
+            registrar.collection_configs[collection_idx].kind == collection_config.kind,
+            CoreNftAttributeVoterError::InvalidNftCollection
+        );
+
+        // The census is configured using configure_weight_census and it's preserved
+        let use_weight_census = registrar.collection_configs[collection_idx].use_weight_census;
+
+        registrar.collection_configs[collection_idx] = CollectionConfig {
+            use_weight_census,
+            ..collection_config
+        };
     } else {
         // Note: In the current runtime version push() would throw an error if we exceed
         // max_collections specified when the Registrar was created
         registrar.collection_configs.push(collection_config);
     }
 
-    // Update MaxVoterWeightRecord based on max voting power of the collections
-    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    let extension_idx = registrar
+        .collection_config_extensions
+        .iter()
+        .position(|ce| ce.collection == collection_key);
 
-    max_voter_weight_record.max_voter_weight = registrar.max_voter_weight()?;
+    if let Some(extension_idx) = extension_idx {
+        registrar.collection_config_extensions[extension_idx] = collection_config_extension;
+    } else {
+        registrar
+            .collection_config_extensions
+            .push(collection_config_extension);
+    }
+
+    // Resize the Registrar if the collection configs no longer fit into the account
+    let new_size = DISCRIMINATOR_SIZE + registrar.try_to_vec()?.len();
+    let registrar_info = registrar.to_account_info();
+
+    if new_size > registrar_info.data_len() {
+        let (payer, system_program) = match (payer, system_program) {
+            (Some(payer), Some(system_program)) => (payer, system_program),
+            _ => return err!(CoreNftAttributeVoterError::RegistrarResizePayerNotProvided),
+        };
+
+        resize_account(
+            &registrar_info,
+            &payer.to_account_info(),
+            &system_program.to_account_info(),
+            new_size,
+        )?;
+    }
 
-    // The weight never expires and only changes when collections are configured
-    max_voter_weight_record.max_voter_weight_expiry = None;
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    match registrar.max_voter_weight()? {
+        Some(max_voter_weight) => {
+            max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+            // The weight never expires and only changes when collections are configured
+            max_voter_weight_record.max_voter_weight_expiry = None;
+        }
+        None => {
+            // The total weight of census collections can't be resolved here and the record is expired
+            // until update_max_voter_weight_record is called with the WeightCensus accounts
+            max_voter_weight_record.max_voter_weight_expiry = Some(0);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use mpl_core::types::PluginAuthority;
+use spl_governance::state::realm;
+
+use crate::error::CoreNftAttributeVoterError;
+use crate::instructions::set_collection_config;
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, CollectionConfigExtension,
+    CollectionKind, MissingAttributePolicy, Registrar, WeightFormula, WeightSource,
+    CURRENT_REGISTRAR_VERSION,
+};
+
+/// Configures assets without a collection identified by their UpdateAuthority::Address update authority
+/// and what weight they have (read from per-NFT attributes)
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+///
+/// Note: The update authority must be a verified creator of each asset used for voting
+/// Note: The assets don't have a collection account and they can't use the CollectionAttribute policy,
+/// LinkedAppData weight source or WeightCensus
+#[derive(Accounts)]
+pub struct ConfigureUpdateAuthority<'info> {
+    /// Registrar for which we configure this update authority
+    /// Version 0 Registrars must be migrated using migrate_registrar first
+    #[account(
+        mut,
+        constraint = registrar.version == CURRENT_REGISTRAR_VERSION
+        @ CoreNftAttributeVoterError::RegistrarMustBeMigrated
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ CoreNftAttributeVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: Update authority of the assets which are going to be used for voting
+    /// It's only used as the key of the assets group
+    pub update_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ CoreNftAttributeVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ CoreNftAttributeVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// Pays for the Registrar resize in the event the configuration doesn't fit
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn configure_update_authority(
+    ctx: Context<ConfigureUpdateAuthority>,
+    max_weight: u64,
+    total_weight: u64,
+    weight_attribute_key: String,
+    expected_attribute_authority: PluginAuthority,
+    weight_formula: Option<WeightFormula>,
+    value_decimals: u8,
+    missing_attribute_policy: MissingAttributePolicy,
+    weight_source: WeightSource,
+) -> Result<()> {
+    let update_authority = ctx.accounts.update_authority.key();
+    let registrar = &ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority == Some(ctx.accounts.realm_authority.key()),
+        CoreNftAttributeVoterError::InvalidRealmAuthority
+    );
+
+    let collection_config = CollectionConfig {
+        collection: update_authority,
+        max_weight,
+        total_weight,
+        weight_attribute_key,
+        expected_attribute_authority,
+        value_decimals,
+        use_weight_census: false,
+        kind: CollectionKind::UpdateAuthority,
+        reserved: [0; 5],
+    };
+
+    let collection_config_extension = CollectionConfigExtension {
+        collection: update_authority,
+        weight_formula,
+        missing_attribute_policy,
+        weight_source,
+    };
+
+    set_collection_config(
+        &mut ctx.accounts.registrar,
+        &mut ctx.accounts.max_voter_weight_record,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        collection_config,
+        collection_config_extension,
+    )
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+use spl_governance::state::realm;
+
+use crate::error::CoreNftAttributeVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar, WeightCensus};
+
+/// Configures WeightCensus for the collection which is attested by the collection's attribute authority
+/// When max_age is provided the census is used instead of the collection's hand-set total_weight
+/// to calculate max voter weight in update_max_voter_weight_record
+/// When max_age is None the collection goes back to using total_weight
+///
+/// MaxVoterWeightRecord is refreshed when all collections use their hand-set total_weight
+/// and expired otherwise until update_max_voter_weight_record is called with the WeightCensus accounts
+#[derive(Accounts)]
+pub struct ConfigureWeightCensus<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ CoreNftAttributeVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The collection the census is for
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"weight-census".as_ref(), registrar.key().as_ref(), collection.key().as_ref()],
+        bump,
+        payer = payer,
+        space = WeightCensus::get_space()
+    )]
+    pub weight_census: Account<'info, WeightCensus>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ CoreNftAttributeVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ CoreNftAttributeVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_weight_census(
+    ctx: Context<ConfigureWeightCensus>,
+    max_age: Option<u64>,
+) -> Result<()> {
+    let collection_key = ctx.accounts.collection.key();
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority == Some(ctx.accounts.realm_authority.key()),
+        CoreNftAttributeVoterError::InvalidRealmAuthority
+    );
+
+    let registrar_key = registrar.key();
+
+    let collection_config = registrar
+        .collection_configs
+        .iter_mut()
+        .find(|cc| cc.collection == collection_key)
+        .ok_or(CoreNftAttributeVoterError::CollectionNotFound)?;
+
+    let weight_census = &mut ctx.accounts.weight_census;
+
+    weight_census.registrar = registrar_key;
+    weight_census.collection = collection_key;
+
+    match max_age {
+        Some(max_age) => {
+            require!(
+                max_age > 0,
+                CoreNftAttributeVoterError::InvalidWeightCensusMaxAge
+            );
+
+            weight_census.max_age = max_age;
+            collection_config.use_weight_census = true;
+        }
+        None => {
+            collection_config.use_weight_census = false;
+        }
+    }
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    match registrar.max_voter_weight()? {
+        Some(max_voter_weight) => {
+            max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+            // The weight never expires and only changes when collections are configured
+            max_voter_weight_record.max_voter_weight_expiry = None;
+        }
+        None => {
+            // The total weight of census collections can't be resolved here and the record is expired
+            // until update_max_voter_weight_record is called with the WeightCensus accounts
+            max_voter_weight_record.max_voter_weight_expiry = Some(0);
+        }
+    }
+
+    Ok(())
+}
@@ -63,6 +63,7 @@ pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_collections: u8) ->
     registrar.governance_program_id = ctx.accounts.governance_program_id.key();
     registrar.realm = ctx.accounts.realm.key();
     registrar.governing_token_mint = ctx.accounts.governing_token_mint.key();
+    registrar.version = CURRENT_REGISTRAR_VERSION;
 
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::error::CoreNftAttributeVoterError;
+use crate::state::{Registrar, RegistrarV0, CURRENT_REGISTRAR_VERSION};
+use crate::tools::anchor::{resize_account, DISCRIMINATOR_SIZE};
+
+/// Migrates version 0 Registrar to the current Registrar layout
+/// Version 0 Registrars were created before collection_config_extensions was added and the instruction
+/// appends the empty extensions to the account, resizing it when needed
+/// The existing collections keep their behaviour and use the default CollectionConfigExtension options
+///
+/// The instruction is permissionless because it doesn't change the configuration of the Registrar
+#[derive(Accounts)]
+pub struct MigrateRegistrar<'info> {
+    /// The Registrar to migrate
+    /// CHECK: Version 0 Registrar can't be deserialized using the current layout and it's deserialized in the instruction
+    #[account(mut, owner = crate::id())]
+    pub registrar: UncheckedAccount<'info>,
+
+    /// Pays for the Registrar resize in the event the current layout doesn't fit
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+    let registrar_info = ctx.accounts.registrar.to_account_info();
+
+    let registrar_v0 = {
+        let data = registrar_info.try_borrow_data()?;
+
+        require!(
+            data.len() >= DISCRIMINATOR_SIZE
+                && &data[..DISCRIMINATOR_SIZE] == Registrar::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        RegistrarV0::deserialize(&mut &data[DISCRIMINATOR_SIZE..])?
+    };
+
+    require!(
+        registrar_v0.version == 0,
+        CoreNftAttributeVoterError::RegistrarAlreadyMigrated
+    );
+
+    let registrar = Registrar {
+        governance_program_id: registrar_v0.governance_program_id,
+        realm: registrar_v0.realm,
+        governing_token_mint: registrar_v0.governing_token_mint,
+        collection_configs: registrar_v0.collection_configs,
+        required_freeze_authority: registrar_v0.required_freeze_authority,
+        version: CURRENT_REGISTRAR_VERSION,
+        reserved: registrar_v0.reserved,
+        collection_config_extensions: vec![],
+    };
+
+    let new_size = DISCRIMINATOR_SIZE + registrar.try_to_vec()?.len();
+
+    if new_size > registrar_info.data_len() {
+        resize_account(
+            &registrar_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_size,
+        )?;
+    }
+
+    let mut data = registrar_info.try_borrow_mut_data()?;
+    registrar.serialize(&mut &mut data[DISCRIMINATOR_SIZE..])?;
+
+    Ok(())
+}
@@ -1,6 +1,12 @@
 pub use configure_collection::*;
 mod configure_collection;
 
+pub use configure_update_authority::*;
+mod configure_update_authority;
+
+pub use set_required_freeze_authority::*;
+mod set_required_freeze_authority;
+
 pub use create_registrar::*;
 mod create_registrar;
 
@@ -21,3 +27,12 @@ mod cast_nft_vote;
 
 pub use update_max_voter_weight_record::*;
 mod update_max_voter_weight_record;
+
+pub use configure_weight_census::*;
+mod configure_weight_census;
+
+pub use update_weight_census::*;
+mod update_weight_census;
+
+pub use migrate_registrar::*;
+mod migrate_registrar;
@@ -5,6 +5,9 @@ use max_voter_weight_record::MaxVoterWeightRecord;
 
 // Takes all collections added to `register`, iterates over them and calculates
 // the max voter weight
+//
+// Remaining accounts: [weight_census...] for the collections with use_weight_census set
+// The census total_weight is used instead of the hand-set total_weight and it must not be stale
 #[derive(Accounts)]
 pub struct UpdateMaxVoterWeightRecord<'info> {
     /// The NFT voting Registrar
@@ -23,15 +26,66 @@ pub struct UpdateMaxVoterWeightRecord<'info> {
 
 pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
+    let registrar_key = registrar.key();
+    let clock = Clock::get()?;
+    let unix_timestamp = clock.unix_timestamp;
 
     // Calculate the max voter weight by iterating over all collections and summing
-    // the max weight of each collection.
-    ctx.accounts.max_voter_weight_record.max_voter_weight = registrar.max_voter_weight()?;
+    // the total weight of each collection taken from its census when enabled
+    let mut max_voter_weight = 0u64;
 
-    // max_voter_weight is derived deterministically from the registrar's collection configs
-    // which only change via configure_collection (which also updates this record).
-    // Setting expiry to None avoids forcing governance users to bundle this instruction.
-    ctx.accounts.max_voter_weight_record.max_voter_weight_expiry = None;
+    for collection_config in registrar.collection_configs.iter() {
+        let total_weight = if collection_config.use_weight_census {
+            let weight_census_address =
+                get_weight_census_address(&registrar_key, &collection_config.collection);
+
+            let weight_census_info = ctx
+                .remaining_accounts
+                .iter()
+                .find(|info| info.key == &weight_census_address)
+                .ok_or(CoreNftAttributeVoterError::WeightCensusNotProvided)?;
+
+            require_keys_eq!(
+                *weight_census_info.owner,
+                crate::id(),
+                CoreNftAttributeVoterError::InvalidAccountOwner
+            );
+
+            let weight_census =
+                WeightCensus::try_deserialize(&mut &weight_census_info.data.borrow()[..])?;
+
+            require!(
+                weight_census.is_current(unix_timestamp),
+                CoreNftAttributeVoterError::WeightCensusStale
+            );
+
+            weight_census.total_weight
+        } else {
+            collection_config.total_weight
+        };
+
+        max_voter_weight = max_voter_weight
+            .checked_add(total_weight)
+            .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?;
+    }
+
+    ctx.accounts.max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+    // max_voter_weight derived from the hand-set total_weight only changes when collections are configured
+    // and setting expiry to None avoids forcing governance users to bundle this instruction
+    //
+    // When any census is used the weight is only valid in the current slot because the census can go stale
+    // and this instruction must be bundled with the governance instruction to enforce the census max_age
+    let uses_weight_census = registrar
+        .collection_configs
+        .iter()
+        .any(|cc| cc.use_weight_census);
+
+    ctx.accounts.max_voter_weight_record.max_voter_weight_expiry = if uses_weight_census {
+        Some(clock.slot)
+    } else {
+        None
+    };
 
     Ok(())
 }
@@ -11,6 +11,11 @@ use mpl_core::accounts::BaseAssetV1;
 /// can only be used with max 5 NFTs due to Solana transaction size limit
 /// It could be supported in future version by introducing bookkeeping accounts to track the NFTs
 /// which were already used to calculate the total weight
+///
+/// Remaining accounts: [collection..., asset...]
+/// The leading collection accounts are only required for collections with MissingAttributePolicy::CollectionAttribute
+///
+/// Returns the number of NFTs skipped due to MissingAttributePolicy::Skip
 #[derive(Accounts)]
 #[instruction(voter_weight_action:VoterWeightAction)]
 pub struct UpdateVoterWeightRecord<'info> {
@@ -39,7 +44,7 @@ pub struct UpdateVoterWeightRecord<'info> {
 pub fn update_voter_weight_record(
     ctx: Context<UpdateVoterWeightRecord>,
     voter_weight_action: VoterWeightAction,
-) -> Result<()> {
+) -> Result<u16> {
     let registrar = &ctx.accounts.registrar;
 
     let governing_token_owner = resolve_governing_token_owner(
@@ -59,24 +64,34 @@ pub fn update_voter_weight_record(
     }
 
     let mut voter_weight = 0u64;
+    let mut skipped_count = 0u16;
 
     // Ensure all nfts are unique
     let mut unique_nft_mints = vec![];
 
-    for asset in ctx.remaining_accounts.iter() {
+    let (collection_accounts, assets) = split_collection_accounts(ctx.remaining_accounts);
+
+    for asset in assets.iter() {
         if asset.owner != &mpl_core::ID {
             return Err(CoreNftAttributeVoterError::InvalidAccountOwner.into());
         }
 
-        let (nft_vote_weight, _) = resolve_nft_vote_weight_and_mint(
+        let (nft_vote_weight, _) = match resolve_nft_vote_weight_and_mint(
             registrar,
             &governing_token_owner,
             *asset.key,
             &BaseAssetV1::from_bytes(&asset.data.borrow())
                 .map_err(|_| CoreNftAttributeVoterError::InvalidAccountOwner)?,
             asset,
+            collection_accounts,
             &mut unique_nft_mints,
-        )?;
+        )? {
+            Some(weight_and_mint) => weight_and_mint,
+            None => {
+                skipped_count += 1;
+                continue;
+            }
+        };
 
         voter_weight = voter_weight.checked_add(nft_vote_weight)
             .ok_or(CoreNftAttributeVoterError::InvalidNftAmount)?;
@@ -93,5 +108,9 @@ pub fn update_voter_weight_record(
     voter_weight_record.weight_action = Some(voter_weight_action);
     voter_weight_record.weight_action_target = None;
 
-    Ok(())
+    if skipped_count > 0 {
+        msg!("Skipped NFTs without weight attributes: {}", skipped_count);
+    }
+
+    Ok(skipped_count)
 }
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_core::types::PluginAuthority;
+
+use crate::error::CoreNftAttributeVoterError;
+use crate::state::{Registrar, WeightCensus};
+
+/// Attests the total attribute weight of the collection in its WeightCensus
+/// The instruction must be signed by the collection's expected_attribute_authority
+/// which is the same authority trusted to set the NFT weight attributes
+#[derive(Accounts)]
+pub struct UpdateWeightCensus<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// The collection the census is for
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        seeds = [b"weight-census".as_ref(), registrar.key().as_ref(), collection.key().as_ref()],
+        bump,
+    )]
+    pub weight_census: Account<'info, WeightCensus>,
+
+    /// The attribute authority of the collection
+    /// It must be either the Address of expected_attribute_authority
+    /// or the collection's update_authority for UpdateAuthority
+    pub attribute_authority: Signer<'info>,
+}
+
+pub fn update_weight_census(ctx: Context<UpdateWeightCensus>, total_weight: u64) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+
+    let collection_config = ctx
+        .accounts
+        .registrar
+        .get_collection_config(collection.key())?;
+
+    let attribute_authority = match collection_config.expected_attribute_authority {
+        PluginAuthority::Address { address } => address,
+        PluginAuthority::UpdateAuthority => collection.update_authority,
+        // Owner and None can't attest the census for the whole collection
+        PluginAuthority::Owner | PluginAuthority::None => {
+            return err!(CoreNftAttributeVoterError::InvalidWeightCensusAuthority)
+        }
+    };
+
+    require_keys_eq!(
+        attribute_authority,
+        ctx.accounts.attribute_authority.key(),
+        CoreNftAttributeVoterError::InvalidWeightCensusAuthority
+    );
+
+    require!(
+        total_weight > 0,
+        CoreNftAttributeVoterError::InvalidTotalWeight
+    );
+
+    let weight_census = &mut ctx.accounts.weight_census;
+
+    weight_census.total_weight = total_weight;
+    weight_census.attested_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -37,7 +37,7 @@ pub mod core_attribute_voter {
     pub fn update_voter_weight_record(
         ctx: Context<UpdateVoterWeightRecord>,
         voter_weight_action: VoterWeightAction,
-    ) -> Result<()> {
+    ) -> Result<u16> {
         log_version();
         instructions::update_voter_weight_record(ctx, voter_weight_action)
     }
@@ -49,20 +49,66 @@ pub mod core_attribute_voter {
         log_version();
         instructions::relinquish_nft_vote(ctx)
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn configure_collection(
         ctx: Context<ConfigureCollection>,
         max_weight: u64,
         total_weight: u64,
         weight_attribute_key: String,
         expected_attribute_authority: mpl_core::types::PluginAuthority,
+        weight_formula: Option<WeightFormula>,
+        value_decimals: u8,
+        missing_attribute_policy: MissingAttributePolicy,
+        weight_source: WeightSource,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_collection(ctx, max_weight, total_weight, weight_attribute_key, expected_attribute_authority, weight_formula, value_decimals, missing_attribute_policy, weight_source)
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn configure_update_authority(
+        ctx: Context<ConfigureUpdateAuthority>,
+        max_weight: u64,
+        total_weight: u64,
+        weight_attribute_key: String,
+        expected_attribute_authority: mpl_core::types::PluginAuthority,
+        weight_formula: Option<WeightFormula>,
+        value_decimals: u8,
+        missing_attribute_policy: MissingAttributePolicy,
+        weight_source: WeightSource,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_update_authority(ctx, max_weight, total_weight, weight_attribute_key, expected_attribute_authority, weight_formula, value_decimals, missing_attribute_policy, weight_source)
+    }
+
+    pub fn set_required_freeze_authority(
+        ctx: Context<SetRequiredFreezeAuthority>,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
     ) -> Result<()> {
         log_version();
-        instructions::configure_collection(ctx, max_weight, total_weight, weight_attribute_key, expected_attribute_authority)
+        instructions::set_required_freeze_authority(ctx, required_freeze_authority)
+    }
+
+    pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+        log_version();
+        instructions::migrate_registrar(ctx)
+    }
+
+    pub fn configure_weight_census(
+        ctx: Context<ConfigureWeightCensus>,
+        max_age: Option<u64>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_weight_census(ctx, max_age)
+    }
+
+    pub fn update_weight_census(ctx: Context<UpdateWeightCensus>, total_weight: u64) -> Result<()> {
+        log_version();
+        instructions::update_weight_census(ctx, total_weight)
     }
 
     pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
-    ) -> Result<()> {
+    ) -> Result<u16> {
         log_version();
         instructions::cast_nft_vote(ctx)
     }
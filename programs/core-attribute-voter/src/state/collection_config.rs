@@ -1,10 +1,27 @@
 use anchor_lang::prelude::*;
-use mpl_core::types::PluginAuthority;
+use mpl_core::types::{Attribute, PluginAuthority};
+
+use crate::error::CoreNftAttributeVoterError;
+use crate::tools::attribute_value::parse_attribute_value;
+
+/// The way assets are grouped into a CollectionConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollectionKind {
+    /// Assets of the Core collection with UpdateAuthority::Collection
+    #[default]
+    Collection,
+
+    /// Assets without a collection with UpdateAuthority::Address
+    /// The update authority must be a verified creator of the asset because Core doesn't require
+    /// the update authority to sign when an asset is created
+    UpdateAuthority,
+}
 
 /// Configuration of an NFT collection used for attribute-based governance power
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
 pub struct CollectionConfig {
     /// The NFT collection used for governance
+    /// For CollectionKind::UpdateAuthority it's the update authority address of the assets
     pub collection: Pubkey,
 
     /// Maximum governance power weight of the collection
@@ -13,25 +30,356 @@ pub struct CollectionConfig {
 
     /// Total governance power contribution of the collection to quorum denominator.
     /// This value is summed across collections to produce MaxVoterWeightRecord.max_voter_weight.
+    /// Note: When use_weight_census is set update_max_voter_weight_record uses WeightCensus.total_weight instead
     pub total_weight: u64,
 
     /// The attribute key to read the voting weight from on each NFT
-    /// The attribute value must be a valid u64 string
+    /// The attribute value must be a valid non-negative number scaled by value_decimals
+    /// Note: When the collection's CollectionConfigExtension.weight_formula is set the formula is used instead of this single key
     pub weight_attribute_key: String,
 
     /// The expected plugin authority for the Attributes plugin on each NFT.
     /// Only attributes set by this authority are trusted for vote weight.
     pub expected_attribute_authority: PluginAuthority,
 
+    /// Number of decimals numeric attribute values are scaled by to get the weight
+    /// For example with 2 decimals the value "12.5" resolves to weight 1250
+    /// Note: max_weight and total_weight are expressed in the scaled units
+    pub value_decimals: u8,
+
+    /// Indicates whether the collection's WeightCensus is used instead of total_weight
+    /// Set by configure_weight_census
+    pub use_weight_census: bool,
+
+    /// The way assets are grouped into the config
+    pub kind: CollectionKind,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    pub reserved: [u8; 5],
 }
 
 impl CollectionConfig {
     /// Borsh serialized size: 32 (Pubkey) + 8 (max_weight) + 8 (total_weight)
-    /// + 4+32 (String with max 32 chars) + 33 (PluginAuthority) + 8 (reserved)
-    pub const SERIALIZED_SIZE: usize = 32 + 8 + 8 + 36 + 33 + 8;
+    /// + 4+32 (String with max 32 chars) + 33 (PluginAuthority) + 1 (value_decimals)
+    /// + 1 (use_weight_census) + 1 (kind) + 5 (reserved)
+    pub const SERIALIZED_SIZE: usize = 32 + 8 + 8 + 36 + 33 + 1 + 1 + 1 + 5;
+
+    /// Resolves the weight from the given attribute list using the extension's weight_formula or weight_attribute_key
+    /// Returns None when any of the weight attributes is missing
+    pub fn resolve_weight(
+        &self,
+        extension: &CollectionConfigExtension,
+        attribute_list: &[Attribute],
+    ) -> Result<Option<u64>> {
+        match &extension.weight_formula {
+            Some(weight_formula) => weight_formula.resolve_weight(attribute_list, self.value_decimals),
+            None => attribute_list
+                .iter()
+                .find(|attr| attr.key == self.weight_attribute_key)
+                .map(|attr| parse_attribute_value(&attr.value, self.value_decimals))
+                .transpose(),
+        }
+    }
+}
+
+/// Weight options of a collection which don't fit into the fixed size CollectionConfig
+/// The extensions are stored in Registrar.collection_config_extensions and collections without an extension
+/// use the default options (single weight_attribute_key, Reject policy and Attributes weight source)
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct CollectionConfigExtension {
+    /// The collection the options are for
+    pub collection: Pubkey,
+
+    /// Optional formula combining several attributes into the NFT weight
+    /// When None the weight is the numeric value of CollectionConfig.weight_attribute_key
+    pub weight_formula: Option<WeightFormula>,
+
+    /// Policy applied to NFTs without the weight attribute(s) or the Attributes plugin
+    pub missing_attribute_policy: MissingAttributePolicy,
+
+    /// The plugin the NFT weight is read from
+    pub weight_source: WeightSource,
+}
+
+impl CollectionConfigExtension {
+    /// Borsh serialized size of the default extension: 32 (Pubkey) + 1 (weight_formula None)
+    /// + 1 (missing_attribute_policy Reject) + 1 (weight_source Attributes)
+    ///
+    /// Note: Extensions with weight_formula, DefaultWeight policy or AppData weight source
+    /// take additional space which is allocated when the collection is configured
+    pub const MIN_SERIALIZED_SIZE: usize = 32 + 1 + 1 + 1;
+
+    /// Returns the default options used by collections without an extension
+    pub fn new_default(collection: Pubkey) -> Self {
+        Self {
+            collection,
+            weight_formula: None,
+            missing_attribute_policy: MissingAttributePolicy::Reject,
+            weight_source: WeightSource::Attributes,
+        }
+    }
+
+    /// Asserts the weight_source can be used together with the other options of the extension
+    /// AppData weight sources hold a single raw value and can't be combined with weight_formula
+    /// or read the weight from the collection's Attributes plugin
+    pub fn assert_weight_source_is_valid(&self) -> Result<()> {
+        if self.weight_source != WeightSource::Attributes {
+            require!(
+                self.weight_formula.is_none()
+                    && self.missing_attribute_policy != MissingAttributePolicy::CollectionAttribute,
+                CoreNftAttributeVoterError::InvalidWeightSource
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Asserts the options can be used with the given CollectionKind
+    /// Assets grouped by update authority don't have a collection account to read
+    /// the collection's Attributes or LinkedAppData plugins from
+    pub fn assert_is_valid_for_kind(&self, kind: CollectionKind) -> Result<()> {
+        if kind == CollectionKind::UpdateAuthority {
+            require!(
+                self.missing_attribute_policy != MissingAttributePolicy::CollectionAttribute,
+                CoreNftAttributeVoterError::InvalidMissingAttributePolicy
+            );
+
+            require!(
+                !matches!(self.weight_source, WeightSource::LinkedAppData { .. }),
+                CoreNftAttributeVoterError::InvalidWeightSource
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Policy applied to NFTs which don't have the weight attribute(s) or the Attributes plugin
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingAttributePolicy {
+    /// The NFT is rejected and the instruction fails
+    Reject,
+
+    /// The NFT is skipped and doesn't contribute any weight
+    Skip,
+
+    /// The NFT contributes the given default weight
+    DefaultWeight { weight: u64 },
+
+    /// The attribute(s) are read from the collection's own Attributes plugin
+    /// The collection account must be provided to the instruction
+    CollectionAttribute,
+}
+
+/// Plugin the NFT weight is read from
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub enum WeightSource {
+    /// The weight is read from the NFT's Attributes plugin using weight_formula or CollectionConfig.weight_attribute_key
+    Attributes,
+
+    /// The weight is read as a little-endian u64 at the given offset of the NFT's AppData external plugin data
+    /// Only the AppData plugin with the given data_authority is trusted for vote weight
+    AppData {
+        data_authority: PluginAuthority,
+        offset: u32,
+    },
+
+    /// The weight is read as a little-endian u64 at the given offset of the NFT's data section
+    /// of the collection's LinkedAppData external plugin
+    /// Only the LinkedAppData plugin with the given data_authority is trusted for vote weight
+    LinkedAppData {
+        data_authority: PluginAuthority,
+        offset: u32,
+    },
+}
+
+impl WeightSource {
+    /// Reads the weight from the given external plugin data at the source offset
+    /// Returns None when no data has been written to the plugin yet
+    pub fn read_weight(&self, data: &[u8]) -> Result<Option<u64>> {
+        let offset = match self {
+            WeightSource::Attributes => {
+                return err!(CoreNftAttributeVoterError::InvalidWeightSource)
+            }
+            WeightSource::AppData { offset, .. } | WeightSource::LinkedAppData { offset, .. } => {
+                *offset as usize
+            }
+        };
+
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let weight_bytes = data
+            .get(offset..offset.saturating_add(8))
+            .ok_or(CoreNftAttributeVoterError::InvalidAppDataWeight)?;
+
+        let mut weight = [0u8; 8];
+        weight.copy_from_slice(weight_bytes);
+
+        Ok(Some(u64::from_le_bytes(weight)))
+    }
+}
+
+/// Max number of attributes a WeightFormula can combine
+pub const MAX_WEIGHT_FORMULA_ATTRIBUTES: usize = 4;
+
+/// Max number of value weights in a single WeightAttribute lookup table
+pub const MAX_ATTRIBUTE_VALUE_WEIGHTS: usize = 16;
+
+/// Max length of attribute keys and values used in WeightFormula
+pub const MAX_ATTRIBUTE_LENGTH: usize = 32;
+
+/// The way weights of the individual attributes are combined into the NFT weight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightCombination {
+    /// The weights of the attributes are added together
+    Sum,
+
+    /// The weights of the attributes are multiplied together
+    Product,
+
+    /// The highest weight of the attributes is used
+    Max,
+}
+
+/// Formula which resolves NFT weight from several attributes
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct WeightFormula {
+    /// How the weights of the attributes are combined
+    pub combination: WeightCombination,
+
+    /// The attributes the weight is resolved from
+    pub attributes: Vec<WeightAttribute>,
+}
+
+/// Attribute used by WeightFormula
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct WeightAttribute {
+    /// The attribute key to read the value from
+    pub key: String,
+
+    /// Multiplier applied to the attribute weight before it's combined
+    pub multiplier: u64,
 
+    /// Lookup table mapping attribute values (ex. "gold", "silver") to weights
+    /// When empty the attribute value must be a valid number and it's scaled by value_decimals
+    /// Note: The lookup table weights are used as is and they are not scaled
+    pub value_weights: Vec<AttributeValueWeight>,
+}
+
+/// Weight of a single attribute value
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct AttributeValueWeight {
+    /// The attribute value
+    pub value: String,
+
+    /// The weight of the value
+    pub weight: u64,
+}
+
+impl WeightFormula {
+    /// Asserts the formula is within the supported bounds and unambiguous
+    pub fn assert_is_valid(&self) -> Result<()> {
+        require!(
+            !self.attributes.is_empty() && self.attributes.len() <= MAX_WEIGHT_FORMULA_ATTRIBUTES,
+            CoreNftAttributeVoterError::InvalidWeightFormula
+        );
+
+        for (idx, attribute) in self.attributes.iter().enumerate() {
+            require!(
+                !attribute.key.is_empty() && attribute.key.len() <= MAX_ATTRIBUTE_LENGTH,
+                CoreNftAttributeVoterError::InvalidWeightAttributeKey
+            );
+
+            require!(
+                !self.attributes[..idx]
+                    .iter()
+                    .any(|a| a.key == attribute.key),
+                CoreNftAttributeVoterError::InvalidWeightFormula
+            );
+
+            require!(
+                attribute.multiplier > 0,
+                CoreNftAttributeVoterError::InvalidWeightFormula
+            );
+
+            require!(
+                attribute.value_weights.len() <= MAX_ATTRIBUTE_VALUE_WEIGHTS,
+                CoreNftAttributeVoterError::InvalidWeightFormula
+            );
+
+            for (value_idx, value_weight) in attribute.value_weights.iter().enumerate() {
+                require!(
+                    !value_weight.value.is_empty()
+                        && value_weight.value.len() <= MAX_ATTRIBUTE_LENGTH,
+                    CoreNftAttributeVoterError::InvalidWeightFormula
+                );
+
+                require!(
+                    !attribute.value_weights[..value_idx]
+                        .iter()
+                        .any(|vw| vw.value == value_weight.value),
+                    CoreNftAttributeVoterError::InvalidWeightFormula
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the weight from the given attribute list
+    /// Numeric attribute values are scaled by the given value_decimals
+    /// Returns None when any of the formula attributes is missing
+    pub fn resolve_weight(
+        &self,
+        attribute_list: &[Attribute],
+        value_decimals: u8,
+    ) -> Result<Option<u64>> {
+        let mut weight: Option<u64> = None;
+
+        for attribute in self.attributes.iter() {
+            let value = match attribute_list.iter().find(|attr| attr.key == attribute.key) {
+                Some(attr) => &attr.value,
+                None => return Ok(None),
+            };
+
+            let attribute_weight = attribute
+                .get_value_weight(value, value_decimals)?
+                .checked_mul(attribute.multiplier)
+                .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?;
+
+            weight = Some(match weight {
+                None => attribute_weight,
+                Some(weight) => match self.combination {
+                    WeightCombination::Sum => weight
+                        .checked_add(attribute_weight)
+                        .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?,
+                    WeightCombination::Product => weight
+                        .checked_mul(attribute_weight)
+                        .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?,
+                    WeightCombination::Max => weight.max(attribute_weight),
+                },
+            });
+        }
+
+        Ok(Some(weight.unwrap_or(0)))
+    }
+}
+
+impl WeightAttribute {
+    /// Returns the weight of the given attribute value
+    pub fn get_value_weight(&self, value: &str, value_decimals: u8) -> Result<u64> {
+        if self.value_weights.is_empty() {
+            return parse_attribute_value(value, value_decimals);
+        }
+
+        self.value_weights
+            .iter()
+            .find(|vw| vw.value == value)
+            .map(|vw| vw.weight)
+            .ok_or_else(|| CoreNftAttributeVoterError::AttributeValueNotWeighted.into())
+    }
 }
 
 impl Default for CollectionConfig {
@@ -43,7 +391,157 @@ impl Default for CollectionConfig {
             // Default to a 32-byte zero-padded string for deterministic sizing
             weight_attribute_key: "\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0".to_string(),
             expected_attribute_authority: PluginAuthority::Address { address: Pubkey::default() },
-            reserved: [0; 8],
+            value_decimals: 0,
+            use_weight_census: false,
+            kind: CollectionKind::Collection,
+            reserved: [0; 5],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn attribute(key: &str, value: &str) -> Attribute {
+        Attribute {
+            key: key.to_string(),
+            value: value.to_string(),
         }
     }
+
+    fn tier_formula(combination: WeightCombination) -> WeightFormula {
+        WeightFormula {
+            combination,
+            attributes: vec![
+                WeightAttribute {
+                    key: "tier".to_string(),
+                    multiplier: 1,
+                    value_weights: vec![
+                        AttributeValueWeight {
+                            value: "gold".to_string(),
+                            weight: 10,
+                        },
+                        AttributeValueWeight {
+                            value: "silver".to_string(),
+                            weight: 5,
+                        },
+                    ],
+                },
+                WeightAttribute {
+                    key: "level".to_string(),
+                    multiplier: 2,
+                    value_weights: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_resolve_weight_with_combinations() {
+        // Arrange
+        let attribute_list = vec![attribute("tier", "gold"), attribute("level", "3")];
+
+        // Act
+        let sum = tier_formula(WeightCombination::Sum).resolve_weight(&attribute_list, 0);
+        let product = tier_formula(WeightCombination::Product).resolve_weight(&attribute_list, 0);
+        let max = tier_formula(WeightCombination::Max).resolve_weight(&attribute_list, 0);
+
+        // Assert
+        assert_eq!(sum.unwrap(), Some(16));
+        assert_eq!(product.unwrap(), Some(60));
+        assert_eq!(max.unwrap(), Some(10));
+    }
+
+    #[test]
+    fn test_resolve_weight_with_missing_attribute() {
+        // Arrange
+        let attribute_list = vec![attribute("tier", "gold")];
+
+        // Act
+        let weight = tier_formula(WeightCombination::Sum).resolve_weight(&attribute_list, 0);
+
+        // Assert
+        assert_eq!(weight.unwrap(), None);
+    }
+
+    #[test]
+    fn test_resolve_weight_with_value_not_weighted_error() {
+        // Arrange
+        let attribute_list = vec![attribute("tier", "bronze"), attribute("level", "3")];
+
+        // Act
+        let err = tier_formula(WeightCombination::Sum)
+            .resolve_weight(&attribute_list, 0)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            err,
+            CoreNftAttributeVoterError::AttributeValueNotWeighted.into()
+        );
+    }
+
+    #[test]
+    fn test_assert_is_valid_with_duplicated_key_error() {
+        // Arrange
+        let mut formula = tier_formula(WeightCombination::Sum);
+        formula.attributes[1].key = "tier".to_string();
+
+        // Act
+        let err = formula.assert_is_valid().err().unwrap();
+
+        // Assert
+        assert_eq!(err, CoreNftAttributeVoterError::InvalidWeightFormula.into());
+    }
+
+    #[test]
+    fn test_read_weight_from_app_data() {
+        // Arrange
+        let weight_source = WeightSource::AppData {
+            data_authority: PluginAuthority::UpdateAuthority,
+            offset: 2,
+        };
+
+        let mut data = vec![0xff, 0xff];
+        data.extend_from_slice(&1234u64.to_le_bytes());
+
+        // Act
+        let weight = weight_source.read_weight(&data);
+
+        // Assert
+        assert_eq!(weight.unwrap(), Some(1234));
+    }
+
+    #[test]
+    fn test_read_weight_from_app_data_without_data() {
+        // Arrange
+        let weight_source = WeightSource::LinkedAppData {
+            data_authority: PluginAuthority::UpdateAuthority,
+            offset: 0,
+        };
+
+        // Act
+        let weight = weight_source.read_weight(&[]);
+
+        // Assert
+        assert_eq!(weight.unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_weight_from_app_data_out_of_bounds_error() {
+        // Arrange
+        let weight_source = WeightSource::AppData {
+            data_authority: PluginAuthority::UpdateAuthority,
+            offset: 4,
+        };
+
+        // Act
+        let err = weight_source.read_weight(&[0; 8]).err().unwrap();
+
+        // Assert
+        assert_eq!(err, CoreNftAttributeVoterError::InvalidAppDataWeight.into());
+    }
 }
@@ -12,4 +12,7 @@ pub mod max_voter_weight_record;
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
 
+pub use weight_census::*;
+pub mod weight_census;
+
 pub mod idl_types;
@@ -1,11 +1,26 @@
 use crate::{
     error::CoreNftAttributeVoterError,
     id,
-    state::{CollectionConfig, VoterWeightRecord},
-    tools::anchor::DISCRIMINATOR_SIZE,
+    state::{
+        CollectionConfig, CollectionConfigExtension, CollectionKind, MissingAttributePolicy,
+        VoterWeightRecord, WeightSource,
+    },
+    tools::{
+        anchor::DISCRIMINATOR_SIZE,
+        core_asset::{
+            is_core_asset_frozen_by, is_plugin_not_found_error, plugin_error_to_program_error,
+        },
+    },
 };
 use anchor_lang::prelude::*;
-use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority, types::PluginType, fetch_plugin};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_external_plugin_adapter_data_info, fetch_plugin,
+    types::{
+        ExternalPluginAdapterKey, LinkedDataKey, PluginAuthority, PluginType, UpdateAuthority,
+        VerifiedCreators,
+    },
+};
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
 use spl_governance::state::{enums::ProposalState, proposal, token_owner_record};
 
@@ -28,20 +43,56 @@ pub struct Registrar {
     /// Core Collection used for voting
     pub collection_configs: Vec<CollectionConfig>,
 
+    /// When set only assets frozen by FreezeDelegate or PermanentFreezeDelegate plugin
+    /// with the given authority can be used for governance
+    /// Frozen assets can't be transferred and used again by another wallet while they are locked
+    pub required_freeze_authority: Option<PluginAuthority>,
+
+    /// Version of the Registrar account layout
+    /// Registrars created before collection_config_extensions was added have version 0
+    /// and must be migrated using migrate_registrar before collections can be configured
+    pub version: u8,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 93],
+
+    /// Weight options of the configured collections which don't fit into CollectionConfig
+    /// Note: The extensions are stored after the reserved space to preserve the layout of version 0 Registrars
+    pub collection_config_extensions: Vec<CollectionConfigExtension>,
 }
 
+/// The current version of the Registrar account layout
+pub const CURRENT_REGISTRAR_VERSION: u8 = 1;
+
 impl Registrar {
     pub fn get_space(max_collections: u8) -> usize {
         DISCRIMINATOR_SIZE
             + PUBKEY_BYTES * 3
             + 4
             + max_collections as usize * CollectionConfig::SERIALIZED_SIZE
-            + 128
+            + 1
+            + 33
+            + 1
+            + 93
+            + 4
+            + max_collections as usize * CollectionConfigExtension::MIN_SERIALIZED_SIZE
     }
 }
 
+/// Registrar account layout before collection_config_extensions was added (version 0)
+/// Version 0 Registrars without spare space can't be deserialized as Registrar and are read
+/// using this layout by migrate_registrar
+#[derive(AnchorDeserialize, Debug)]
+pub struct RegistrarV0 {
+    pub governance_program_id: Pubkey,
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub collection_configs: Vec<CollectionConfig>,
+    pub required_freeze_authority: Option<PluginAuthority>,
+    pub version: u8,
+    pub reserved: [u8; 93],
+}
+
 /// Returns Registrar PDA seeds
 pub fn get_registrar_seeds<'a>(
     realm: &'a Pubkey,
@@ -64,13 +115,47 @@ impl Registrar {
             .ok_or_else(|| CoreNftAttributeVoterError::CollectionNotFound.into());
     }
 
-    pub fn max_voter_weight(&self) -> Result<u64> {
+    /// Returns the config for the given collection or update authority and asserts it's of the given kind
+    pub fn get_collection_config_of_kind(
+        &self,
+        collection: Pubkey,
+        kind: CollectionKind,
+    ) -> Result<&CollectionConfig> {
+        let collection_config = self.get_collection_config(collection)?;
+
+        require!(
+            collection_config.kind == kind,
+            CoreNftAttributeVoterError::InvalidNftCollection
+        );
+
+        Ok(collection_config)
+    }
+
+    /// Returns the weight options of the given collection
+    /// Collections without an extension use the default options
+    pub fn get_collection_config_extension(&self, collection: Pubkey) -> CollectionConfigExtension {
+        self.collection_config_extensions
+            .iter()
+            .find(|ce| ce.collection == collection)
+            .cloned()
+            .unwrap_or_else(|| CollectionConfigExtension::new_default(collection))
+    }
+
+    /// Returns the max voter weight of the collections using their hand-set total_weight
+    /// Returns None when any of the collections uses its WeightCensus whose total_weight
+    /// can only be resolved by update_max_voter_weight_record
+    pub fn max_voter_weight(&self) -> Result<Option<u64>> {
+        if self.collection_configs.iter().any(|cc| cc.use_weight_census) {
+            return Ok(None);
+        }
+
         self.collection_configs
             .iter()
             .try_fold(0u64, |sum, cc| {
                 sum.checked_add(cc.total_weight)
                     .ok_or_else(|| CoreNftAttributeVoterError::ArithmeticOverflow.into())
             })
+            .map(Some)
     }
 }
 
@@ -125,15 +210,17 @@ pub fn resolve_proposal_account(
 }
 
 /// Resolves vote weight and voting mint for the given NFT
-/// Reads the weight from the NFT's Attributes plugin using the collection's weight_attribute_key
+/// Reads the weight from the plugin configured by the collection's weight_source
+/// Returns None when the NFT should be skipped according to the collection's missing_attribute_policy
 pub fn resolve_nft_vote_weight_and_mint(
     registrar: &Registrar,
     governing_token_owner: &Pubkey,
     asset_key: Pubkey,
     asset: &BaseAssetV1,
     asset_account_info: &AccountInfo,
+    collection_accounts: &[AccountInfo],
     unique_nft_mints: &mut Vec<Pubkey>,
-) -> Result<(u64, Pubkey)> {
+) -> Result<Option<(u64, Pubkey)>> {
     let nft_owner = asset.owner;
 
     // voter_weight_record.governing_token_owner must be the owner of the NFT
@@ -150,46 +237,176 @@ pub fn resolve_nft_vote_weight_and_mint(
     }
     unique_nft_mints.push(nft_mint);
 
-    // The Core NFT must have a collection and the collection must be verified
-    let collection = match asset.update_authority {
+    // The asset must be locked by the required freeze authority to prevent it from being transferred
+    // and used again by another wallet
+    if let Some(required_freeze_authority) = &registrar.required_freeze_authority {
+        require!(
+            is_core_asset_frozen_by(asset_account_info, required_freeze_authority),
+            CoreNftAttributeVoterError::AssetMustBeFrozen
+        );
+    }
+
+    // The Core NFT must have a collection or an update authority configured for the Registrar
+    let collection_config = match asset.update_authority {
         UpdateAuthority::Collection(collection) => {
-            collection
+            registrar.get_collection_config_of_kind(collection, CollectionKind::Collection)?
+        }
+        UpdateAuthority::Address(update_authority) => {
+            // Anybody can create an asset with any update authority and the update authority
+            // must have verified the asset as its creator
+            require!(
+                is_verified_creator(asset_account_info, &update_authority),
+                CoreNftAttributeVoterError::UpdateAuthorityNotVerified
+            );
+
+            registrar.get_collection_config_of_kind(update_authority, CollectionKind::UpdateAuthority)?
+        }
+        UpdateAuthority::None => return Err(CoreNftAttributeVoterError::InvalidNftCollection.into()),
+    };
+
+    let collection_config_extension =
+        registrar.get_collection_config_extension(collection_config.collection);
+
+    let asset_weight = match &collection_config_extension.weight_source {
+        WeightSource::Attributes => {
+            // Resolve the weight from the asset's Attributes plugin
+            // A missing plugin is handled the same way as missing attributes and malformed plugin data fails
+            match fetch_plugin::<BaseAssetV1, mpl_core::types::Attributes>(
+                asset_account_info,
+                PluginType::Attributes,
+            ) {
+                Ok((authority, attributes, _)) => {
+                    // Verify the Attributes plugin authority matches the expected authority
+                    require!(
+                        authority == collection_config.expected_attribute_authority,
+                        CoreNftAttributeVoterError::AttributeAuthorityMismatch
+                    );
+
+                    collection_config
+                        .resolve_weight(&collection_config_extension, &attributes.attribute_list)?
+                }
+                Err(error) if is_plugin_not_found_error(&error) => None,
+                Err(error) => return Err(plugin_error_to_program_error(error)),
+            }
+        }
+        WeightSource::AppData { data_authority, .. } => resolve_app_data_weight(
+            &collection_config_extension.weight_source,
+            asset_account_info,
+            &ExternalPluginAdapterKey::AppData(data_authority.clone()),
+        )?,
+        WeightSource::LinkedAppData { data_authority, .. } => resolve_app_data_weight(
+            &collection_config_extension.weight_source,
+            asset_account_info,
+            &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
+                data_authority.clone(),
+            )),
+        )?,
+    };
+
+    let weight = match asset_weight {
+        Some(weight) => weight,
+        None => match collection_config_extension.missing_attribute_policy {
+            MissingAttributePolicy::Reject => {
+                return err!(CoreNftAttributeVoterError::AttributeNotFound)
+            }
+            MissingAttributePolicy::Skip => return Ok(None),
+            MissingAttributePolicy::DefaultWeight { weight } => weight,
+            MissingAttributePolicy::CollectionAttribute => {
+                resolve_collection_attribute_weight(
+                    collection_config,
+                    &collection_config_extension,
+                    collection_accounts,
+                )?
+            }
         },
-        _ => return Err(CoreNftAttributeVoterError::InvalidNftCollection.into())
     };
 
-    let collection_config = registrar.get_collection_config(collection)?;
+    // Cap the weight to max_weight to prevent any single NFT from exceeding the configured ceiling
+    let capped_weight = weight.min(collection_config.max_weight);
+
+    Ok(Some((capped_weight, nft_mint)))
+}
 
-    // Fetch the Attributes plugin from the asset
-    let (authority, attributes, _) = fetch_plugin::<BaseAssetV1, mpl_core::types::Attributes>(
+/// Returns true if the given creator is verified in the asset's VerifiedCreators plugin
+fn is_verified_creator(asset_info: &AccountInfo, creator: &Pubkey) -> bool {
+    fetch_plugin::<BaseAssetV1, VerifiedCreators>(asset_info, PluginType::VerifiedCreators)
+        .map(|(_, verified_creators, _)| {
+            verified_creators
+                .signatures
+                .iter()
+                .any(|signature| signature.address == *creator && signature.verified)
+        })
+        .unwrap_or(false)
+}
+
+/// Resolves the weight from the asset's external plugin data identified by the given key
+/// The plugin is looked up by its data authority and plugins written by any other authority are not trusted
+/// A missing plugin is handled the same way as missing attributes and malformed plugin data fails
+fn resolve_app_data_weight(
+    weight_source: &WeightSource,
+    asset_account_info: &AccountInfo,
+    plugin_key: &ExternalPluginAdapterKey,
+) -> Result<Option<u64>> {
+    let (data_offset, data_len) = match fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
         asset_account_info,
+        None,
+        plugin_key,
+    ) {
+        Ok(data_info) => data_info,
+        Err(error) if is_plugin_not_found_error(&error) => return Ok(None),
+        Err(error) => return Err(plugin_error_to_program_error(error)),
+    };
+
+    let asset_data = asset_account_info.data.borrow();
+    let plugin_data = asset_data
+        .get(data_offset..data_offset.saturating_add(data_len))
+        .ok_or(CoreNftAttributeVoterError::InvalidAppDataWeight)?;
+
+    weight_source.read_weight(plugin_data)
+}
+
+/// Resolves the weight from the Attributes plugin of the collection itself
+fn resolve_collection_attribute_weight(
+    collection_config: &CollectionConfig,
+    collection_config_extension: &CollectionConfigExtension,
+    collection_accounts: &[AccountInfo],
+) -> Result<u64> {
+    let collection_info = collection_accounts
+        .iter()
+        .find(|ci| ci.key == &collection_config.collection)
+        .ok_or(CoreNftAttributeVoterError::MissingCollectionAccount)?;
+
+    let (authority, attributes, _) = fetch_plugin::<BaseCollectionV1, mpl_core::types::Attributes>(
+        collection_info,
         PluginType::Attributes,
     )
     .map_err(|_| CoreNftAttributeVoterError::AttributeNotFound)?;
 
-    // Verify the Attributes plugin authority matches the expected authority
+    // The collection attributes must be set by the same authority trusted for the asset attributes
     require!(
         authority == collection_config.expected_attribute_authority,
         CoreNftAttributeVoterError::AttributeAuthorityMismatch
     );
 
-    // Find the attribute matching the collection's weight_attribute_key
-    let weight_value = attributes
-        .attribute_list
-        .iter()
-        .find(|attr| attr.key == collection_config.weight_attribute_key)
-        .ok_or(CoreNftAttributeVoterError::AttributeNotFound)?;
-
-    // Parse the attribute value as u64
-    let weight: u64 = weight_value
-        .value
-        .parse::<u64>()
-        .map_err(|_| CoreNftAttributeVoterError::InvalidAttributeValue)?;
+    collection_config
+        .resolve_weight(collection_config_extension, &attributes.attribute_list)?
+        .ok_or_else(|| CoreNftAttributeVoterError::AttributeNotFound.into())
+}
 
-    // Cap the weight to max_weight to prevent any single NFT from exceeding the configured ceiling
-    let capped_weight = weight.min(collection_config.max_weight);
+/// Splits the leading Core collection accounts from the rest of the given accounts
+/// The collection accounts are used to resolve the weight for MissingAttributePolicy::CollectionAttribute
+pub fn split_collection_accounts<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> (&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]) {
+    let collections_count = accounts
+        .iter()
+        .take_while(|info| {
+            info.owner == &mpl_core::ID
+                && info.data.borrow().first() == Some(&(mpl_core::types::Key::CollectionV1 as u8))
+        })
+        .count();
 
-    Ok((capped_weight, nft_mint))
+    accounts.split_at(collections_count)
 }
 
 #[cfg(test)]
@@ -211,7 +428,16 @@ mod test {
                 CollectionConfig::default(),
                 CollectionConfig::default(),
             ],
-            reserved: [0; 128],
+            required_freeze_authority: Some(PluginAuthority::Address {
+                address: Pubkey::default(),
+            }),
+            version: CURRENT_REGISTRAR_VERSION,
+            reserved: [0; 93],
+            collection_config_extensions: vec![
+                CollectionConfigExtension::new_default(Pubkey::default()),
+                CollectionConfigExtension::new_default(Pubkey::default()),
+                CollectionConfigExtension::new_default(Pubkey::default()),
+            ],
         };
 
         // Act
@@ -220,4 +446,38 @@ mod test {
         // Assert
         assert_eq!(expected_space, actual_space);
     }
+
+    #[test]
+    fn test_deserialize_version_0_registrar() {
+        // Arrange
+        let registrar = Registrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            collection_configs: vec![CollectionConfig::default()],
+            required_freeze_authority: None,
+            version: 0,
+            reserved: [0; 93],
+            collection_config_extensions: vec![],
+        };
+
+        // Version 0 layout without the trailing collection_config_extensions
+        let mut registrar_data = registrar.try_to_vec().unwrap();
+        registrar_data.truncate(registrar_data.len() - 4);
+
+        // Act
+        let registrar_v0 = RegistrarV0::deserialize(&mut &registrar_data[..]).unwrap();
+        let registrar_without_spare_space = Registrar::deserialize(&mut &registrar_data[..]);
+
+        registrar_data.extend_from_slice(&[0; CollectionConfig::SERIALIZED_SIZE]);
+        let registrar_with_spare_space = Registrar::deserialize(&mut &registrar_data[..]).unwrap();
+
+        // Assert
+        assert_eq!(registrar_v0.realm, registrar.realm);
+        assert_eq!(registrar_v0.collection_configs, registrar.collection_configs);
+        assert_eq!(registrar_v0.version, 0);
+
+        assert!(registrar_without_spare_space.is_err());
+        assert_eq!(registrar_with_spare_space, registrar);
+    }
 }
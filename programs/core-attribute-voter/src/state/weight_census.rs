@@ -0,0 +1,101 @@
+use crate::id;
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Census of the total attribute weight of a collection
+/// The census is attested by the collection's expected_attribute_authority and when enabled for the collection
+/// it's used instead of CollectionConfig.total_weight to calculate MaxVoterWeightRecord.max_voter_weight
+/// The PDA of the census is ["weight-census",registrar,collection]
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct WeightCensus {
+    /// The Registrar the census belongs to
+    pub registrar: Pubkey,
+
+    /// The collection the census is for
+    pub collection: Pubkey,
+
+    /// The summed attribute weight of all NFTs in the collection attested by the attribute authority
+    pub total_weight: u64,
+
+    /// Unix timestamp when total_weight was last attested
+    /// It's 0 when the census was never attested
+    pub attested_at: i64,
+
+    /// Max age in seconds of the attestation for the census to be used
+    /// Set by the Realm authority
+    pub max_age: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 64],
+}
+
+impl WeightCensus {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 2 + 8 + 8 + 8 + 64
+    }
+
+    /// Returns true if the census was attested within max_age at the given unix timestamp
+    pub fn is_current(&self, unix_timestamp: i64) -> bool {
+        self.attested_at > 0
+            && unix_timestamp
+                .checked_sub(self.attested_at)
+                .is_some_and(|age| age >= 0 && age as u64 <= self.max_age)
+    }
+}
+
+/// Returns WeightCensus PDA seeds
+pub fn get_weight_census_seeds<'a>(registrar: &'a Pubkey, collection: &'a Pubkey) -> [&'a [u8]; 3] {
+    [b"weight-census", registrar.as_ref(), collection.as_ref()]
+}
+
+/// Returns WeightCensus PDA address
+pub fn get_weight_census_address(registrar: &Pubkey, collection: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_weight_census_seeds(registrar, collection), &id()).0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = WeightCensus::get_space();
+
+        let weight_census = WeightCensus {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            total_weight: 0,
+            attested_at: 0,
+            max_age: 0,
+            reserved: [0; 64],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + weight_census.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_is_current() {
+        // Arrange
+        let weight_census = WeightCensus {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            total_weight: 100,
+            attested_at: 1_000,
+            max_age: 60,
+            reserved: [0; 64],
+        };
+
+        // Act + Assert
+        assert!(weight_census.is_current(1_000));
+        assert!(weight_census.is_current(1_060));
+        assert!(!weight_census.is_current(1_061));
+    }
+}
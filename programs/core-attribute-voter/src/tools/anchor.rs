@@ -1 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
 pub const DISCRIMINATOR_SIZE: usize = 8;
+
+/// Resizes the given program owned account to new_size
+/// The payer funds any additional rent required for the new size
+pub fn resize_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_size: usize,
+) -> Result<()> {
+    let lamports_diff = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account_info.lamports());
+
+    if lamports_diff > 0 {
+        let cpi_accounts = Transfer {
+            from: payer_info.clone(),
+            to: account_info.clone(),
+        };
+
+        let cpi_ctx = CpiContext::new(system_program_info.clone(), cpi_accounts);
+        system_program::transfer(cpi_ctx, lamports_diff)?;
+    }
+
+    account_info.realloc(new_size, false)?;
+
+    Ok(())
+}
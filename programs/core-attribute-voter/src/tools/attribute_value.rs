@@ -0,0 +1,167 @@
+use std::convert::TryFrom;
+
+use anchor_lang::prelude::*;
+
+use crate::error::CoreNftAttributeVoterError;
+
+/// Max number of decimals attribute values can be scaled by
+pub const MAX_VALUE_DECIMALS: u8 = 9;
+
+/// Max number of significant mantissa digits used to resolve the weight
+/// The number of digits always fits into u128 and any further digits are rounded down
+pub const MAX_SIGNIFICANT_DIGITS: usize = 38;
+
+/// Parses attribute value into a fixed-point u64 weight scaled by 10^decimals
+///
+/// The value can be an integer ("12", "+12"), a decimal ("12.5") or use exponent notation ("1e3", "2.5E-1")
+/// Digits below the given decimals precision are rounded down
+/// Only the first MAX_SIGNIFICANT_DIGITS significant digits of the mantissa are used and the remaining ones are rounded down
+/// Negative values are rejected
+pub fn parse_attribute_value(value: &str, decimals: u8) -> Result<u64> {
+    let value = value.trim();
+
+    require!(
+        !value.starts_with('-'),
+        CoreNftAttributeVoterError::NegativeAttributeValue
+    );
+
+    let value = value.strip_prefix('+').unwrap_or(value);
+
+    let (mantissa, exponent) = match value.find(['e', 'E']) {
+        Some(idx) => (
+            &value[..idx],
+            value[idx + 1..]
+                .parse::<i32>()
+                .map_err(|_| CoreNftAttributeVoterError::InvalidAttributeValue)?,
+        ),
+        None => (value, 0),
+    };
+
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+        None => (mantissa, ""),
+    };
+
+    require!(
+        !(integer.is_empty() && fraction.is_empty())
+            && integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit()),
+        CoreNftAttributeVoterError::InvalidAttributeValue
+    );
+
+    let mut digits = 0u128;
+    let mut dropped_digits = 0usize;
+
+    for (idx, c) in integer
+        .chars()
+        .chain(fraction.chars())
+        .skip_while(|c| *c == '0')
+        .enumerate()
+    {
+        if idx < MAX_SIGNIFICANT_DIGITS {
+            digits = digits * 10 + c.to_digit(10).unwrap() as u128;
+        } else {
+            dropped_digits += 1;
+        }
+    }
+
+    // The power of 10 the digits have to be scaled by to get the fixed-point weight
+    let scale = i32::try_from(dropped_digits)
+        .ok()
+        .and_then(|dropped_digits| exponent.checked_add(dropped_digits))
+        .and_then(|scale| scale.checked_add(decimals as i32))
+        .and_then(|scale| scale.checked_sub(i32::try_from(fraction.len()).ok()?))
+        .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?;
+
+    let weight = if digits == 0 {
+        0
+    } else if scale >= 0 {
+        10u128
+            .checked_pow(scale as u32)
+            .and_then(|factor| digits.checked_mul(factor))
+            .ok_or(CoreNftAttributeVoterError::ArithmeticOverflow)?
+    } else {
+        // Round down when the value has more precision than the configured decimals
+        match 10u128.checked_pow(scale.unsigned_abs()) {
+            Some(divisor) => digits / divisor,
+            None => 0,
+        }
+    };
+
+    u64::try_from(weight).map_err(|_| CoreNftAttributeVoterError::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_attribute_value() {
+        assert_eq!(parse_attribute_value("12", 0).unwrap(), 12);
+        assert_eq!(parse_attribute_value("12", 2).unwrap(), 1200);
+        assert_eq!(parse_attribute_value("12.5", 1).unwrap(), 125);
+        assert_eq!(parse_attribute_value(".5", 2).unwrap(), 50);
+        assert_eq!(parse_attribute_value("1e3", 0).unwrap(), 1000);
+        assert_eq!(parse_attribute_value("2.5E-1", 2).unwrap(), 25);
+        assert_eq!(parse_attribute_value("+12", 0).unwrap(), 12);
+        assert_eq!(parse_attribute_value("+1.5e1", 1).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_parse_attribute_value_with_long_mantissa() {
+        // 45 significant digits are rounded down to MAX_SIGNIFICANT_DIGITS
+        assert_eq!(
+            parse_attribute_value("123456789012345678901234567890123456789012345e-40", 0)
+                .unwrap(),
+            12345
+        );
+        assert_eq!(
+            parse_attribute_value("0.00000000000000000000000000000000000000000000000012", 0)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            parse_attribute_value("000000000000000000000000000000000000000000000012", 0)
+                .unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_parse_attribute_value_rounds_down() {
+        assert_eq!(parse_attribute_value("12.59", 1).unwrap(), 125);
+        assert_eq!(parse_attribute_value("0.5", 0).unwrap(), 0);
+        assert_eq!(parse_attribute_value("1e-100", 9).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_parse_attribute_value_with_negative_value_error() {
+        assert_eq!(
+            parse_attribute_value("-1", 0).err().unwrap(),
+            CoreNftAttributeVoterError::NegativeAttributeValue.into()
+        );
+    }
+
+    #[test]
+    fn test_parse_attribute_value_with_invalid_value_error() {
+        for value in ["", ".", "1.2.3", "abc", "1e", "0x10", "+", "++1", "+-1"].iter() {
+            assert_eq!(
+                parse_attribute_value(value, 0).err().unwrap(),
+                CoreNftAttributeVoterError::InvalidAttributeValue.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_attribute_value_with_overflow_error() {
+        assert_eq!(
+            parse_attribute_value("18446744073709551616", 0)
+                .err()
+                .unwrap(),
+            CoreNftAttributeVoterError::ArithmeticOverflow.into()
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::CoreNftAttributeVoterError;
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    errors::MplCoreError,
+    fetch_plugin,
+    types::{FreezeDelegate, PermanentFreezeDelegate, PluginAuthority, PluginType},
+};
+
+/// Returns true if the given Core asset is frozen by its FreezeDelegate or PermanentFreezeDelegate plugin
+/// and the frozen plugin's authority is the given freeze_authority
+pub fn is_core_asset_frozen_by(asset_info: &AccountInfo, freeze_authority: &PluginAuthority) -> bool {
+    let is_frozen =
+        fetch_plugin::<BaseAssetV1, FreezeDelegate>(asset_info, PluginType::FreezeDelegate)
+            .map(|(authority, freeze_delegate, _)| {
+                freeze_delegate.frozen && authority == *freeze_authority
+            })
+            .unwrap_or(false);
+
+    is_frozen
+        || fetch_plugin::<BaseAssetV1, PermanentFreezeDelegate>(
+            asset_info,
+            PluginType::PermanentFreezeDelegate,
+        )
+        .map(|(authority, permanent_freeze_delegate, _)| {
+            permanent_freeze_delegate.frozen && authority == *freeze_authority
+        })
+        .unwrap_or(false)
+}
+
+/// Returns true if the given fetch_plugin or fetch_external_plugin_adapter_data_info error
+/// means the plugin doesn't exist on the account
+/// Any other error means the plugin data is malformed and it must not be treated as a missing plugin
+pub fn is_plugin_not_found_error(error: &std::io::Error) -> bool {
+    let message = error.to_string();
+
+    message == MplCoreError::PluginNotFound.to_string()
+        || message == MplCoreError::ExternalPluginAdapterNotFound.to_string()
+}
+
+/// Converts the given fetch_plugin or fetch_external_plugin_adapter_data_info error into program error
+pub fn plugin_error_to_program_error(error: std::io::Error) -> Error {
+    msg!("Core plugin error: {}", error);
+    CoreNftAttributeVoterError::InvalidAssetPluginData.into()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_is_plugin_not_found_error() {
+        // Arrange
+        let not_found_error = std::io::Error::new(
+            std::io::ErrorKind::Other,
+            MplCoreError::PluginNotFound.to_string(),
+        );
+        let external_not_found_error = std::io::Error::new(
+            std::io::ErrorKind::Other,
+            MplCoreError::ExternalPluginAdapterNotFound.to_string(),
+        );
+        let deserialization_error =
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Unexpected length of input");
+
+        // Act
+        let is_not_found = is_plugin_not_found_error(&not_found_error);
+        let is_external_not_found = is_plugin_not_found_error(&external_not_found_error);
+        let is_deserialization_not_found = is_plugin_not_found_error(&deserialization_error);
+
+        // Assert
+        assert!(is_not_found);
+        assert!(is_external_not_found);
+        assert!(!is_deserialization_not_found);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod anchor;
 pub mod governance;
+pub mod attribute_value;
+pub mod core_asset;
@@ -1503,3 +1503,90 @@ async fn test_cast_asset_vote_with_multiple_collections() -> Result<(), Transpor
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_cast_asset_vote_with_skip_missing_attribute_policy() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_weight(&collection_cookie, &voter_cookie, 10)
+        .await?;
+
+    // Asset without the Attributes plugin
+    let asset_cookie2 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::Skip,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    // Act
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1, &asset_cookie2],
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    // The skipped asset is not recorded and can be used once its attributes are set
+    let skipped_asset_vote_record = core_voter_test
+        .bench
+        .get_account(&asset_vote_record_cookies[1].address)
+        .await;
+
+    assert_eq!(skipped_asset_vote_record, None);
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
+use anchor_lang::prelude::AccountMeta;
+use gpl_core_attribute_voter::state::*;
 use program_test::{
     core_voter_test::CoreVoterTest,
     tools::{assert_anchor_err, assert_nft_voter_err},
@@ -578,3 +580,440 @@ async fn test_configure_collection_with_too_long_weight_attribute_key_error(
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_configure_collection_with_weight_formula() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let weight_formula = WeightFormula {
+        combination: WeightCombination::Sum,
+        attributes: vec![
+            WeightAttribute {
+                key: "tier".to_string(),
+                multiplier: 1,
+                value_weights: vec![
+                    AttributeValueWeight {
+                        value: "gold".to_string(),
+                        weight: 10,
+                    },
+                    AttributeValueWeight {
+                        value: "silver".to_string(),
+                        weight: 5,
+                    },
+                ],
+            },
+            WeightAttribute {
+                key: "level".to_string(),
+                multiplier: 2,
+                value_weights: vec![],
+            },
+        ],
+    };
+
+    // Act
+    let collection_config_cookie = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_formula: Some(weight_formula),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs.len(), 1);
+
+    assert_eq!(
+        registrar.collection_configs[0],
+        collection_config_cookie.collection_config
+    );
+
+    assert_eq!(
+        registrar.collection_config_extensions,
+        vec![collection_config_cookie.collection_config_extension]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_invalid_weight_formula_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Multiplier must be greater than 0
+    let weight_formula = WeightFormula {
+        combination: WeightCombination::Product,
+        attributes: vec![WeightAttribute {
+            key: "level".to_string(),
+            multiplier: 0,
+            value_weights: vec![],
+        }],
+    };
+
+    // Act
+    let err = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight_formula: Some(weight_formula),
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidWeightFormula);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_invalid_value_decimals_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                value_decimals: 10,
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidValueDecimals);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_app_data_weight_source() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let weight_source = WeightSource::AppData {
+        data_authority: mpl_core::types::PluginAuthority::Address {
+            address: core_voter_test.bench.payer.pubkey(),
+        },
+        offset: 8,
+    };
+
+    // Act
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_source: weight_source.clone(),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.collection_config_extensions[0].weight_source,
+        weight_source
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_app_data_weight_source_and_weight_formula_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let weight_formula = WeightFormula {
+        combination: WeightCombination::Sum,
+        attributes: vec![WeightAttribute {
+            key: "level".to_string(),
+            multiplier: 1,
+            value_weights: vec![],
+        }],
+    };
+
+    // Act
+    let err = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight_formula: Some(weight_formula),
+                weight_source: WeightSource::AppData {
+                    data_authority: mpl_core::types::PluginAuthority::UpdateAuthority,
+                    offset: 0,
+                },
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidWeightSource);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_linked_app_data_weight_source_and_collection_attribute_policy_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                missing_attribute_policy: MissingAttributePolicy::CollectionAttribute,
+                weight_source: WeightSource::LinkedAppData {
+                    data_authority: mpl_core::types::PluginAuthority::UpdateAuthority,
+                    offset: 0,
+                },
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidWeightSource);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_without_payer() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let collection_config_cookie = core_voter_test
+        .with_collection_using_ix(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+            |i| {
+                // The payer and system_program are not required when the Registrar doesn't have to be resized
+                let none_account = AccountMeta::new_readonly(gpl_core_attribute_voter::id(), false);
+                i.accounts[5] = none_account.clone(); // payer
+                i.accounts[6] = none_account; // system_program
+            },
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.collection_configs,
+        vec![collection_config_cookie.collection_config]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_registrar_version_0_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Version 0 Registrar with spare space can still be deserialized
+    core_voter_test
+        .with_registrar_version_0(&registrar_cookie, CollectionConfig::SERIALIZED_SIZE)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::RegistrarMustBeMigrated);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_weight_census_collection() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie1 = core_voter_test.core.create_collection(None).await?;
+    let collection_cookie2 = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie1,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { max_weight: 1, ..Default::default() }),
+        )
+        .await?;
+
+    core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie1,
+            &max_voter_weight_record_cookie,
+            Some(100),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie2,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { max_weight: 2, ..Default::default() }),
+        )
+        .await?;
+
+    // Assert
+
+    // The census total weight can only be resolved by update_max_voter_weight_record
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
+use gpl_core_attribute_voter::state::*;
+use program_test::core_voter_test::{ConfigureCollectionArgs, CoreVoterTest};
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_update_authority() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let collection_config_cookie = core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                total_weight: Some(100),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.collection_configs,
+        vec![collection_config_cookie.collection_config]
+    );
+
+    assert_eq!(
+        registrar.collection_configs[0].kind,
+        CollectionKind::UpdateAuthority
+    );
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 100);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_collection_attribute_policy_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::CollectionAttribute,
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        CoreNftAttributeVoterError::InvalidMissingAttributePolicy,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_configured_collection_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &collection_cookie.collection,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidNftCollection);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .with_update_authority_using_ix(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            None,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
+use program_test::{
+    core_voter_test::{ConfigureCollectionArgs, CoreVoterTest},
+    tools::assert_nft_voter_err,
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_weight_census() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Act
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    // Assert
+    let weight_census = core_voter_test
+        .get_weight_census_account(&weight_census_cookie.address)
+        .await;
+
+    assert_eq!(weight_census_cookie.account, weight_census);
+
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(registrar.collection_configs[0].use_weight_census);
+
+    // The census total weight can only be resolved by update_max_voter_weight_record
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_weight_census_with_disable() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(!registrar.collection_configs[0].use_weight_census);
+
+    // The hand-set total_weight is used again and MaxVoterWeightRecord is refreshed
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 10);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_weight_census_with_collection_not_found_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::CollectionNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_weight_census_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .with_weight_census_using_ix(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
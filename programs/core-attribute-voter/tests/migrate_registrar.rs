@@ -0,0 +1,126 @@
+use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
+use gpl_core_attribute_voter::state::*;
+use program_test::core_voter_test::{ConfigureCollectionArgs, CoreVoterTest};
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_migrate_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_config_cookie = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Version 0 Registrar without any spare space for collection_config_extensions
+    core_voter_test
+        .with_registrar_version_0(&registrar_cookie, 0)
+        .await?;
+
+    // Act
+    core_voter_test.migrate_registrar(&registrar_cookie).await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.version, CURRENT_REGISTRAR_VERSION);
+
+    assert_eq!(
+        registrar.collection_configs,
+        vec![collection_config_cookie.collection_config]
+    );
+
+    assert_eq!(registrar.collection_config_extensions, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_registrar_and_configure_collection() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_registrar_version_0(&registrar_cookie, 0)
+        .await?;
+
+    core_voter_test.migrate_registrar(&registrar_cookie).await?;
+
+    // Act
+    let collection_config_cookie = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                missing_attribute_policy: MissingAttributePolicy::DefaultWeight { weight: 5 },
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.collection_config_extensions,
+        vec![collection_config_cookie.collection_config_extension]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_registrar_with_already_migrated_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = core_voter_test
+        .migrate_registrar(&registrar_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::RegistrarAlreadyMigrated);
+
+    Ok(())
+}
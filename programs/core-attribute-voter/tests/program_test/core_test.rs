@@ -42,6 +42,55 @@ impl CoreTest {
     pub async fn create_collection(
         &self,
         collection_size: Option<u64>,
+    ) -> Result<CollectionCookie, TransportError> {
+        self.create_collection_with_attributes(collection_size, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_collection_with_attributes(
+        &self,
+        collection_size: Option<u64>,
+        attributes: Option<Vec<mpl_core::types::Attribute>>,
+    ) -> Result<CollectionCookie, TransportError> {
+        let plugins = attributes.map(|attrs| {
+            vec![mpl_core::types::PluginAuthorityPair {
+                plugin: mpl_core::types::Plugin::Attributes(mpl_core::types::Attributes {
+                    attribute_list: attrs,
+                }),
+                authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+            }]
+        });
+
+        self.create_collection_with_plugins(collection_size, plugins, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_collection_with_linked_app_data(
+        &self,
+        data_authority: mpl_core::types::PluginAuthority,
+    ) -> Result<CollectionCookie, TransportError> {
+        let external_plugin_adapters = vec![
+            mpl_core::types::ExternalPluginAdapterInitInfo::LinkedAppData(
+                mpl_core::types::LinkedAppDataInitInfo {
+                    data_authority,
+                    init_plugin_authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+                    schema: Some(mpl_core::types::ExternalPluginAdapterSchema::Binary),
+                },
+            ),
+        ];
+
+        self.create_collection_with_plugins(None, None, Some(external_plugin_adapters))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_collection_with_plugins(
+        &self,
+        collection_size: Option<u64>,
+        plugins: Option<Vec<mpl_core::types::PluginAuthorityPair>>,
+        external_plugin_adapters: Option<Vec<mpl_core::types::ExternalPluginAdapterInitInfo>>,
     ) -> Result<CollectionCookie, TransportError> {
         let update_authority = self.bench.context.borrow().payer.pubkey();
         let payer = self.bench.context.borrow().payer.pubkey();
@@ -57,8 +106,8 @@ impl CoreTest {
         let args = mpl_core::instructions::CreateCollectionV2InstructionArgs {
             name: coll_name,
             uri: coll_uri,
-            plugins: None,
-            external_plugin_adapters: None,
+            plugins,
+            external_plugin_adapters,
         };
 
         // instruction accounts
@@ -111,15 +160,6 @@ impl CoreTest {
         asset_owner_cookie: &WalletCookie,
         attributes: Option<Vec<mpl_core::types::Attribute>>,
     ) -> Result<AssetCookie, TransportError> {
-        let collection_authority = self.bench.context.borrow().payer.pubkey();
-        let payer = self.bench.context.borrow().payer.pubkey();
-
-        // Create Asset
-        let asset_keypair = Keypair::new();
-
-        let name = "TestAsset".to_string();
-        let uri = "URI".to_string();
-
         let plugins = attributes.map(|attrs| {
             vec![mpl_core::types::PluginAuthorityPair {
                 plugin: mpl_core::types::Plugin::Attributes(mpl_core::types::Attributes {
@@ -129,13 +169,34 @@ impl CoreTest {
             }]
         });
 
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, plugins, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_asset_with_plugins(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        plugins: Option<Vec<mpl_core::types::PluginAuthorityPair>>,
+        external_plugin_adapters: Option<Vec<mpl_core::types::ExternalPluginAdapterInitInfo>>,
+    ) -> Result<AssetCookie, TransportError> {
+        let collection_authority = self.bench.context.borrow().payer.pubkey();
+        let payer = self.bench.context.borrow().payer.pubkey();
+
+        // Create Asset
+        let asset_keypair = Keypair::new();
+
+        let name = "TestAsset".to_string();
+        let uri = "URI".to_string();
+
         // instruction args
         let args = mpl_core::instructions::CreateV2InstructionArgs {
             data_state: mpl_core::types::DataState::AccountState,
             name,
             uri,
             plugins,
-            external_plugin_adapters: None,
+            external_plugin_adapters,
         };
 
         // instruction accounts
@@ -162,6 +223,73 @@ impl CoreTest {
         })
     }
 
+    /// Creates asset without a collection with UpdateAuthority::Address update authority and the given weight attribute
+    /// The update authority signs the creation and is added to the asset's VerifiedCreators plugin
+    #[allow(dead_code)]
+    pub async fn create_asset_with_update_authority(
+        &self,
+        update_authority: &Keypair,
+        asset_owner_cookie: &WalletCookie,
+        weight: u64,
+        verified: bool,
+    ) -> Result<AssetCookie, TransportError> {
+        let payer = self.bench.context.borrow().payer.pubkey();
+
+        let asset_keypair = Keypair::new();
+
+        let plugins = vec![
+            mpl_core::types::PluginAuthorityPair {
+                plugin: mpl_core::types::Plugin::VerifiedCreators(
+                    mpl_core::types::VerifiedCreators {
+                        signatures: vec![mpl_core::types::VerifiedCreatorsSignature {
+                            address: update_authority.pubkey(),
+                            verified,
+                        }],
+                    },
+                ),
+                authority: None,
+            },
+            mpl_core::types::PluginAuthorityPair {
+                plugin: mpl_core::types::Plugin::Attributes(mpl_core::types::Attributes {
+                    attribute_list: vec![mpl_core::types::Attribute {
+                        key: "weight".to_string(),
+                        value: weight.to_string(),
+                    }],
+                }),
+                authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+            },
+        ];
+
+        let args = mpl_core::instructions::CreateV2InstructionArgs {
+            data_state: mpl_core::types::DataState::AccountState,
+            name: "TestAsset".to_string(),
+            uri: "URI".to_string(),
+            plugins: Some(plugins),
+            external_plugin_adapters: None,
+        };
+
+        let create_accounts = mpl_core::instructions::CreateV2 {
+            asset: asset_keypair.pubkey(),
+            collection: None,
+            authority: Some(update_authority.pubkey()),
+            payer,
+            owner: Some(asset_owner_cookie.address),
+            update_authority: Some(update_authority.pubkey()),
+            system_program: system_program::ID,
+            log_wrapper: None,
+        };
+
+        let create_ix = create_accounts.instruction(args);
+
+        self.bench
+            .process_transaction(&[create_ix], Some(&[&asset_keypair, update_authority]))
+            .await?;
+
+        Ok(AssetCookie {
+            asset: asset_keypair.pubkey(),
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn create_asset_with_weight(
         &self,
@@ -180,6 +308,37 @@ impl CoreTest {
         .await
     }
 
+    /// Creates asset with the given weight attribute frozen by FreezeDelegate plugin with the given authority
+    #[allow(dead_code)]
+    pub async fn create_frozen_asset_with_weight(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        weight: u64,
+        freeze_authority: mpl_core::types::PluginAuthority,
+    ) -> Result<AssetCookie, TransportError> {
+        let plugins = vec![
+            mpl_core::types::PluginAuthorityPair {
+                plugin: mpl_core::types::Plugin::Attributes(mpl_core::types::Attributes {
+                    attribute_list: vec![mpl_core::types::Attribute {
+                        key: "weight".to_string(),
+                        value: weight.to_string(),
+                    }],
+                }),
+                authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+            },
+            mpl_core::types::PluginAuthorityPair {
+                plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                    frozen: true,
+                }),
+                authority: Some(freeze_authority),
+            },
+        ];
+
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, Some(plugins), None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn create_asset_with_named_weight(
         &self,
@@ -199,6 +358,108 @@ impl CoreTest {
         .await
     }
 
+    /// Creates an asset with AppData external plugin and writes the given data to it
+    /// The bench payer is used as the data authority
+    #[allow(dead_code)]
+    pub async fn create_asset_with_app_data(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        data: Vec<u8>,
+    ) -> Result<AssetCookie, TransportError> {
+        let data_authority = mpl_core::types::PluginAuthority::Address {
+            address: self.bench.payer.pubkey(),
+        };
+
+        let external_plugin_adapters = vec![mpl_core::types::ExternalPluginAdapterInitInfo::AppData(
+            mpl_core::types::AppDataInitInfo {
+                data_authority: data_authority.clone(),
+                init_plugin_authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+                schema: Some(mpl_core::types::ExternalPluginAdapterSchema::Binary),
+            },
+        )];
+
+        let asset_cookie = self
+            .create_asset_with_plugins(
+                collection_cookie,
+                asset_owner_cookie,
+                None,
+                Some(external_plugin_adapters),
+            )
+            .await?;
+
+        self.write_asset_plugin_data(
+            collection_cookie,
+            &asset_cookie,
+            mpl_core::types::ExternalPluginAdapterKey::AppData(data_authority),
+            data,
+        )
+        .await?;
+
+        Ok(asset_cookie)
+    }
+
+    /// Creates an asset in a collection with LinkedAppData external plugin and writes the given data
+    /// to the asset's data section
+    /// The bench payer is used as the data authority
+    #[allow(dead_code)]
+    pub async fn create_asset_with_linked_app_data(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        data: Vec<u8>,
+    ) -> Result<AssetCookie, TransportError> {
+        let data_authority = mpl_core::types::PluginAuthority::Address {
+            address: self.bench.payer.pubkey(),
+        };
+
+        let asset_cookie = self
+            .create_asset(collection_cookie, asset_owner_cookie)
+            .await?;
+
+        self.write_asset_plugin_data(
+            collection_cookie,
+            &asset_cookie,
+            mpl_core::types::ExternalPluginAdapterKey::LinkedAppData(data_authority),
+            data,
+        )
+        .await?;
+
+        Ok(asset_cookie)
+    }
+
+    #[allow(dead_code)]
+    pub async fn write_asset_plugin_data(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_cookie: &AssetCookie,
+        key: mpl_core::types::ExternalPluginAdapterKey,
+        data: Vec<u8>,
+    ) -> Result<(), TransportError> {
+        let payer = self.bench.payer.pubkey();
+
+        let args = mpl_core::instructions::WriteExternalPluginAdapterDataV1InstructionArgs {
+            key,
+            data: Some(data),
+        };
+
+        let write_accounts = mpl_core::instructions::WriteExternalPluginAdapterDataV1 {
+            asset: asset_cookie.asset,
+            collection: Some(collection_cookie.collection),
+            payer,
+            authority: Some(payer),
+            buffer: None,
+            system_program: system_program::ID,
+            log_wrapper: None,
+        };
+
+        let write_ix = write_accounts.instruction(args);
+
+        self.bench.process_transaction(&[write_ix], None).await?;
+
+        Ok(())
+    }
+
     pub async fn mint_assets_to_collection(&self, collection_cookie: &CollectionCookie, size: u64) {
         let asset_owner = self.bench.with_wallet().await;
 
@@ -51,8 +51,14 @@ pub struct MaxVoterWeightRecordCookie {
     pub account: MaxVoterWeightRecord,
 }
 
+pub struct WeightCensusCookie {
+    pub address: Pubkey,
+    pub account: WeightCensus,
+}
+
 pub struct CollectionConfigCookie {
     pub collection_config: CollectionConfig,
+    pub collection_config_extension: CollectionConfigExtension,
 }
 
 pub struct ConfigureCollectionArgs {
@@ -60,6 +66,10 @@ pub struct ConfigureCollectionArgs {
     pub total_weight: Option<u64>,
     pub weight_attribute_key: String,
     pub expected_attribute_authority: mpl_core::types::PluginAuthority,
+    pub weight_formula: Option<WeightFormula>,
+    pub value_decimals: u8,
+    pub missing_attribute_policy: MissingAttributePolicy,
+    pub weight_source: WeightSource,
 }
 
 impl Default for ConfigureCollectionArgs {
@@ -69,6 +79,10 @@ impl Default for ConfigureCollectionArgs {
             total_weight: None,
             weight_attribute_key: "weight".to_string(),
             expected_attribute_authority: mpl_core::types::PluginAuthority::UpdateAuthority,
+            weight_formula: None,
+            value_decimals: 0,
+            missing_attribute_policy: MissingAttributePolicy::Reject,
+            weight_source: WeightSource::Attributes,
         }
     }
 }
@@ -236,7 +250,10 @@ impl CoreVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             collection_configs: vec![],
-            reserved: [0; 128],
+            required_freeze_authority: None,
+            version: CURRENT_REGISTRAR_VERSION,
+            reserved: [0; 93],
+            collection_config_extensions: vec![],
         };
 
         Ok(RegistrarCookie {
@@ -389,6 +406,33 @@ impl CoreVoterTest {
         voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
         asset_cookies: &[&AssetCookie],
     ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_collections(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_weight_action,
+            asset_voter_cookie,
+            voter_token_owner_record_cookie,
+            &[],
+            asset_cookies,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Updates VoterWeightRecord with the given collections passed for MissingAttributePolicy::CollectionAttribute
+    /// Returns the number of skipped assets
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_collections(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        voter_weight_action: VoterWeightAction,
+        asset_voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        collection_cookies: &[&CollectionCookie],
+        asset_cookies: &[&AssetCookie],
+    ) -> Result<u16, BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_core_attribute_voter::instruction::UpdateVoterWeightRecord {
                 voter_weight_action,
@@ -404,6 +448,10 @@ impl CoreVoterTest {
 
         let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
 
+        for collection_cookie in collection_cookies {
+            account_metas.push(AccountMeta::new_readonly(collection_cookie.collection, false));
+        }
+
         for asset_cookie in asset_cookies {
             account_metas.push(AccountMeta::new_readonly(asset_cookie.asset, false));
         }
@@ -414,9 +462,14 @@ impl CoreVoterTest {
             data,
         }];
 
-        self.bench
-            .process_transaction(&instructions, Some(&[&asset_voter_cookie.signer]))
-            .await
+        let return_data = self
+            .bench
+            .process_transaction_with_return_data(&instructions, Some(&[&asset_voter_cookie.signer]))
+            .await?;
+
+        Ok(return_data
+            .map(|data| u16::from_le_bytes([data[0], data[1]]))
+            .unwrap_or_default())
     }
 
     #[allow(dead_code)]
@@ -424,6 +477,21 @@ impl CoreVoterTest {
         &self,
         registrar_cookie: &RegistrarCookie,
         max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.update_max_voter_weight_record_with_censuses(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            &[],
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_with_censuses(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
+        weight_census_cookies: &[&WeightCensusCookie],
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_core_attribute_voter::instruction::UpdateMaxVoterWeightRecord {}
@@ -434,7 +502,11 @@ impl CoreVoterTest {
             max_voter_weight_record: max_voter_weight_record_cookie.address,
         };
 
-        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for weight_census_cookie in weight_census_cookies {
+            account_metas.push(AccountMeta::new_readonly(weight_census_cookie.address, false));
+        }
 
         let instructions = vec![Instruction {
             program_id: gpl_core_attribute_voter::id(),
@@ -445,6 +517,129 @@ impl CoreVoterTest {
         self.bench.process_transaction(&instructions, None).await
     }
 
+    #[allow(dead_code)]
+    pub async fn set_required_freeze_authority(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
+    ) -> Result<(), BanksClientError> {
+        self.set_required_freeze_authority_using_ix(
+            registrar_cookie,
+            required_freeze_authority,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_required_freeze_authority_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_attribute_voter::instruction::SetRequiredFreezeAuthority {
+                required_freeze_authority,
+            },
+        );
+
+        let accounts = gpl_core_attribute_voter::accounts::SetRequiredFreezeAuthority {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut set_required_freeze_authority_ix = Instruction {
+            program_id: gpl_core_attribute_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_required_freeze_authority_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_required_freeze_authority_ix], Some(signers))
+            .await
+    }
+
+    /// Rewrites the given Registrar account using version 0 layout without collection_config_extensions
+    /// The account is resized to the layout size plus the given spare space
+    #[allow(dead_code)]
+    pub async fn with_registrar_version_0(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        spare_space: usize,
+    ) -> Result<(), BanksClientError> {
+        let mut registrar = self.get_registrar_account(&registrar_cookie.address).await;
+        registrar.version = 0;
+        registrar.collection_config_extensions = vec![];
+
+        let mut data = vec![];
+        anchor_lang::AccountSerialize::try_serialize(&registrar, &mut data).unwrap();
+
+        // Remove the empty collection_config_extensions vec
+        data.truncate(data.len() - 4);
+        data.extend_from_slice(&vec![0; spare_space]);
+
+        let mut account = self
+            .bench
+            .get_account(&registrar_cookie.address)
+            .await
+            .unwrap();
+
+        account.lamports = self.bench.rent.minimum_balance(data.len());
+        account.data = data;
+
+        self.bench.set_account(&registrar_cookie.address, &account);
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_registrar(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        self.migrate_registrar_using_ix(registrar_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_attribute_voter::instruction::MigrateRegistrar {},
+        );
+
+        let accounts = gpl_core_attribute_voter::accounts::MigrateRegistrar {
+            registrar: registrar_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let mut migrate_registrar_ix = Instruction {
+            program_id: gpl_core_attribute_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut migrate_registrar_ix);
+
+        self.bench
+            .process_transaction(&[migrate_registrar_ix], signers_override)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn relinquish_nft_vote(
         &mut self,
@@ -532,6 +727,10 @@ impl CoreVoterTest {
                 total_weight,
                 weight_attribute_key: args.weight_attribute_key.clone(),
                 expected_attribute_authority: args.expected_attribute_authority.clone(),
+                weight_formula: args.weight_formula.clone(),
+                value_decimals: args.value_decimals,
+                missing_attribute_policy: args.missing_attribute_policy,
+                weight_source: args.weight_source.clone(),
             });
 
         let accounts = gpl_core_attribute_voter::accounts::ConfigureCollection {
@@ -540,6 +739,8 @@ impl CoreVoterTest {
             realm_authority: registrar_cookie.realm_authority.pubkey(),
             collection: collection_cookie.collection,
             max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: Some(self.bench.payer.pubkey()),
+            system_program: Some(system_program::ID),
         };
 
         let mut configure_collection_ix = Instruction {
@@ -563,10 +764,255 @@ impl CoreVoterTest {
             total_weight,
             weight_attribute_key: args.weight_attribute_key,
             expected_attribute_authority: args.expected_attribute_authority,
-            reserved: [0; 8],
+            value_decimals: args.value_decimals,
+            use_weight_census: false,
+            kind: CollectionKind::Collection,
+            reserved: [0; 5],
+        };
+
+        let collection_config_extension = CollectionConfigExtension {
+            collection: collection_cookie.collection,
+            weight_formula: args.weight_formula,
+            missing_attribute_policy: args.missing_attribute_policy,
+            weight_source: args.weight_source,
         };
 
-        Ok(CollectionConfigCookie { collection_config })
+        Ok(CollectionConfigCookie {
+            collection_config,
+            collection_config_extension,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_update_authority(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        update_authority: &Pubkey,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        args: Option<ConfigureCollectionArgs>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        self.with_update_authority_using_ix(
+            registrar_cookie,
+            update_authority,
+            max_voter_weight_record_cookie,
+            args,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_update_authority_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        update_authority: &Pubkey,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        args: Option<ConfigureCollectionArgs>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        let args = args.unwrap_or_default();
+        let total_weight = args.total_weight.unwrap_or(args.max_weight);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_attribute_voter::instruction::ConfigureUpdateAuthority {
+                max_weight: args.max_weight,
+                total_weight,
+                weight_attribute_key: args.weight_attribute_key.clone(),
+                expected_attribute_authority: args.expected_attribute_authority.clone(),
+                weight_formula: args.weight_formula.clone(),
+                value_decimals: args.value_decimals,
+                missing_attribute_policy: args.missing_attribute_policy,
+                weight_source: args.weight_source.clone(),
+            },
+        );
+
+        let accounts = gpl_core_attribute_voter::accounts::ConfigureUpdateAuthority {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            update_authority: *update_authority,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: Some(self.bench.payer.pubkey()),
+            system_program: Some(system_program::ID),
+        };
+
+        let mut configure_update_authority_ix = Instruction {
+            program_id: gpl_core_attribute_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_update_authority_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_update_authority_ix], Some(signers))
+            .await?;
+
+        let collection_config = CollectionConfig {
+            collection: *update_authority,
+            max_weight: args.max_weight,
+            total_weight,
+            weight_attribute_key: args.weight_attribute_key,
+            expected_attribute_authority: args.expected_attribute_authority,
+            value_decimals: args.value_decimals,
+            use_weight_census: false,
+            kind: CollectionKind::UpdateAuthority,
+            reserved: [0; 5],
+        };
+
+        let collection_config_extension = CollectionConfigExtension {
+            collection: *update_authority,
+            weight_formula: args.weight_formula,
+            missing_attribute_policy: args.missing_attribute_policy,
+            weight_source: args.weight_source,
+        };
+
+        Ok(CollectionConfigCookie {
+            collection_config,
+            collection_config_extension,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_weight_census(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        max_age: Option<u64>,
+    ) -> Result<WeightCensusCookie, BanksClientError> {
+        self.with_weight_census_using_ix(
+            registrar_cookie,
+            collection_cookie,
+            max_voter_weight_record_cookie,
+            max_age,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_weight_census_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        max_age: Option<u64>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<WeightCensusCookie, BanksClientError> {
+        let weight_census_key =
+            get_weight_census_address(&registrar_cookie.address, &collection_cookie.collection);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_attribute_voter::instruction::ConfigureWeightCensus { max_age },
+        );
+
+        let accounts = gpl_core_attribute_voter::accounts::ConfigureWeightCensus {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: collection_cookie.collection,
+            weight_census: weight_census_key,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let mut configure_weight_census_ix = Instruction {
+            program_id: gpl_core_attribute_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_weight_census_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_weight_census_ix], Some(signers))
+            .await?;
+
+        let account = WeightCensus {
+            registrar: registrar_cookie.address,
+            collection: collection_cookie.collection,
+            total_weight: 0,
+            attested_at: 0,
+            max_age: max_age.unwrap_or_default(),
+            reserved: [0; 64],
+        };
+
+        Ok(WeightCensusCookie {
+            address: weight_census_key,
+            account,
+        })
+    }
+
+    /// Attests the census total_weight signed by the collection update authority
+    #[allow(dead_code)]
+    pub async fn update_weight_census(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        weight_census_cookie: &WeightCensusCookie,
+        total_weight: u64,
+    ) -> Result<(), BanksClientError> {
+        self.update_weight_census_using_ix(
+            registrar_cookie,
+            collection_cookie,
+            weight_census_cookie,
+            total_weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_weight_census_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        weight_census_cookie: &WeightCensusCookie,
+        total_weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_attribute_voter::instruction::UpdateWeightCensus { total_weight },
+        );
+
+        let accounts = gpl_core_attribute_voter::accounts::UpdateWeightCensus {
+            registrar: registrar_cookie.address,
+            collection: collection_cookie.collection,
+            weight_census: weight_census_cookie.address,
+            // The collection update authority is the bench payer
+            attribute_authority: self.bench.payer.pubkey(),
+        };
+
+        let mut update_weight_census_ix = Instruction {
+            program_id: gpl_core_attribute_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut update_weight_census_ix);
+
+        self.bench
+            .process_transaction(&[update_weight_census_ix], signers_override)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_weight_census_account(&self, weight_census: &Pubkey) -> WeightCensus {
+        self.bench.get_anchor_account(*weight_census).await
     }
 
     /// Casts NFT Vote and spl-gov Vote
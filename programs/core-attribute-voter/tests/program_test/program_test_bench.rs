@@ -88,6 +88,39 @@ impl ProgramTestBench {
             .await
     }
 
+    /// Processes the transaction and returns the return data set by the last instruction which set it
+    #[allow(dead_code)]
+    pub async fn process_transaction_with_return_data(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[&Keypair]>,
+    ) -> Result<Option<Vec<u8>>, BanksClientError> {
+        let mut context = self.context.borrow_mut();
+
+        let mut transaction =
+            Transaction::new_with_payer(&instructions, Some(&context.payer.pubkey()));
+
+        let mut all_signers = vec![&context.payer];
+
+        if let Some(signers) = signers {
+            all_signers.extend_from_slice(signers);
+        }
+
+        transaction.sign(&all_signers, context.last_blockhash);
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        Ok(result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .map(|return_data| return_data.data))
+    }
+
     pub async fn get_clock(&self) -> solana_program::clock::Clock {
         self.context
             .borrow_mut()
@@ -106,6 +139,15 @@ impl ProgramTestBench {
             .unwrap();
     }
 
+    /// Advances Clock.unix_timestamp by the given number of seconds
+    #[allow(dead_code)]
+    pub async fn advance_clock_by_seconds(&self, seconds: i64) {
+        let mut clock = self.get_clock().await;
+        clock.unix_timestamp += seconds;
+
+        self.context.borrow_mut().set_sysvar(&clock);
+    }
+
     pub async fn with_mint(&self) -> Result<MintCookie, TransportError> {
         let mint_keypair = Keypair::new();
         let mint_authority = Keypair::new();
@@ -286,6 +328,13 @@ impl ProgramTestBench {
         }
     }
 
+    #[allow(dead_code)]
+    pub fn set_account(&self, address: &Pubkey, account: &Account) {
+        self.context
+            .borrow_mut()
+            .set_account(address, &account.clone().into());
+    }
+
     #[allow(dead_code)]
     pub async fn get_account(&self, address: &Pubkey) -> Option<Account> {
         self.context
@@ -1,5 +1,7 @@
 use crate::program_test::core_voter_test::ConfigureCollectionArgs;
+use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
 use program_test::core_voter_test::CoreVoterTest;
+use program_test::tools::assert_nft_voter_err;
 use solana_program_test::*;
 use solana_sdk::transport::TransportError;
 
@@ -196,3 +198,269 @@ async fn test_update_max_voter_weight_record_provides_valid_expirey() -> Result<
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_weight_census() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_cookie_1 = core_voter_test.core.create_collection(None).await?;
+    let collection_cookie_2 = core_voter_test.core.create_collection(None).await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie_1,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                total_weight: Some(1000),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie_2,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                total_weight: Some(30),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie_1,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    core_voter_test
+        .update_weight_census(
+            &registrar_cookie,
+            &collection_cookie_1,
+            &weight_census_cookie,
+            250,
+        )
+        .await?;
+
+    let clock = core_voter_test.bench.get_clock().await;
+
+    // Act
+    core_voter_test
+        .update_max_voter_weight_record_with_censuses(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            &[&weight_census_cookie],
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // The attested census replaces the hand-set total_weight of collection_1
+    assert_eq!(max_voter_weight_record.max_voter_weight, 250 + 30);
+
+    // The census can go stale and the weight is only valid in the current slot
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_weight_census_not_provided_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    core_voter_test
+        .update_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &weight_census_cookie,
+            250,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record(&registrar_cookie, &mut max_voter_weight_record_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::WeightCensusNotProvided);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_stale_weight_census_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    core_voter_test
+        .update_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &weight_census_cookie,
+            250,
+        )
+        .await?;
+
+    core_voter_test.bench.advance_clock_by_seconds(3601).await;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_censuses(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            &[&weight_census_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::WeightCensusStale);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_unattested_weight_census_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_censuses(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            &[&weight_census_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::WeightCensusStale);
+
+    Ok(())
+}
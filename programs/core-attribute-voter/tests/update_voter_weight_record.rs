@@ -5,6 +5,7 @@ use program_test::core_voter_test::CoreVoterTest;
 use program_test::tools::*;
 use solana_program_test::*;
 use solana_sdk::msg;
+use solana_sdk::signature::{Keypair, Signer};
 use solana_sdk::transport::TransportError;
 
 mod program_test;
@@ -412,3 +413,1127 @@ async fn test_update_voter_weight_with_same_nft_error() -> Result<(), TransportE
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_with_weight_formula() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_attributes(
+            &collection_cookie,
+            &voter_cookie,
+            Some(vec![
+                mpl_core::types::Attribute {
+                    key: "tier".to_string(),
+                    value: "gold".to_string(),
+                },
+                mpl_core::types::Attribute {
+                    key: "level".to_string(),
+                    value: "3".to_string(),
+                },
+            ]),
+        )
+        .await?;
+
+    let weight_formula = WeightFormula {
+        combination: WeightCombination::Sum,
+        attributes: vec![
+            WeightAttribute {
+                key: "tier".to_string(),
+                multiplier: 1,
+                value_weights: vec![AttributeValueWeight {
+                    value: "gold".to_string(),
+                    weight: 10,
+                }],
+            },
+            WeightAttribute {
+                key: "level".to_string(),
+                multiplier: 2,
+                value_weights: vec![],
+            },
+        ],
+    };
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_formula: Some(weight_formula),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // gold (10) + level 3 * 2
+    assert_eq!(voter_weight_record.voter_weight, 16);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_attribute_value_not_weighted_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_named_weight(&collection_cookie, &voter_cookie, "tier", "bronze")
+        .await?;
+
+    let weight_formula = WeightFormula {
+        combination: WeightCombination::Max,
+        attributes: vec![WeightAttribute {
+            key: "tier".to_string(),
+            multiplier: 1,
+            value_weights: vec![AttributeValueWeight {
+                value: "gold".to_string(),
+                weight: 10,
+            }],
+        }],
+    };
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                weight_formula: Some(weight_formula),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::AttributeValueNotWeighted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_value_decimals() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_named_weight(&collection_cookie, &voter_cookie, "weight", "12.5")
+        .await?;
+
+    let asset_cookie2 = core_voter_test
+        .core
+        .create_asset_with_named_weight(&collection_cookie, &voter_cookie, "weight", "1e1")
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10_000,
+                value_decimals: 2,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1, &asset_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // 12.50 + 10.00 scaled by 2 decimals
+    assert_eq!(voter_weight_record.voter_weight, 2250);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_negative_attribute_value_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_named_weight(&collection_cookie, &voter_cookie, "weight", "-1.5")
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                value_decimals: 1,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::NegativeAttributeValue);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_skip_missing_attribute_policy() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_weight(&collection_cookie, &voter_cookie, 10)
+        .await?;
+
+    // Asset without the Attributes plugin
+    let asset_cookie2 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::Skip,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let skipped_count = core_voter_test
+        .update_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            &[&asset_cookie1, &asset_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(skipped_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_default_weight_missing_attribute_policy(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_weight(&collection_cookie, &voter_cookie, 10)
+        .await?;
+
+    // Asset with an attribute other than the weight attribute
+    let asset_cookie2 = core_voter_test
+        .core
+        .create_asset_with_named_weight(&collection_cookie, &voter_cookie, "tier", "gold")
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::DefaultWeight { weight: 3 },
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let skipped_count = core_voter_test
+        .update_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            &[&asset_cookie1, &asset_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 13);
+    assert_eq!(skipped_count, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_collection_attribute_missing_attribute_policy(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test
+        .core
+        .create_collection_with_attributes(
+            None,
+            Some(vec![mpl_core::types::Attribute {
+                key: "weight".to_string(),
+                value: "7".to_string(),
+            }]),
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_weight(&collection_cookie, &voter_cookie, 10)
+        .await?;
+
+    let asset_cookie2 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::CollectionAttribute,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&collection_cookie],
+            &[&asset_cookie1, &asset_cookie2],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 17);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_missing_collection_account_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test
+        .core
+        .create_collection_with_attributes(
+            None,
+            Some(vec![mpl_core::types::Attribute {
+                key: "weight".to_string(),
+                value: "7".to_string(),
+            }]),
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                missing_attribute_policy: MissingAttributePolicy::CollectionAttribute,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::MissingCollectionAccount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_app_data_weight_source() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The weight is stored after an 8 bytes staking timestamp
+    let mut data = 1_700_000_000u64.to_le_bytes().to_vec();
+    data.extend_from_slice(&42u64.to_le_bytes());
+
+    let asset_cookie = core_voter_test
+        .core
+        .create_asset_with_app_data(&collection_cookie, &voter_cookie, data)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_source: WeightSource::AppData {
+                    data_authority: mpl_core::types::PluginAuthority::Address {
+                        address: core_voter_test.bench.payer.pubkey(),
+                    },
+                    offset: 8,
+                },
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_linked_app_data_weight_source() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let data_authority = mpl_core::types::PluginAuthority::Address {
+        address: core_voter_test.bench.payer.pubkey(),
+    };
+
+    let collection_cookie = core_voter_test
+        .core
+        .create_collection_with_linked_app_data(data_authority.clone())
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie = core_voter_test
+        .core
+        .create_asset_with_linked_app_data(
+            &collection_cookie,
+            &voter_cookie,
+            250u64.to_le_bytes().to_vec(),
+        )
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_source: WeightSource::LinkedAppData {
+                    data_authority,
+                    offset: 0,
+                },
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The weight is capped by max_weight
+    assert_eq!(voter_weight_record.voter_weight, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_app_data_from_untrusted_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The AppData plugin is written by the bench payer
+    let asset_cookie = core_voter_test
+        .core
+        .create_asset_with_app_data(&collection_cookie, &voter_cookie, 42u64.to_le_bytes().to_vec())
+        .await?;
+
+    // The collection trusts a different data authority
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 100,
+                weight_source: WeightSource::AppData {
+                    data_authority: mpl_core::types::PluginAuthority::Address {
+                        address: Keypair::new().pubkey(),
+                    },
+                    offset: 0,
+                },
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::AttributeNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_required_freeze_authority() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset_with_weight(
+            &collection_cookie,
+            &voter_cookie,
+            7,
+            mpl_core::types::PluginAuthority::UpdateAuthority,
+        )
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_asset_not_frozen_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The asset is frozen by the owner who can thaw it at any time
+    let asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset_with_weight(
+            &collection_cookie,
+            &voter_cookie,
+            7,
+            mpl_core::types::PluginAuthority::Owner,
+        )
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::AssetMustBeFrozen);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_update_authority_asset() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_update_authority(&update_authority, &voter_cookie, 7, true)
+        .await?;
+
+    core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { max_weight: 10, ..Default::default() }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = core_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_with_unverified_update_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset_with_update_authority(&update_authority, &voter_cookie, 7, false)
+        .await?;
+
+    core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { max_weight: 10, ..Default::default() }),
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, CoreNftAttributeVoterError::UpdateAuthorityNotVerified);
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use gpl_core_attribute_voter::error::CoreNftAttributeVoterError;
+use program_test::{
+    core_voter_test::{ConfigureCollectionArgs, CoreVoterTest},
+    tools::assert_nft_voter_err,
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_weight_census() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    let clock = core_voter_test.bench.get_clock().await;
+
+    // Act
+    core_voter_test
+        .update_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &weight_census_cookie,
+            250,
+        )
+        .await?;
+
+    // Assert
+    let weight_census = core_voter_test
+        .get_weight_census_account(&weight_census_cookie.address)
+        .await;
+
+    assert_eq!(weight_census.total_weight, 250);
+    assert_eq!(weight_census.attested_at, clock.unix_timestamp);
+    assert_eq!(weight_census.max_age, 3600);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_weight_census_with_invalid_attribute_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    // Try to attest the census by someone other than the collection update authority
+    let attribute_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .update_weight_census_using_ix(
+            &registrar_cookie,
+            &collection_cookie,
+            &weight_census_cookie,
+            250,
+            |i| i.accounts[3].pubkey = attribute_authority.pubkey(), // attribute_authority
+            Some(&[&attribute_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        CoreNftAttributeVoterError::InvalidWeightCensusAuthority,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_weight_census_with_owner_attribute_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Owner managed attributes can't be attested for the whole collection
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                max_weight: 10,
+                expected_attribute_authority: mpl_core::types::PluginAuthority::Owner,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let weight_census_cookie = core_voter_test
+        .with_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(3600),
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_weight_census(
+            &registrar_cookie,
+            &collection_cookie,
+            &weight_census_cookie,
+            250,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        CoreNftAttributeVoterError::InvalidWeightCensusAuthority,
+    );
+
+    Ok(())
+}
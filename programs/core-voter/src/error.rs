@@ -82,4 +82,31 @@ pub enum NftVoterError {
 
     #[msg("Proposal is not in voting state")]
     InvalidProposalState,
+
+    #[msg("Voting on the Proposal must be finalized")]
+    ProposalMustBeFinalized,
+
+    #[msg("Invalid payer for AssetVoteRecord")]
+    InvalidPayerForNftVoteRecord,
+
+    #[msg("Asset must be frozen")]
+    AssetMustBeFrozen,
+
+    #[msg("Frozen assets are not excluded from collection sizes for the Registrar")]
+    FrozenAssetsNotExcluded,
+
+    #[msg("Asset update authority is not a verified creator of the asset")]
+    UpdateAuthorityNotVerified,
+
+    #[msg("AssetVoteRecords and their payers must be supplied in pairs")]
+    InvalidNftVoteRecordAccountPairs,
+
+    #[msg("Frozen assets excluded from collection sizes can't be used for voting")]
+    ExcludedFrozenAssetCannotVote,
+
+    #[msg("Required freeze authority can't be Owner or None")]
+    InvalidRequiredFreezeAuthority,
+
+    #[msg("Required freeze authority can't be combined with excluded frozen assets")]
+    RequiredFreezeAuthorityWithExcludedFrozenAssets,
 }
@@ -94,6 +94,7 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
             &governing_token_owner,
             asset.key.clone(),
             &BaseAssetV1::from_bytes(&asset.data.borrow()).unwrap(),
+            asset,
             &mut unique_asset_mints,
         )?;
 
@@ -119,6 +120,7 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
             asset_mint,
             governing_token_owner,
             reserved: [0; 8],
+            payer: ctx.accounts.payer.key(),
         };
 
         // Anchor doesn't natively support dynamic account creation using remaining_accounts
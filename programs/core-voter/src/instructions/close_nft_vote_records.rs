@@ -0,0 +1,79 @@
+use crate::error::NftVoterError;
+use crate::state::{get_nft_vote_record_data_for_proposal, Registrar};
+use anchor_lang::prelude::*;
+use itertools::Itertools;
+use spl_governance::state::{enums::ProposalState, governance, proposal};
+use spl_governance_tools::account::dispose_account;
+
+/// Disposes AssetVoteRecords of a finalized Proposal and refunds the rent to the payers who created them
+/// The instruction is permissionless and can be used to clean up AssetVoteRecords left behind by voters
+/// AssetVoteRecords are supplied as (asset_vote_record, payer) pairs using remaining_accounts
+///
+/// Note: AssetVoteRecords created before the payer was recorded can only be disposed with relinquish_nft_vote
+#[derive(Accounts)]
+pub struct CloseNftVoteRecords<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    /// Governance account the Proposal is for
+    #[account(owner = registrar.governance_program_id)]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(owner = registrar.governance_program_id)]
+    pub proposal: UncheckedAccount<'info>,
+}
+
+pub fn close_nft_vote_records(ctx: Context<CloseNftVoteRecords>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    // Ensure the Governance belongs to Registrar.realm and is owned by Registrar.governance_program_id
+    let _governance = governance::get_governance_data_for_realm(
+        &registrar.governance_program_id,
+        &ctx.accounts.governance,
+        &registrar.realm,
+    )?;
+
+    // Ensure the Proposal belongs to Governance from Registrar.realm and Registrar.governing_token_mint and is owned by Registrar.governance_program_id
+    let proposal = proposal::get_proposal_data_for_governance_and_governing_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.proposal,
+        &ctx.accounts.governance.key(),
+        &registrar.governing_token_mint,
+    )?;
+
+    // AssetVoteRecords can only be closed once voting on the Proposal is finalized
+    // and they are no longer needed to prevent the assets from voting again
+    require!(
+        !matches!(
+            proposal.state,
+            ProposalState::Draft | ProposalState::SigningOff | ProposalState::Voting
+        ),
+        NftVoterError::ProposalMustBeFinalized
+    );
+
+    // AssetVoteRecords must be supplied with their payers and an unpaired trailing account is rejected
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(2),
+        NftVoterError::InvalidNftVoteRecordAccountPairs
+    );
+
+    for (asset_vote_record_info, payer_info) in ctx.remaining_accounts.iter().tuples() {
+        let asset_vote_record = get_nft_vote_record_data_for_proposal(
+            asset_vote_record_info,
+            &ctx.accounts.proposal.key(),
+        )?;
+
+        // Legacy AssetVoteRecords have the default payer and can't match any payer account
+        require!(
+            asset_vote_record.payer != Pubkey::default()
+                && asset_vote_record.payer == payer_info.key(),
+            NftVoterError::InvalidPayerForNftVoteRecord
+        );
+
+        dispose_account(asset_vote_record_info, payer_info)?;
+    }
+
+    Ok(())
+}
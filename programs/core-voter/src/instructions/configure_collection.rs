@@ -9,7 +9,9 @@ use mpl_core::accounts::BaseCollectionV1;
 use spl_governance::state::realm;
 
 use crate::error::NftVoterError;
-use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, Registrar};
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, CollectionKind, Registrar,
+};
 
 /// Configures NFT voting collection which defines what NFTs can be used for governances
 /// and what weight they have
@@ -81,7 +83,8 @@ pub fn configure_collection(
     let collection_config = CollectionConfig {
         collection: collection.key(),
         weight,
-        reserved: [0; 8],
+        kind: CollectionKind::Collection,
+        reserved: [0; 7],
         size,
     };
 
@@ -91,6 +94,11 @@ pub fn configure_collection(
         .position(|cc| cc.collection == collection.key());
 
     if let Some(collection_idx) = collection_idx {
+        require!(
+            registrar.collection_configs[collection_idx].kind == CollectionKind::Collection,
+            NftVoterError::InvalidNftCollection
+        );
+
         registrar.collection_configs[collection_idx] = collection_config;
     } else {
         // Note: In the current runtime version push() would throw an error if we exceed
@@ -98,8 +106,8 @@ pub fn configure_collection(
         registrar.collection_configs.push(collection_config);
     }
 
-    // TODO: if weight == 0 then remove the collection from config
-    // Currently if weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // Note: If weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // remove_collection should be used to remove the collection and free its slot
 
     // Update MaxVoterWeightRecord based on max voting power of the collections
     let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
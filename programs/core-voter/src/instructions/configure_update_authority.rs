@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, CollectionKind, Registrar,
+};
+
+/// Configures assets without a collection identified by their UpdateAuthority::Address update authority
+/// and what weight they have
+/// Such assets don't have a collection account tracking their count and the size used for max voter weight
+/// must be set explicitly
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+///
+/// Note: The update authority must be a verified creator of each asset used for voting
+#[derive(Accounts)]
+pub struct ConfigureUpdateAuthority<'info> {
+    /// Registrar for which we configure this update authority
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: Update authority of the assets which are going to be used for voting
+    /// It's only used as the key of the assets group
+    pub update_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn configure_update_authority(
+    ctx: Context<ConfigureUpdateAuthority>,
+    weight: u64,
+    size: u32,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    require!(size > 0, NftVoterError::InvalidCollectionSize);
+
+    let update_authority = ctx.accounts.update_authority.key();
+
+    let collection_config = CollectionConfig {
+        collection: update_authority,
+        weight,
+        kind: CollectionKind::UpdateAuthority,
+        reserved: [0; 7],
+        size,
+    };
+
+    let collection_idx = registrar
+        .collection_configs
+        .iter()
+        .position(|cc| cc.collection == update_authority);
+
+    if let Some(collection_idx) = collection_idx {
+        require!(
+            registrar.collection_configs[collection_idx].kind == CollectionKind::UpdateAuthority,
+            NftVoterError::InvalidNftCollection
+        );
+
+        registrar.collection_configs[collection_idx] = collection_config;
+    } else {
+        // Note: In the current runtime version push() would throw an error if we exceed
+        // max_collections specified when the Registrar was created
+        registrar.collection_configs.push(collection_config);
+    }
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar
+        .collection_configs
+        .iter()
+        .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
+        .unwrap();
+
+    // The weight never expires and only changes when collections are configured
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
@@ -1,6 +1,18 @@
 pub use configure_collection::*;
 mod configure_collection;
 
+pub use configure_update_authority::*;
+mod configure_update_authority;
+
+pub use remove_collection::*;
+mod remove_collection;
+
+pub use set_exclude_frozen_assets::*;
+mod set_exclude_frozen_assets;
+
+pub use set_required_freeze_authority::*;
+mod set_required_freeze_authority;
+
 pub use create_registrar::*;
 mod create_registrar;
 
@@ -16,6 +28,9 @@ mod update_voter_weight_record;
 pub use relinquish_nft_vote::*;
 mod relinquish_nft_vote;
 
+pub use close_nft_vote_records::*;
+mod close_nft_vote_records;
+
 pub use cast_nft_vote::*;
 mod cast_nft_vote;
 
@@ -57,7 +57,8 @@ pub struct RelinquishNftVote<'info> {
     /// #[account(owner = registrar.governance_program_id)]
     pub vote_record: UncheckedAccount<'info>,
 
-    /// CHECK: The beneficiary who receives lamports from the disposed NftVoterRecord accounts can be any account
+    /// CHECK: The beneficiary who receives lamports from the disposed NftVoterRecord accounts
+    /// It must be the payer of the records unless they were created before the payer was recorded
     #[account(mut)]
     pub beneficiary: UncheckedAccount<'info>,
 }
@@ -133,12 +134,20 @@ pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
     // Dispose all NftVoteRecords
     for nft_vote_record_info in ctx.remaining_accounts.iter() {
         // Ensure NftVoteRecord is for the given Proposal and TokenOwner
-        let _nft_vote_record = get_nft_vote_record_data_for_proposal_and_token_owner(
+        let nft_vote_record = get_nft_vote_record_data_for_proposal_and_token_owner(
             nft_vote_record_info,
             &ctx.accounts.proposal.key(),
             &governing_token_owner,
         )?;
 
+        // The rent can only be refunded to the payer who created the NftVoteRecord
+        // Legacy NftVoteRecords have the default payer and the rent can go to any beneficiary
+        require!(
+            nft_vote_record.payer == Pubkey::default()
+                || nft_vote_record.payer == ctx.accounts.beneficiary.key(),
+            NftVoterError::InvalidPayerForNftVoteRecord
+        );
+
         dispose_account(nft_vote_record_info, &ctx.accounts.beneficiary)?;
     }
 
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::Registrar;
+
+/// Sets whether frozen assets are excluded from the collection sizes
+/// refreshed by update_max_voter_weight_record
+/// Frozen assets can't be excluded while required_freeze_authority requires assets to be frozen to vote
+#[derive(Accounts)]
+pub struct SetExcludeFrozenAssets<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+pub fn set_exclude_frozen_assets(
+    ctx: Context<SetExcludeFrozenAssets>,
+    exclude_frozen_assets: bool,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    require!(
+        !(exclude_frozen_assets && registrar.required_freeze_authority.is_some()),
+        NftVoterError::RequiredFreezeAuthorityWithExcludedFrozenAssets
+    );
+
+    registrar.exclude_frozen_assets = exclude_frozen_assets;
+
+    Ok(())
+}
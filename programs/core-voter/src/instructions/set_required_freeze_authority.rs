@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use mpl_core::types::PluginAuthority;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::Registrar;
+
+/// Sets the freeze authority assets must be frozen by to be used for governance
+/// When set only assets frozen by FreezeDelegate or PermanentFreezeDelegate plugin with the authority are accepted
+/// The authority can't be Owner or None because the owner could thaw the asset at any time
+/// and it can't be combined with exclude_frozen_assets which rejects frozen assets from voting
+#[derive(Accounts)]
+pub struct SetRequiredFreezeAuthority<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+pub fn set_required_freeze_authority(
+    ctx: Context<SetRequiredFreezeAuthority>,
+    required_freeze_authority: Option<PluginAuthority>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    if let Some(required_freeze_authority) = &required_freeze_authority {
+        require!(
+            !matches!(
+                required_freeze_authority,
+                PluginAuthority::Owner | PluginAuthority::None
+            ),
+            NftVoterError::InvalidRequiredFreezeAuthority
+        );
+
+        require!(
+            !registrar.exclude_frozen_assets,
+            NftVoterError::RequiredFreezeAuthorityWithExcludedFrozenAssets
+        );
+    }
+
+    registrar.required_freeze_authority = required_freeze_authority;
+
+    Ok(())
+}
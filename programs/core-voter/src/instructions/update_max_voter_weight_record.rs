@@ -1,13 +1,30 @@
 use crate::error::NftVoterError;
 use crate::state::*;
+use crate::tools::core_asset::{is_core_asset_account, is_core_asset_frozen};
 use anchor_lang::prelude::*;
 use max_voter_weight_record::MaxVoterWeightRecord;
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+};
+use spl_governance::state::realm;
 
-// Takes all collections added to `register`, iterates over them and calculates
-// the max voter weight
+/// Refreshes the sizes of the supplied collections and calculates the max voter weight
+/// by iterating over all collections added to `registrar`
+///
+/// The collections are supplied as (collection, [frozen_asset...]) groups using remaining_accounts
+/// Each collection size is set to BaseCollectionV1.current_size which doesn't count burned assets
+/// and if Registrar.exclude_frozen_assets is set then the supplied frozen assets are not counted either
+/// Collections which are not supplied keep their stored size
+/// and update authority configs always use the size set by configure_update_authority
+///
+/// The instruction is permissionless and can be used as a crank before each proposal
+/// However if Registrar.exclude_frozen_assets is set then the collection sizes depend on the frozen assets
+/// chosen by the caller and they can only be refreshed by the realm authority
 #[derive(Accounts)]
 pub struct UpdateMaxVoterWeightRecord<'info> {
     /// The NFT voting Registrar
+    #[account(mut)]
     pub registrar: Account<'info, Registrar>,
 
     #[account(
@@ -19,10 +36,111 @@ pub struct UpdateMaxVoterWeightRecord<'info> {
         @ NftVoterError::InvalidVoterWeightRecordMint,
     )]
     pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// The Realm of the Registrar
+    /// It's required together with realm_authority to refresh collection sizes
+    /// when Registrar.exclude_frozen_assets is set
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: Option<UncheckedAccount<'info>>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    /// when collection sizes are refreshed and Registrar.exclude_frozen_assets is set
+    pub realm_authority: Option<Signer<'info>>,
 }
 
 pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
-    let registrar = &ctx.accounts.registrar;
+    let registrar = &mut ctx.accounts.registrar;
+    let exclude_frozen_assets = registrar.exclude_frozen_assets;
+
+    // The frozen assets excluded from the collection sizes are chosen by the caller
+    // and hence only the realm authority can refresh the sizes
+    if exclude_frozen_assets && !ctx.remaining_accounts.is_empty() {
+        let (realm_info, realm_authority) =
+            match (&ctx.accounts.realm, &ctx.accounts.realm_authority) {
+                (Some(realm_info), Some(realm_authority)) => (realm_info, realm_authority),
+                _ => return Err(NftVoterError::InvalidRealmAuthority.into()),
+            };
+
+        let realm = realm::get_realm_data_for_governing_token_mint(
+            &registrar.governance_program_id,
+            realm_info,
+            &registrar.governing_token_mint,
+        )?;
+
+        require!(
+            realm.authority == Some(realm_authority.key()),
+            NftVoterError::InvalidRealmAuthority
+        );
+    }
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter().peekable();
+
+    while let Some(collection_info) = remaining_accounts.next() {
+        if collection_info.owner != &mpl_core::ID {
+            return Err(NftVoterError::InvalidAccountOwner.into());
+        }
+
+        let collection = BaseCollectionV1::from_bytes(&collection_info.data.borrow())
+            .map_err(|_| NftVoterError::InvalidNftCollection)?;
+
+        require!(
+            collection.key == mpl_core::types::Key::CollectionV1,
+            NftVoterError::InvalidNftCollection
+        );
+
+        let collection_config = registrar
+            .collection_configs
+            .iter_mut()
+            .find(|cc| cc.collection == collection_info.key())
+            .ok_or(NftVoterError::CollectionNotFound)?;
+
+        require!(
+            collection_config.kind == CollectionKind::Collection,
+            NftVoterError::InvalidNftCollection
+        );
+
+        let mut size = collection.current_size;
+
+        // Ensure the same frozen asset is not counted more than once
+        let mut unique_frozen_assets = vec![];
+
+        while let Some(asset_info) = remaining_accounts.next_if(|info| is_core_asset_account(info))
+        {
+            require!(
+                exclude_frozen_assets,
+                NftVoterError::FrozenAssetsNotExcluded
+            );
+
+            let asset = BaseAssetV1::from_bytes(&asset_info.data.borrow())
+                .map_err(|_| NftVoterError::InvalidNftCollection)?;
+
+            require!(
+                asset.update_authority == UpdateAuthority::Collection(collection_info.key()),
+                NftVoterError::InvalidNftCollection
+            );
+
+            require!(
+                !unique_frozen_assets.contains(asset_info.key),
+                NftVoterError::DuplicatedNftDetected
+            );
+            unique_frozen_assets.push(asset_info.key());
+
+            require!(
+                is_core_asset_frozen(asset_info),
+                NftVoterError::AssetMustBeFrozen
+            );
+
+            size = size
+                .checked_sub(1)
+                .ok_or(NftVoterError::InvalidCollectionSize)?;
+        }
+
+        collection_config.size = size;
+    }
 
     // Calculate the max voter weight by iterating over all collections and summing
     // the max weight of each collection.
@@ -73,6 +73,7 @@ pub fn update_voter_weight_record(
             &governing_token_owner,
             asset.key.clone(),
             &BaseAssetV1::from_bytes(&asset.data.borrow()).unwrap(),
+            asset,
             &mut unique_nft_mints,
         )?;
 
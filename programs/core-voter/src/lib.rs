@@ -49,10 +49,41 @@ pub mod core_voter {
         log_version();
         instructions::relinquish_nft_vote(ctx)
     }
+    pub fn close_nft_vote_records(ctx: Context<CloseNftVoteRecords>) -> Result<()> {
+        log_version();
+        instructions::close_nft_vote_records(ctx)
+    }
     pub fn configure_collection(ctx: Context<ConfigureCollection>, weight: u64) -> Result<()> {
         log_version();
         instructions::configure_collection(ctx, weight)
     }
+    pub fn configure_update_authority(
+        ctx: Context<ConfigureUpdateAuthority>,
+        weight: u64,
+        size: u32,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_update_authority(ctx, weight, size)
+    }
+    pub fn remove_collection(ctx: Context<RemoveCollection>) -> Result<()> {
+        log_version();
+        instructions::remove_collection(ctx)
+    }
+    pub fn set_exclude_frozen_assets(
+        ctx: Context<SetExcludeFrozenAssets>,
+        exclude_frozen_assets: bool,
+    ) -> Result<()> {
+        log_version();
+        instructions::set_exclude_frozen_assets(ctx, exclude_frozen_assets)
+    }
+
+    pub fn set_required_freeze_authority(
+        ctx: Context<SetRequiredFreezeAuthority>,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
+    ) -> Result<()> {
+        log_version();
+        instructions::set_required_freeze_authority(ctx, required_freeze_authority)
+    }
 
     pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
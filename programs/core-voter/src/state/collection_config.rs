@@ -1,14 +1,29 @@
 use anchor_lang::prelude::*;
 
+/// The way assets are grouped into a CollectionConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollectionKind {
+    /// Assets of the Core collection with UpdateAuthority::Collection
+    #[default]
+    Collection,
+
+    /// Assets without a collection with UpdateAuthority::Address
+    /// The update authority must be a verified creator of the asset because Core doesn't require
+    /// the update authority to sign when an asset is created
+    UpdateAuthority,
+}
+
 /// Configuration of an NFT collection used for governance power
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
 pub struct CollectionConfig {
     /// The NFT collection used for governance
+    /// For CollectionKind::UpdateAuthority it's the update authority address of the assets
     pub collection: Pubkey,
 
     /// The size of the NFT collection used to calculate max voter weight
-    /// Note: At the moment the size is not captured on Metaplex accounts
-    /// and it has to be manually updated on the Registrar
+    /// It's captured from BaseCollectionV1.current_size by configure_collection
+    /// and refreshed by update_max_voter_weight_record
+    /// For CollectionKind::UpdateAuthority it's set explicitly by configure_update_authority
     pub size: u32,
 
     /// Governance power weight of the collection
@@ -17,8 +32,11 @@ pub struct CollectionConfig {
     /// Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100
     pub weight: u64,
 
+    /// The way assets are grouped into the config
+    pub kind: CollectionKind,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    pub reserved: [u8; 7],
 }
 
 impl CollectionConfig {
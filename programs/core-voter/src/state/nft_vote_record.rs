@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program_pack::IsInitialized;
 
+use anchor_lang::solana_program::borsh1::try_from_slice_unchecked;
 use spl_governance_tools::account::{get_account_data, AccountMaxSize};
 
 use crate::{error::NftVoterError, id};
@@ -30,11 +31,18 @@ pub struct AssetVoteRecord {
 
     /// Reserved for future upgrades
     pub reserved: [u8; 8],
+
+    /// The account which paid the rent for the record and receives it back when the record is closed
+    /// AssetVoteRecords created before the payer was recorded have the default Pubkey as the payer
+    pub payer: Pubkey,
 }
 
 impl AssetVoteRecord {
     /// sha256("account:AssetVoteRecord")[..8]
     pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [14, 166, 191, 239, 186, 156, 140, 83];
+
+    /// Size of AssetVoteRecords created before the payer was recorded
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 32 * 3 + 8;
 }
 
 impl AccountMaxSize for AssetVoteRecord {}
@@ -57,16 +65,34 @@ pub fn get_nft_vote_record_address(proposal: &Pubkey, asset_mint: &Pubkey) -> Pu
 
 /// Deserializes account and checks owner program
 pub fn get_nft_vote_record_data(nft_vote_record_info: &AccountInfo) -> Result<AssetVoteRecord> {
-    Ok(get_account_data::<AssetVoteRecord>(
-        &id(),
-        nft_vote_record_info,
-    )?)
+    if nft_vote_record_info.data_len() != AssetVoteRecord::LEGACY_ACCOUNT_SIZE {
+        return Ok(get_account_data::<AssetVoteRecord>(
+            &id(),
+            nft_vote_record_info,
+        )?);
+    }
+
+    // Legacy AssetVoteRecords don't have the payer and it's deserialized as the default Pubkey
+    require!(
+        nft_vote_record_info.owner == &id(),
+        NftVoterError::InvalidAccountOwner
+    );
+
+    let mut data = nft_vote_record_info.try_borrow_data()?.to_vec();
+    data.extend_from_slice(Pubkey::default().as_ref());
+
+    let nft_vote_record: AssetVoteRecord = try_from_slice_unchecked(&data)?;
+
+    if !nft_vote_record.is_initialized() {
+        return Err(ProgramError::UninitializedAccount.into());
+    }
+
+    Ok(nft_vote_record)
 }
 
-pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
+pub fn get_nft_vote_record_data_for_proposal(
     nft_vote_record_info: &AccountInfo,
     proposal: &Pubkey,
-    governing_token_owner: &Pubkey,
 ) -> Result<AssetVoteRecord> {
     let nft_vote_record = get_nft_vote_record_data(nft_vote_record_info)?;
 
@@ -75,6 +101,16 @@ pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
         NftVoterError::InvalidProposalForNftVoteRecord
     );
 
+    Ok(nft_vote_record)
+}
+
+pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
+    nft_vote_record_info: &AccountInfo,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Result<AssetVoteRecord> {
+    let nft_vote_record = get_nft_vote_record_data_for_proposal(nft_vote_record_info, proposal)?;
+
     require!(
         nft_vote_record.governing_token_owner == *governing_token_owner,
         NftVoterError::InvalidTokenOwnerForNftVoteRecord
@@ -82,3 +118,51 @@ pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
 
     Ok(nft_vote_record)
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_lang::solana_program::{borsh1::get_instance_packed_len, pubkey::PUBKEY_BYTES};
+
+    #[test]
+    fn test_get_nft_vote_record_data_for_legacy_record() {
+        // Arrange
+        let asset_vote_record = AssetVoteRecord {
+            account_discriminator: AssetVoteRecord::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::new_unique(),
+            asset_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            reserved: [0; 8],
+            payer: Pubkey::default(),
+        };
+
+        let mut data = borsh_1::to_vec(&asset_vote_record).unwrap();
+        data.truncate(AssetVoteRecord::LEGACY_ACCOUNT_SIZE);
+
+        let key = Pubkey::new_unique();
+        let owner = id();
+        let mut lamports = 0;
+
+        let asset_vote_record_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let actual_asset_vote_record = get_nft_vote_record_data(&asset_vote_record_info).unwrap();
+
+        // Assert
+        assert_eq!(
+            AssetVoteRecord::LEGACY_ACCOUNT_SIZE + PUBKEY_BYTES,
+            get_instance_packed_len(&asset_vote_record).unwrap()
+        );
+        assert_eq!(asset_vote_record, actual_asset_vote_record);
+    }
+}
@@ -1,11 +1,18 @@
 use crate::{
     error::NftVoterError,
     id,
-    state::{CollectionConfig, VoterWeightRecord},
-    tools::anchor::DISCRIMINATOR_SIZE,
+    state::{CollectionConfig, CollectionKind, VoterWeightRecord},
+    tools::{
+        anchor::DISCRIMINATOR_SIZE,
+        core_asset::{is_core_asset_frozen, is_core_asset_frozen_by},
+    },
 };
 use anchor_lang::prelude::*;
-use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    types::{PluginAuthority, PluginType, UpdateAuthority, VerifiedCreators},
+};
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
 use spl_governance::state::{enums::ProposalState, proposal, token_owner_record};
 
@@ -28,8 +35,20 @@ pub struct Registrar {
     /// Core Collection used for voting
     pub collection_configs: Vec<CollectionConfig>,
 
+    /// Excludes frozen assets from the collection sizes refreshed by update_max_voter_weight_record
+    /// When set the frozen assets of a collection can be supplied after the collection account
+    /// and each of them decreases the collection size used for the max voter weight
+    /// The sizes can then only be refreshed by the realm authority and frozen assets can't be used for voting
+    /// Note: It can't be combined with required_freeze_authority
+    pub exclude_frozen_assets: bool,
+
+    /// When set only assets frozen by FreezeDelegate or PermanentFreezeDelegate plugin
+    /// with the given authority can be used for governance
+    /// Frozen assets can't be transferred and used again by another wallet while they are locked
+    pub required_freeze_authority: Option<PluginAuthority>,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 93],
 }
 
 impl Registrar {
@@ -38,7 +57,10 @@ impl Registrar {
             + PUBKEY_BYTES * 3
             + 4
             + max_collections as usize * (PUBKEY_BYTES + 4 + 8 + 8)
-            + 128
+            + 1
+            + 1
+            + 33
+            + 93
     }
 }
 
@@ -63,6 +85,22 @@ impl Registrar {
             .find(|cc| cc.collection == collection)
             .ok_or_else(|| NftVoterError::CollectionNotFound.into());
     }
+
+    /// Returns the config for the given collection or update authority and asserts it's of the given kind
+    pub fn get_collection_config_of_kind(
+        &self,
+        collection: Pubkey,
+        kind: CollectionKind,
+    ) -> Result<&CollectionConfig> {
+        let collection_config = self.get_collection_config(collection)?;
+
+        require!(
+            collection_config.kind == kind,
+            NftVoterError::InvalidNftCollection
+        );
+
+        Ok(collection_config)
+    }
 }
 
 // Resolves governing_token_owner from voter TokenOwnerRecord and
@@ -116,6 +154,7 @@ pub fn resolve_nft_vote_weight_and_mint(
     governing_token_owner: &Pubkey,
     asset_key: Pubkey,
     asset: &BaseAssetV1,
+    asset_info: &AccountInfo,
     // nft_metadata_info: &AccountInfo,
     unique_nft_mints: &mut Vec<Pubkey>,
 ) -> Result<(u64, Pubkey)> {
@@ -135,21 +174,56 @@ pub fn resolve_nft_vote_weight_and_mint(
     }
     unique_nft_mints.push(nft_mint);
 
-    // The Core NFT must have a collection and the collection must be verified
-    let collection = match asset.update_authority {
-        UpdateAuthority::Collection(collection) => {
-            collection
-        },
-        _ => return Err(NftVoterError::InvalidNftCollection.into())
-    };
+    // The asset must be locked by the required freeze authority to prevent it from being transferred
+    // and used again by another wallet
+    if let Some(required_freeze_authority) = &registrar.required_freeze_authority {
+        require!(
+            is_core_asset_frozen_by(asset_info, required_freeze_authority),
+            NftVoterError::AssetMustBeFrozen
+        );
+    }
+
+    // Frozen assets can be excluded from the collection sizes and hence they can't be used for voting
+    if registrar.exclude_frozen_assets {
+        require!(
+            !is_core_asset_frozen(asset_info),
+            NftVoterError::ExcludedFrozenAssetCannotVote
+        );
+    }
 
-    let collection_config = registrar.get_collection_config(collection)?;
+    // The Core NFT must have a collection or an update authority configured for the Registrar
+    let collection_config = match asset.update_authority {
+        UpdateAuthority::Collection(collection) => {
+            registrar.get_collection_config_of_kind(collection, CollectionKind::Collection)?
+        }
+        UpdateAuthority::Address(update_authority) => {
+            // Anybody can create an asset with any update authority and the update authority
+            // must have verified the asset as its creator
+            require!(
+                is_verified_creator(asset_info, &update_authority),
+                NftVoterError::UpdateAuthorityNotVerified
+            );
 
-    require!(collection_config.collection == collection, NftVoterError::InvalidNftCollection);
+            registrar.get_collection_config_of_kind(update_authority, CollectionKind::UpdateAuthority)?
+        }
+        UpdateAuthority::None => return Err(NftVoterError::InvalidNftCollection.into()),
+    };
 
     Ok((collection_config.weight, nft_mint))
 }
 
+/// Returns true if the given creator is verified in the asset's VerifiedCreators plugin
+fn is_verified_creator(asset_info: &AccountInfo, creator: &Pubkey) -> bool {
+    fetch_plugin::<BaseAssetV1, VerifiedCreators>(asset_info, PluginType::VerifiedCreators)
+        .map(|(_, verified_creators, _)| {
+            verified_creators
+                .signatures
+                .iter()
+                .any(|signature| signature.address == *creator && signature.verified)
+        })
+        .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod test {
 
@@ -169,7 +243,11 @@ mod test {
                 CollectionConfig::default(),
                 CollectionConfig::default(),
             ],
-            reserved: [0; 128],
+            exclude_frozen_assets: false,
+            required_freeze_authority: Some(PluginAuthority::Address {
+                address: Pubkey::default(),
+            }),
+            reserved: [0; 93],
         };
 
         // Act
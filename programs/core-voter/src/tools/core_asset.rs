@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    types::{FreezeDelegate, Key, PermanentFreezeDelegate, PluginAuthority, PluginType},
+};
+
+/// Returns true if the given account is a Core asset
+/// The check is cheap and only reads the account owner and the Key of the account
+pub fn is_core_asset_account(account_info: &AccountInfo) -> bool {
+    account_info.owner == &mpl_core::ID
+        && account_info
+            .try_borrow_data()
+            .map(|data| data.first() == Some(&(Key::AssetV1 as u8)))
+            .unwrap_or(false)
+}
+
+/// Returns true if the given Core asset is frozen by its FreezeDelegate or PermanentFreezeDelegate plugin
+pub fn is_core_asset_frozen(asset_info: &AccountInfo) -> bool {
+    let is_frozen =
+        fetch_plugin::<BaseAssetV1, FreezeDelegate>(asset_info, PluginType::FreezeDelegate)
+            .map(|(_, freeze_delegate, _)| freeze_delegate.frozen)
+            .unwrap_or(false);
+
+    is_frozen
+        || fetch_plugin::<BaseAssetV1, PermanentFreezeDelegate>(
+            asset_info,
+            PluginType::PermanentFreezeDelegate,
+        )
+        .map(|(_, permanent_freeze_delegate, _)| permanent_freeze_delegate.frozen)
+        .unwrap_or(false)
+}
+
+/// Returns true if the given Core asset is frozen by its FreezeDelegate or PermanentFreezeDelegate plugin
+/// and the frozen plugin's authority is the given freeze_authority
+pub fn is_core_asset_frozen_by(asset_info: &AccountInfo, freeze_authority: &PluginAuthority) -> bool {
+    let is_frozen =
+        fetch_plugin::<BaseAssetV1, FreezeDelegate>(asset_info, PluginType::FreezeDelegate)
+            .map(|(authority, freeze_delegate, _)| {
+                freeze_delegate.frozen && authority == *freeze_authority
+            })
+            .unwrap_or(false);
+
+    is_frozen
+        || fetch_plugin::<BaseAssetV1, PermanentFreezeDelegate>(
+            asset_info,
+            PluginType::PermanentFreezeDelegate,
+        )
+        .map(|(authority, permanent_freeze_delegate, _)| {
+            permanent_freeze_delegate.frozen && authority == *freeze_authority
+        })
+        .unwrap_or(false)
+}
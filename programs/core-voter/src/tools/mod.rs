@@ -1,2 +1,3 @@
 pub mod anchor;
+pub mod core_asset;
 pub mod governance;
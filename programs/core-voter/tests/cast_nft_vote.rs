@@ -1111,3 +1111,148 @@ async fn test_cast_asset_vote_with_invalid_voter_weight_token_owner_error() -> R
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_cast_asset_vote_with_asset_not_frozen_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 10 }),
+        )
+        .await?;
+
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::AssetMustBeFrozen);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_asset_vote_with_excluded_frozen_asset_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let frozen_asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 10 }),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&frozen_asset_cookie],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::ExcludedFrozenAssetCannotVote);
+
+    Ok(())
+}
@@ -0,0 +1,344 @@
+use crate::program_test::core_voter_test::ConfigureCollectionArgs;
+use gpl_core_voter::error::NftVoterError;
+use program_test::core_voter_test::CoreVoterTest;
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_nft_vote_records() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }), // Set Size == 1 to complete voting with just one vote
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await?;
+
+    let payer_lamports = core_voter_test
+        .bench
+        .get_account(&asset_vote_record_cookies[0].account.payer)
+        .await
+        .unwrap()
+        .lamports;
+
+    // Act
+
+    // The instruction is permissionless and the voter doesn't sign it
+    core_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &asset_vote_record_cookies,
+        )
+        .await?;
+
+    // Assert
+
+    // Check AssetVoteRecord was disposed and the rent was refunded to the payer
+    let asset_vote_record = core_voter_test
+        .bench
+        .get_account(&asset_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(None, asset_vote_record);
+
+    let payer_account = core_voter_test
+        .bench
+        .get_account(&asset_vote_record_cookies[0].account.payer)
+        .await
+        .unwrap();
+
+    assert!(payer_account.lamports > payer_lamports);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_for_proposal_in_voting_state_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    // Second asset keeps the vote below the threshold and the Proposal in Voting state
+    core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &asset_vote_record_cookies,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::ProposalMustBeFinalized);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_with_invalid_payer_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }), // Set Size == 1 to complete voting with just one vote
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await?;
+
+    // Try to redirect the rent to the voter instead of the payer
+    let voter_address = voter_cookie.address;
+
+    // Act
+    let err = core_voter_test
+        .close_nft_vote_records_using_ix(
+            &registrar_cookie,
+            &proposal_cookie,
+            &asset_vote_record_cookies,
+            |i| i.accounts[4].pubkey = voter_address, // payer
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidPayerForNftVoteRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_with_unpaired_account_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }), // Set Size == 1 to complete voting with just one vote
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .close_nft_vote_records_using_ix(
+            &registrar_cookie,
+            &proposal_cookie,
+            &asset_vote_record_cookies,
+            |i| {
+                i.accounts.pop(); // payer
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidNftVoteRecordAccountPairs);
+
+    Ok(())
+}
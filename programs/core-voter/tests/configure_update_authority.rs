@@ -0,0 +1,183 @@
+use gpl_core_voter::error::NftVoterError;
+use program_test::{core_voter_test::CoreVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+use crate::program_test::core_voter_test::ConfigureCollectionArgs;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_update_authority() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let collection_config_cookie = core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            3,
+            50,
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs.len(), 1);
+
+    assert_eq!(
+        registrar.collection_configs[0],
+        collection_config_cookie.collection_config
+    );
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+    assert_eq!(max_voter_weight_record.max_voter_weight, 150);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_collection() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(10)).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 2 }),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            3,
+            50,
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs.len(), 2);
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 10 * 2 + 50 * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_zero_size_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .with_update_authority(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            3,
+            0,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCollectionSize);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_update_authority_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let update_authority = Keypair::new();
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .with_update_authority_using_ix(
+            &registrar_cookie,
+            &update_authority.pubkey(),
+            &max_voter_weight_record_cookie,
+            3,
+            50,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
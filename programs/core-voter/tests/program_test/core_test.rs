@@ -100,6 +100,153 @@ impl CoreTest {
         collection_cookie: &CollectionCookie,
         asset_owner_cookie: &WalletCookie,
         // collection: Option<Pubkey>,
+    ) -> Result<AssetCookie, TransportError> {
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, None)
+            .await
+    }
+
+    /// Creates asset frozen by FreezeDelegate plugin
+    #[allow(dead_code)]
+    pub async fn create_frozen_asset(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+    ) -> Result<AssetCookie, TransportError> {
+        let plugins = vec![mpl_core::types::PluginAuthorityPair {
+            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                frozen: true,
+            }),
+            authority: None,
+        }];
+
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, Some(plugins))
+            .await
+    }
+
+    /// Creates asset frozen by FreezeDelegate plugin with the given authority
+    #[allow(dead_code)]
+    pub async fn create_frozen_asset_with_authority(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        authority: mpl_core::types::PluginAuthority,
+    ) -> Result<AssetCookie, TransportError> {
+        let plugins = vec![mpl_core::types::PluginAuthorityPair {
+            plugin: mpl_core::types::Plugin::FreezeDelegate(mpl_core::types::FreezeDelegate {
+                frozen: true,
+            }),
+            authority: Some(authority),
+        }];
+
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, Some(plugins))
+            .await
+    }
+
+    /// Creates asset frozen by PermanentFreezeDelegate plugin with the collection UpdateAuthority
+    #[allow(dead_code)]
+    pub async fn create_permanently_frozen_asset(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+    ) -> Result<AssetCookie, TransportError> {
+        let plugins = vec![mpl_core::types::PluginAuthorityPair {
+            plugin: mpl_core::types::Plugin::PermanentFreezeDelegate(
+                mpl_core::types::PermanentFreezeDelegate { frozen: true },
+            ),
+            authority: Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        }];
+
+        self.create_asset_with_plugins(collection_cookie, asset_owner_cookie, Some(plugins))
+            .await
+    }
+
+    /// Creates asset without a collection with UpdateAuthority::Address update authority
+    /// The update authority signs the creation and is added to the asset's VerifiedCreators plugin
+    #[allow(dead_code)]
+    pub async fn create_asset_with_update_authority(
+        &self,
+        update_authority: &Keypair,
+        asset_owner_cookie: &WalletCookie,
+        verified: bool,
+    ) -> Result<AssetCookie, TransportError> {
+        let payer = self.bench.context.borrow().payer.pubkey();
+
+        let asset_keypair = Keypair::new();
+
+        let plugins = vec![mpl_core::types::PluginAuthorityPair {
+            plugin: mpl_core::types::Plugin::VerifiedCreators(mpl_core::types::VerifiedCreators {
+                signatures: vec![mpl_core::types::VerifiedCreatorsSignature {
+                    address: update_authority.pubkey(),
+                    verified,
+                }],
+            }),
+            authority: None,
+        }];
+
+        let args = mpl_core::instructions::CreateV2InstructionArgs {
+            data_state: mpl_core::types::DataState::AccountState,
+            name: "TestAsset".to_string(),
+            uri: "URI".to_string(),
+            plugins: Some(plugins),
+            external_plugin_adapters: None,
+        };
+
+        let create_accounts = mpl_core::instructions::CreateV2 {
+            asset: asset_keypair.pubkey(),
+            collection: None,
+            authority: Some(update_authority.pubkey()),
+            payer,
+            owner: Some(asset_owner_cookie.address),
+            update_authority: Some(update_authority.pubkey()),
+            system_program: system_program::ID,
+            log_wrapper: None,
+        };
+
+        let create_ix = create_accounts.instruction(args);
+
+        self.bench
+            .process_transaction(&[create_ix], Some(&[&asset_keypair, update_authority]))
+            .await?;
+
+        Ok(AssetCookie {
+            asset: asset_keypair.pubkey(),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn burn_asset(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_cookie: &AssetCookie,
+        asset_owner_cookie: &WalletCookie,
+    ) -> Result<(), TransportError> {
+        let payer = self.bench.context.borrow().payer.pubkey();
+
+        let burn_accounts = mpl_core::instructions::BurnV1 {
+            asset: asset_cookie.asset,
+            collection: Some(collection_cookie.collection),
+            payer,
+            authority: Some(asset_owner_cookie.address),
+            system_program: None,
+            log_wrapper: None,
+        };
+
+        let burn_ix = burn_accounts.instruction(mpl_core::instructions::BurnV1InstructionArgs {
+            compression_proof: None,
+        });
+
+        self.bench
+            .process_transaction(&[burn_ix], Some(&[&asset_owner_cookie.signer]))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_asset_with_plugins(
+        &self,
+        collection_cookie: &CollectionCookie,
+        asset_owner_cookie: &WalletCookie,
+        plugins: Option<Vec<mpl_core::types::PluginAuthorityPair>>,
     ) -> Result<AssetCookie, TransportError> {
         let collection_authority = self.bench.context.borrow().payer.pubkey();
         let payer = self.bench.context.borrow().payer.pubkey();
@@ -115,7 +262,7 @@ impl CoreTest {
             data_state: mpl_core::types::DataState::AccountState,
             name,
             uri,
-            plugins: None,
+            plugins,
             external_plugin_adapters: None,
         };
 
@@ -228,7 +228,9 @@ impl CoreVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             collection_configs: vec![],
-            reserved: [0; 128],
+            exclude_frozen_assets: false,
+            required_freeze_authority: None,
+            reserved: [0; 93],
         };
 
         Ok(RegistrarCookie {
@@ -424,6 +426,8 @@ impl CoreVoterTest {
         let accounts = gpl_core_voter::accounts::UpdateMaxVoterWeightRecord {
             registrar: registrar_cookie.address,
             max_voter_weight_record: max_voter_weight_record_cookie.address,
+            realm: None,
+            realm_authority: None,
         };
 
         let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
@@ -437,6 +441,178 @@ impl CoreVoterTest {
         self.bench.process_transaction(&instructions, None).await
     }
 
+    /// Updates MaxVoterWeightRecord and refreshes the sizes of the given collections
+    /// Each collection is followed by its frozen assets which are excluded from the collection size
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_with_collections(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        collections: &[(&CollectionCookie, &[&AssetCookie])],
+    ) -> Result<(), BanksClientError> {
+        self.update_max_voter_weight_record_with_collections_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            collections,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_with_collections_using_ix<
+        F: Fn(&mut Instruction),
+    >(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        collections: &[(&CollectionCookie, &[&AssetCookie])],
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_voter::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_core_voter::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            realm: Some(registrar_cookie.account.realm),
+            realm_authority: Some(registrar_cookie.realm_authority.pubkey()),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for (collection_cookie, frozen_asset_cookies) in collections {
+            account_metas.push(AccountMeta::new_readonly(
+                collection_cookie.collection,
+                false,
+            ));
+
+            for asset_cookie in frozen_asset_cookies.iter() {
+                account_metas.push(AccountMeta::new_readonly(asset_cookie.asset, false));
+            }
+        }
+
+        let mut update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut update_max_voter_weight_record_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_exclude_frozen_assets(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        exclude_frozen_assets: bool,
+    ) -> Result<(), BanksClientError> {
+        self.set_exclude_frozen_assets_using_ix(
+            registrar_cookie,
+            exclude_frozen_assets,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_exclude_frozen_assets_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        exclude_frozen_assets: bool,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_voter::instruction::SetExcludeFrozenAssets {
+                exclude_frozen_assets,
+            },
+        );
+
+        let accounts = gpl_core_voter::accounts::SetExcludeFrozenAssets {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut set_exclude_frozen_assets_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_exclude_frozen_assets_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_exclude_frozen_assets_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_required_freeze_authority(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
+    ) -> Result<(), BanksClientError> {
+        self.set_required_freeze_authority_using_ix(
+            registrar_cookie,
+            required_freeze_authority,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_required_freeze_authority_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        required_freeze_authority: Option<mpl_core::types::PluginAuthority>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_voter::instruction::SetRequiredFreezeAuthority {
+                required_freeze_authority,
+            },
+        );
+
+        let accounts = gpl_core_voter::accounts::SetRequiredFreezeAuthority {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut set_required_freeze_authority_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_required_freeze_authority_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_required_freeze_authority_ix], Some(signers))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn relinquish_nft_vote(
         &mut self,
@@ -446,6 +622,29 @@ impl CoreVoterTest {
         voter_cookie: &WalletCookie,
         voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
         asset_vote_record_cookies: &Vec<AssetVoteRecordCookie>,
+    ) -> Result<(), BanksClientError> {
+        self.relinquish_nft_vote_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            proposal_cookie,
+            voter_cookie,
+            voter_token_owner_record_cookie,
+            asset_vote_record_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn relinquish_nft_vote_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        asset_vote_record_cookies: &Vec<AssetVoteRecordCookie>,
+        instruction_override: F,
     ) -> Result<(), BanksClientError> {
         let data =
             anchor_lang::InstructionData::data(&gpl_core_voter::instruction::RelinquishNftVote {});
@@ -473,12 +672,14 @@ impl CoreVoterTest {
             account_metas.push(AccountMeta::new(asset_vote_record_cookie.address, false));
         }
 
-        let relinquish_nft_vote_ix = Instruction {
+        let mut relinquish_nft_vote_ix = Instruction {
             program_id: gpl_core_voter::id(),
             accounts: account_metas,
             data,
         };
 
+        instruction_override(&mut relinquish_nft_vote_ix);
+
         self.bench
             .process_transaction(&[relinquish_nft_vote_ix], Some(&[&voter_cookie.signer]))
             .await?;
@@ -486,6 +687,63 @@ impl CoreVoterTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn close_nft_vote_records(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        proposal_cookie: &ProposalCookie,
+        asset_vote_record_cookies: &[AssetVoteRecordCookie],
+    ) -> Result<(), BanksClientError> {
+        self.close_nft_vote_records_using_ix(
+            registrar_cookie,
+            proposal_cookie,
+            asset_vote_record_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_vote_records_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        proposal_cookie: &ProposalCookie,
+        asset_vote_record_cookies: &[AssetVoteRecordCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_voter::instruction::CloseNftVoteRecords {},
+        );
+
+        let accounts = gpl_core_voter::accounts::CloseNftVoteRecords {
+            registrar: registrar_cookie.address,
+            governance: proposal_cookie.account.governance,
+            proposal: proposal_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for asset_vote_record_cookie in asset_vote_record_cookies {
+            account_metas.push(AccountMeta::new(asset_vote_record_cookie.address, false));
+            account_metas.push(AccountMeta::new(
+                asset_vote_record_cookie.account.payer,
+                false,
+            ));
+        }
+
+        let mut close_nft_vote_records_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut close_nft_vote_records_ix);
+
+        self.bench
+            .process_transaction(&[close_nft_vote_records_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_collection(
         &mut self,
@@ -553,12 +811,137 @@ impl CoreVoterTest {
             collection: collection_cookie.collection,
             size: collection_account.current_size,
             weight: args.weight,
-            reserved: [0; 8],
+            kind: CollectionKind::Collection,
+            reserved: [0; 7],
         };
 
         Ok(CollectionConfigCookie { collection_config })
     }
 
+    #[allow(dead_code)]
+    pub async fn with_update_authority(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        update_authority: &Pubkey,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        weight: u64,
+        size: u32,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        self.with_update_authority_using_ix(
+            registrar_cookie,
+            update_authority,
+            max_voter_weight_record_cookie,
+            weight,
+            size,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn with_update_authority_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        update_authority: &Pubkey,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        weight: u64,
+        size: u32,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_core_voter::instruction::ConfigureUpdateAuthority { weight, size },
+        );
+
+        let accounts = gpl_core_voter::accounts::ConfigureUpdateAuthority {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            update_authority: *update_authority,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_update_authority_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_update_authority_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_update_authority_ix], Some(signers))
+            .await?;
+
+        let collection_config = CollectionConfig {
+            collection: *update_authority,
+            size,
+            weight,
+            kind: CollectionKind::UpdateAuthority,
+            reserved: [0; 7],
+        };
+
+        Ok(CollectionConfigCookie { collection_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_collection_using_ix(
+            registrar_cookie,
+            collection_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_cookie: &CollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_core_voter::instruction::RemoveCollection {});
+
+        let accounts = gpl_core_voter::accounts::RemoveCollection {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: collection_cookie.collection,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut remove_collection_ix = Instruction {
+            program_id: gpl_core_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_collection_ix], Some(signers))
+            .await
+    }
+
     /// Casts NFT Vote and spl-gov Vote
     #[allow(dead_code)]
     pub async fn cast_asset_vote(
@@ -602,6 +985,7 @@ impl CoreVoterTest {
                 governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
                 account_discriminator: AssetVoteRecord::ACCOUNT_DISCRIMINATOR,
                 reserved: [0; 8],
+                payer: self.bench.payer.pubkey(),
             };
 
             asset_vote_record_cookies.push(AssetVoteRecordCookie {
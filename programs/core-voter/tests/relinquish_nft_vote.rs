@@ -660,3 +660,87 @@ async fn test_relinquish_nft_vote_using_delegate() -> Result<(), TransportError>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_relinquish_nft_vote_with_invalid_beneficiary_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }),
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            None,
+        )
+        .await?;
+
+    core_voter_test.bench.advance_clock().await;
+
+    // Try to take the rent paid by the payer
+    let voter_address = voter_cookie.address;
+
+    // Act
+    let err = core_voter_test
+        .relinquish_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &asset_vote_record_cookies,
+            |i| i.accounts[7].pubkey = voter_address, // beneficiary
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidPayerForNftVoteRecord);
+
+    Ok(())
+}
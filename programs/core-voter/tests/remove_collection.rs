@@ -0,0 +1,263 @@
+use crate::program_test::core_voter_test::ConfigureCollectionArgs;
+use gpl_core_voter::error::NftVoterError;
+use program_test::core_voter_test::{CastAssetVoteArgs, CoreVoterTest};
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_remove_collection() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie1 = core_voter_test.core.create_collection(None).await?;
+    let collection_cookie2 = core_voter_test.core.create_collection(None).await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    core_voter_test
+        .core
+        .create_asset(&collection_cookie1, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .core
+        .create_asset(&collection_cookie2, &voter_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie1,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1 }),
+        )
+        .await?;
+
+    let collection_config_cookie2 = core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie2,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 2 }),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .remove_collection(
+            &registrar_cookie,
+            &collection_cookie1,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.collection_configs,
+        vec![collection_config_cookie2.collection_config]
+    );
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight,
+        collection_config_cookie2.collection_config.get_max_weight()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_collection_with_collection_not_found_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .remove_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CollectionNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_collection_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .remove_collection_using_ix(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_relinquish_nft_vote_for_removed_collection() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(None).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_token_owner_record_cookie = core_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = core_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = core_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    let asset_vote_record_cookies = core_voter_test
+        .cast_asset_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&asset_cookie1],
+            Some(CastAssetVoteArgs {
+                cast_spl_gov_vote: false,
+            }),
+        )
+        .await?;
+
+    core_voter_test
+        .remove_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+        )
+        .await?;
+
+    core_voter_test.bench.advance_clock().await;
+
+    // Act
+    core_voter_test
+        .relinquish_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &asset_vote_record_cookies,
+        )
+        .await?;
+
+    // Assert
+    let asset_vote_record = core_voter_test
+        .bench
+        .get_account(&asset_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(None, asset_vote_record);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use gpl_core_voter::error::NftVoterError;
+use program_test::core_voter_test::CoreVoterTest;
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_set_exclude_frozen_assets() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(registrar.exclude_frozen_assets);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_exclude_frozen_assets_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .set_exclude_frozen_assets_using_ix(
+            &registrar_cookie,
+            true,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_exclude_frozen_assets_with_required_freeze_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::RequiredFreezeAuthorityWithExcludedFrozenAssets,
+    );
+
+    Ok(())
+}
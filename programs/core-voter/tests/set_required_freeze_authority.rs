@@ -0,0 +1,157 @@
+use gpl_core_voter::error::NftVoterError;
+use program_test::core_voter_test::CoreVoterTest;
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_set_required_freeze_authority() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.required_freeze_authority,
+        Some(mpl_core::types::PluginAuthority::UpdateAuthority)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_required_freeze_authority_to_none() -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .set_required_freeze_authority(&registrar_cookie, None)
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.required_freeze_authority, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_required_freeze_authority_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .set_required_freeze_authority_using_ix(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_required_freeze_authority_with_owner_authority_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::Owner),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRequiredFreezeAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_required_freeze_authority_with_excluded_frozen_assets_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .set_required_freeze_authority(
+            &registrar_cookie,
+            Some(mpl_core::types::PluginAuthority::UpdateAuthority),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::RequiredFreezeAuthorityWithExcludedFrozenAssets,
+    );
+
+    Ok(())
+}
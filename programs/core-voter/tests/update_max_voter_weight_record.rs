@@ -1,6 +1,10 @@
 use crate::program_test::core_voter_test::ConfigureCollectionArgs;
+use gpl_core_voter::error::NftVoterError;
 use program_test::core_voter_test::CoreVoterTest;
+use program_test::tools::assert_nft_voter_err;
 use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
 use solana_sdk::transport::TransportError;
 
 mod program_test;
@@ -186,3 +190,325 @@ async fn test_update_max_voter_weight_record_provides_valid_expirey() -> Result<
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_collection_size_sync(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(3)).await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 2 }),
+        )
+        .await?;
+
+    // Mint 2 assets and burn 1 after the collection was configured
+    let asset_cookie1 = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .core
+        .burn_asset(&collection_cookie, &asset_cookie1, &voter_cookie)
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_max_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[])],
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs[0].size, 4);
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 8);
+    assert!(max_voter_weight_record.max_voter_weight_expiry.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_frozen_assets_excluded(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(2)).await?;
+
+    let frozen_asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 10 }),
+        )
+        .await?;
+
+    // Act
+    core_voter_test
+        .update_max_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[&frozen_asset_cookie])],
+        )
+        .await?;
+
+    // Assert
+    let registrar = core_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs[0].size, 2);
+
+    let max_voter_weight_record = core_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_frozen_assets_not_excluded_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(2)).await?;
+
+    let frozen_asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[&frozen_asset_cookie])],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::FrozenAssetsNotExcluded);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_asset_not_frozen_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(2)).await?;
+
+    let asset_cookie = core_voter_test
+        .core
+        .create_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[&asset_cookie])],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::AssetMustBeFrozen);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_collection_not_found_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(2)).await?;
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_collections(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[])],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CollectionNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_frozen_assets_excluded_and_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut core_voter_test = CoreVoterTest::start_new().await;
+
+    let realm_cookie = core_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = core_voter_test.with_registrar(&realm_cookie).await?;
+
+    core_voter_test
+        .set_exclude_frozen_assets(&registrar_cookie, true)
+        .await?;
+
+    let max_voter_weight_record_cookie = core_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = core_voter_test.bench.with_wallet().await;
+
+    let collection_cookie = core_voter_test.core.create_collection(Some(2)).await?;
+
+    let frozen_asset_cookie = core_voter_test
+        .core
+        .create_frozen_asset(&collection_cookie, &voter_cookie)
+        .await?;
+
+    core_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = core_voter_test
+        .update_max_voter_weight_record_with_collections_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[(&collection_cookie, &[&frozen_asset_cookie])],
+            |i| i.accounts[3].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
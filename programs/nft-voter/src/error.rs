@@ -73,4 +73,37 @@ pub enum NftVoterError {
 
     #[msg("VoterWeightRecord must be expired")]
     VoterWeightRecordMustBeExpired,
+
+    #[msg("Invalid NFT token account")]
+    InvalidNftTokenAccount,
+
+    #[msg("TokenRecord is required for programmable NFT")]
+    MissingTokenRecord,
+
+    #[msg("Invalid TokenRecord for programmable NFT")]
+    InvalidTokenRecord,
+
+    #[msg("Listed programmable NFT can't be used for voting")]
+    ProgrammableNftListed,
+
+    #[msg("Collection sizes are fixed for the Registrar")]
+    FixedCollectionSizes,
+
+    #[msg("Collection is not a sized collection")]
+    CollectionIsNotSized,
+
+    #[msg("Voting on the Proposal must be finalized")]
+    ProposalMustBeFinalized,
+
+    #[msg("Invalid payer for NftVoteRecord")]
+    InvalidPayerForNftVoteRecord,
+
+    #[msg("Merkle tree must be managed by Bubblegum")]
+    InvalidMerkleTreeAuthority,
+
+    #[msg("NftVoteRecords and their payers must be supplied in pairs")]
+    InvalidNftVoteRecordAccountPairs,
+
+    #[msg("Programmable NFT delegate role can't be used for voting")]
+    ProgrammableNftDelegateRoleNotAllowed,
 }
@@ -0,0 +1,141 @@
+use crate::error::NftVoterError;
+use crate::tools::bubblegum::SPL_ACCOUNT_COMPRESSION_PROGRAM_ID;
+use crate::{id, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+/// Casts compressed NFT (Bubblegum) vote. The compressed NFTs used for voting are tracked using NftVoteRecord accounts
+/// keyed by the asset id of the compressed NFT
+/// This instruction updates VoterWeightRecord which is valid for the current Slot and the target Proposal only
+/// and hance the instruction has to be executed inside the same transaction as spl-gov.CastVote
+///
+/// The Merkle proof of the compressed NFT leaf is passed as remaining accounts and because of the proof size
+/// only a single compressed NFT can be used per instruction
+/// CastCompressedNftVote is accumulative with CastNftVote and can be invoked several times for the same Proposal
+/// In this scenario only the last instruction should be bundled with spl-gov.CastVote in the same transaction
+#[derive(Accounts)]
+#[instruction(proposal: Pubkey)]
+pub struct CastCompressedNftVote<'info> {
+    /// The NFT voting registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter who casts the vote
+    #[account(
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    voter_token_owner_record: UncheckedAccount<'info>,
+
+    /// Authority of the voter who casts the vote
+    /// It can be either governing_token_owner or its delegate and must sign this instruction
+    pub voter_authority: Signer<'info>,
+
+    /// Merkle tree the compressed NFT belongs to
+    /// It must be managed by Bubblegum, i.e. its authority must be the Bubblegum TreeConfig of the tree
+    #[account(owner = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    /// CHECK: The tree authority is validated in the instruction and the leaf is verified against the tree by spl-account-compression
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// NftVoteRecord of the compressed NFT
+    #[account(mut)]
+    /// CHECK: The PDA is validated in create_and_serialize_account_signed
+    pub nft_vote_record: UncheckedAccount<'info>,
+
+    /// CHECK: spl-account-compression program used to verify the leaf
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// The account which pays for the transaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Casts vote with the compressed NFT
+pub fn cast_compressed_nft_vote<'info>(
+    ctx: Context<'_, '_, '_, 'info, CastCompressedNftVote<'info>>,
+    proposal: Pubkey,
+    compressed_nft: CompressedNftArgs,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    let governing_token_owner = resolve_governing_token_owner(
+        registrar,
+        &ctx.accounts.voter_token_owner_record,
+        &ctx.accounts.voter_authority,
+        voter_weight_record,
+    )?;
+
+    let (nft_vote_weight, asset_id) = resolve_compressed_nft_vote_weight_and_asset_id(
+        registrar,
+        &governing_token_owner,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.compression_program,
+        ctx.remaining_accounts,
+        &compressed_nft,
+    )?;
+
+    // Create NFT vote record to ensure the same compressed NFT hasn't been already used for voting
+    // Note: The correct PDA of the NftVoteRecord is validated in create_and_serialize_account_signed
+    // It ensures the NftVoteRecord is for ('nft-vote-record',proposal,asset_id) seeds
+    let nft_vote_record_info = &ctx.accounts.nft_vote_record;
+
+    require!(
+        nft_vote_record_info.data_is_empty(),
+        NftVoterError::NftAlreadyVoted
+    );
+
+    let nft_vote_record = NftVoteRecord {
+        account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+        proposal,
+        nft_mint: asset_id,
+        governing_token_owner,
+        reserved: [0; 8],
+        payer: ctx.accounts.payer.key(),
+    };
+
+    create_and_serialize_account_signed(
+        &ctx.accounts.payer.to_account_info(),
+        nft_vote_record_info,
+        &nft_vote_record,
+        &get_nft_vote_record_seeds(&proposal, &asset_id),
+        &id(),
+        &ctx.accounts.system_program.to_account_info(),
+        &Rent::get()?,
+        0,
+    )?;
+
+    if voter_weight_record.weight_action_target == Some(proposal)
+        && voter_weight_record.weight_action == Some(VoterWeightAction::CastVote)
+    {
+        // If the vote is cast for the same proposal then we keep accumulating the weight
+        voter_weight_record.voter_weight = voter_weight_record
+            .voter_weight
+            .checked_add(nft_vote_weight)
+            .unwrap();
+    } else {
+        voter_weight_record.voter_weight = nft_vote_weight;
+    }
+
+    // The record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // The record is only valid for casting vote on the given Proposal
+    voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
+    voter_weight_record.weight_action_target = Some(proposal);
+
+    Ok(())
+}
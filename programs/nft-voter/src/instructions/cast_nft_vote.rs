@@ -1,4 +1,5 @@
 use crate::error::NftVoterError;
+use crate::tools::token_metadata::is_token_record_account;
 use crate::{id, state::*};
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
@@ -71,14 +72,25 @@ pub fn cast_nft_vote<'info>(
 
     let rent = Rent::get()?;
 
-    for (nft_info, nft_metadata_info, nft_vote_record_info) in
-        ctx.remaining_accounts.iter().tuples()
-    {
+    // Each NFT is supplied as (nft, metadata, [token_record], nft_vote_record) accounts
+    // where token_record is only present for programmable NFTs
+    let mut remaining_accounts = ctx.remaining_accounts.iter().peekable();
+
+    while let Some((nft_info, nft_metadata_info)) = remaining_accounts.next_tuple() {
+        let nft_token_record_info =
+            remaining_accounts.next_if(|info| is_token_record_account(info));
+
+        let nft_vote_record_info = match remaining_accounts.next() {
+            Some(nft_vote_record_info) => nft_vote_record_info,
+            None => break,
+        };
+
         let (nft_vote_weight, nft_mint) = resolve_nft_vote_weight_and_mint(
             registrar,
             &governing_token_owner,
             nft_info,
             nft_metadata_info,
+            nft_token_record_info,
             &mut unique_nft_mints,
         )?;
 
@@ -104,6 +116,7 @@ pub fn cast_nft_vote<'info>(
             nft_mint,
             governing_token_owner,
             reserved: [0; 8],
+            payer: ctx.accounts.payer.key(),
         };
 
         // Anchor doesn't natively support dynamic account creation using remaining_accounts
@@ -95,8 +95,8 @@ pub fn configure_collection(
         registrar.collection_configs.push(collection_config);
     }
 
-    // TODO: if weight == 0 then remove the collection from config
-    // Currently if weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // Note: If weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // remove_collection should be used to remove the collection and free its slot
 
     // Update MaxVoterWeightRecord based on max voting power of the collections
     let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
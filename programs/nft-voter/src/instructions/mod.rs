@@ -1,6 +1,15 @@
 pub use configure_collection::*;
 mod configure_collection;
 
+pub use remove_collection::*;
+mod remove_collection;
+
+pub use set_fixed_collection_sizes::*;
+mod set_fixed_collection_sizes;
+
+pub use set_allow_token_delegates::*;
+mod set_allow_token_delegates;
+
 pub use create_registrar::*;
 mod create_registrar;
 
@@ -13,8 +22,17 @@ mod create_max_voter_weight_record;
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
 
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
 pub use relinquish_nft_vote::*;
 mod relinquish_nft_vote;
 
+pub use close_nft_vote_records::*;
+mod close_nft_vote_records;
+
 pub use cast_nft_vote::*;
 mod cast_nft_vote;
+
+pub use cast_compressed_nft_vote::*;
+mod cast_compressed_nft_vote;
@@ -0,0 +1,88 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar};
+
+/// Removes NFT voting collection from the Registrar
+/// It's used to retire a collection or to disable a compromised one and the freed slot can be reused by configure_collection
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+///
+/// Note: Outstanding NftVoteRecords of the removed collection are not affected and can still be relinquished
+#[derive(Accounts)]
+pub struct RemoveCollection<'info> {
+    /// Registrar from which we remove the Collection
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: Collection which is going to be removed
+    /// It's only used as the collection key and isn't required to be a valid collection any longer
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn remove_collection(ctx: Context<RemoveCollection>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    let collection = ctx.accounts.collection.key();
+
+    let collection_idx = registrar
+        .collection_configs
+        .iter()
+        .position(|cc| cc.collection == collection)
+        .ok_or(NftVoterError::CollectionNotFound)?;
+
+    // The Registrar account keeps its size and the slot can be reused for another collection
+    registrar.collection_configs.remove(collection_idx);
+
+    // Update MaxVoterWeightRecord based on max voting power of the remaining collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar
+        .collection_configs
+        .iter()
+        .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
+        .unwrap();
+
+    // The weight never expires and only changes when collections are configured
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
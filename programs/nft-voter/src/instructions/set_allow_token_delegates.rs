@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::Registrar;
+
+/// Sets whether NFTs delegated to voters with spl-token approve can be used for voting
+/// It lets escrow, staking and rental programs which custody NFTs prove beneficial ownership
+/// by approving the beneficial owner as the token delegate
+/// Programmable NFTs can only be voted by their Standard, Utility, Staking or LockedTransfer delegates
+#[derive(Accounts)]
+pub struct SetAllowTokenDelegates<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+pub fn set_allow_token_delegates(
+    ctx: Context<SetAllowTokenDelegates>,
+    allow_token_delegates: bool,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    registrar.allow_token_delegates = allow_token_delegates;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::error::NftVoterError;
+use crate::state::Registrar;
+
+/// Sets whether the Registrar opts out of collection size sync
+/// DAOs which prefer fixed collection sizes set them with configure_collection
+/// and update_max_voter_weight_record is disallowed for their Registrar
+#[derive(Accounts)]
+pub struct SetFixedCollectionSizes<'info> {
+    /// The NFT voting Registrar
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+pub fn set_fixed_collection_sizes(
+    ctx: Context<SetFixedCollectionSizes>,
+    fixed_collection_sizes: bool,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
+        NftVoterError::InvalidRealmAuthority
+    );
+
+    registrar.fixed_collection_sizes = fixed_collection_sizes;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::convert::TryFrom;
+
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::token_metadata::get_token_metadata;
+use anchor_lang::prelude::*;
+use max_voter_weight_record::MaxVoterWeightRecord;
+use mpl_token_metadata::types::CollectionDetails;
+
+/// Refreshes sizes of the configured collections from their sized collection metadata
+/// and updates MaxVoterWeightRecord accordingly
+/// The instruction is permissionless and can be run as a crank when the collections are minted or burned
+///
+/// The metadata accounts of the collections to refresh are passed as remaining accounts
+/// Collections which are not passed keep their current size
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The NFT voting Registrar
+    #[account(
+        mut,
+        constraint = !registrar.fixed_collection_sizes @ NftVoterError::FixedCollectionSizes
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    for collection_metadata_info in ctx.remaining_accounts.iter() {
+        let collection_metadata = get_token_metadata(collection_metadata_info)?;
+
+        let collection_config = registrar
+            .collection_configs
+            .iter_mut()
+            .find(|cc| cc.collection == collection_metadata.mint)
+            .ok_or(NftVoterError::CollectionNotFound)?;
+
+        let size = match collection_metadata.collection_details {
+            Some(CollectionDetails::V1 { size }) => size,
+            _ => return err!(NftVoterError::CollectionIsNotSized),
+        };
+
+        collection_config.size =
+            u32::try_from(size).map_err(|_| NftVoterError::InvalidCollectionSize)?;
+    }
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar
+        .collection_configs
+        .iter()
+        .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
+        .unwrap();
+
+    // The weight never expires and only changes when collections are configured or their sizes refreshed
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
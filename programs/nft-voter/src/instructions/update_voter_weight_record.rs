@@ -1,5 +1,6 @@
 use crate::error::NftVoterError;
 use crate::state::*;
+use crate::tools::token_metadata::is_token_record_account;
 use anchor_lang::prelude::*;
 use itertools::Itertools;
 
@@ -49,12 +50,20 @@ pub fn update_voter_weight_record(
     // Ensure all nfts are unique
     let mut unique_nft_mints = vec![];
 
-    for (nft_info, nft_metadata_info) in ctx.remaining_accounts.iter().tuples() {
+    // Each NFT is supplied as (nft, metadata, [token_record]) accounts
+    // where token_record is only present for programmable NFTs
+    let mut remaining_accounts = ctx.remaining_accounts.iter().peekable();
+
+    while let Some((nft_info, nft_metadata_info)) = remaining_accounts.next_tuple() {
+        let nft_token_record_info =
+            remaining_accounts.next_if(|info| is_token_record_account(info));
+
         let (nft_vote_weight, _) = resolve_nft_vote_weight_and_mint(
             registrar,
             governing_token_owner,
             nft_info,
             nft_metadata_info,
+            nft_token_record_info,
             &mut unique_nft_mints,
         )?;
 
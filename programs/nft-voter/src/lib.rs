@@ -41,10 +41,18 @@ pub mod nft_voter {
         log_version();
         instructions::update_voter_weight_record(ctx, voter_weight_action)
     }
+    pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
     pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
         log_version();
         instructions::relinquish_nft_vote(ctx)
     }
+    pub fn close_nft_vote_records(ctx: Context<CloseNftVoteRecords>) -> Result<()> {
+        log_version();
+        instructions::close_nft_vote_records(ctx)
+    }
     pub fn configure_collection(
         ctx: Context<ConfigureCollection>,
         weight: u64,
@@ -53,6 +61,24 @@ pub mod nft_voter {
         log_version();
         instructions::configure_collection(ctx, weight, size)
     }
+    pub fn remove_collection(ctx: Context<RemoveCollection>) -> Result<()> {
+        log_version();
+        instructions::remove_collection(ctx)
+    }
+    pub fn set_fixed_collection_sizes(
+        ctx: Context<SetFixedCollectionSizes>,
+        fixed_collection_sizes: bool,
+    ) -> Result<()> {
+        log_version();
+        instructions::set_fixed_collection_sizes(ctx, fixed_collection_sizes)
+    }
+    pub fn set_allow_token_delegates(
+        ctx: Context<SetAllowTokenDelegates>,
+        allow_token_delegates: bool,
+    ) -> Result<()> {
+        log_version();
+        instructions::set_allow_token_delegates(ctx, allow_token_delegates)
+    }
 
     pub fn cast_nft_vote<'info>(
         ctx: Context<'_, '_, '_, 'info, CastNftVote<'info>>,
@@ -61,6 +87,15 @@ pub mod nft_voter {
         log_version();
         instructions::cast_nft_vote(ctx, proposal)
     }
+
+    pub fn cast_compressed_nft_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, CastCompressedNftVote<'info>>,
+        proposal: Pubkey,
+        compressed_nft: CompressedNftArgs,
+    ) -> Result<()> {
+        log_version();
+        instructions::cast_compressed_nft_vote(ctx, proposal, compressed_nft)
+    }
 }
 
 fn log_version() {
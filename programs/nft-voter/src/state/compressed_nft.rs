@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+/// Compressed NFT metadata as stored by mpl-bubblegum
+/// The layout must match Bubblegum MetadataArgs because it's hashed into the leaf data_hash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataArgs {
+    /// The name of the asset
+    pub name: String,
+
+    /// The symbol for the asset
+    pub symbol: String,
+
+    /// URI pointing to JSON representing the asset
+    pub uri: String,
+
+    /// Royalty basis points that goes to creators in secondary sales (0-10000)
+    pub seller_fee_basis_points: u16,
+
+    /// Immutable, once flipped, all sales of this metadata are considered secondary
+    pub primary_sale_happened: bool,
+
+    /// Whether or not the data struct is mutable
+    pub is_mutable: bool,
+
+    /// Nonce for easy calculation of editions, if present
+    pub edition_nonce: Option<u8>,
+
+    /// Token standard of the asset
+    pub token_standard: Option<TokenStandard>,
+
+    /// Collection of the asset
+    pub collection: Option<Collection>,
+
+    /// Uses of the asset
+    pub uses: Option<Uses>,
+
+    /// Token program version of the asset
+    pub token_program_version: TokenProgramVersion,
+
+    /// Creators of the asset
+    pub creators: Vec<Creator>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    /// The percentage share of royalties
+    pub share: u8,
+}
+
+/// Compressed NFT leaf supplied by the voter
+/// The leaf is rebuilt from the args and verified against the Merkle tree using the proof
+/// passed in remaining accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedNftArgs {
+    /// Merkle tree root the proof was generated for
+    pub root: [u8; 32],
+
+    /// Delegate of the leaf, the owner itself if the leaf is not delegated
+    pub leaf_delegate: Pubkey,
+
+    /// Nonce of the leaf used to derive the asset id
+    pub nonce: u64,
+
+    /// Index of the leaf in the Merkle tree
+    pub index: u32,
+
+    /// Metadata of the compressed NFT
+    pub metadata: MetadataArgs,
+}
@@ -7,6 +7,9 @@ pub mod collection_config;
 pub use nft_vote_record::*;
 pub mod nft_vote_record;
 
+pub use compressed_nft::*;
+pub mod compressed_nft;
+
 pub mod max_voter_weight_record;
 
 pub use voter_weight_record::*;
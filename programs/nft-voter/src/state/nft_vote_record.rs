@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program_pack::IsInitialized;
 
+use anchor_lang::solana_program::borsh1::try_from_slice_unchecked;
 use spl_governance_tools::account::{get_account_data, AccountMaxSize};
 
 use crate::{error::NftVoterError, id};
@@ -30,11 +31,18 @@ pub struct NftVoteRecord {
 
     /// Reserved for future upgrades
     pub reserved: [u8; 8],
+
+    /// The account which paid the rent for the record and receives it back when the record is closed
+    /// NftVoteRecords created before the payer was recorded have the default Pubkey as the payer
+    pub payer: Pubkey,
 }
 
 impl NftVoteRecord {
     /// sha256("account:NftVoteRecord")[..8]
     pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [137, 6, 55, 139, 251, 126, 254, 99];
+
+    /// Size of NftVoteRecords created before the payer was recorded
+    pub const LEGACY_ACCOUNT_SIZE: usize = 8 + 32 * 3 + 8;
 }
 
 impl AccountMaxSize for NftVoteRecord {}
@@ -57,16 +65,34 @@ pub fn get_nft_vote_record_address(proposal: &Pubkey, nft_mint: &Pubkey) -> Pubk
 
 /// Deserializes account and checks owner program
 pub fn get_nft_vote_record_data(nft_vote_record_info: &AccountInfo) -> Result<NftVoteRecord> {
-    Ok(get_account_data::<NftVoteRecord>(
-        &id(),
-        nft_vote_record_info,
-    )?)
+    if nft_vote_record_info.data_len() != NftVoteRecord::LEGACY_ACCOUNT_SIZE {
+        return Ok(get_account_data::<NftVoteRecord>(
+            &id(),
+            nft_vote_record_info,
+        )?);
+    }
+
+    // Legacy NftVoteRecords don't have the payer and it's deserialized as the default Pubkey
+    require!(
+        nft_vote_record_info.owner == &id(),
+        NftVoterError::InvalidAccountOwner
+    );
+
+    let mut data = nft_vote_record_info.try_borrow_data()?.to_vec();
+    data.extend_from_slice(Pubkey::default().as_ref());
+
+    let nft_vote_record: NftVoteRecord = try_from_slice_unchecked(&data)?;
+
+    if !nft_vote_record.is_initialized() {
+        return Err(ProgramError::UninitializedAccount.into());
+    }
+
+    Ok(nft_vote_record)
 }
 
-pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
+pub fn get_nft_vote_record_data_for_proposal(
     nft_vote_record_info: &AccountInfo,
     proposal: &Pubkey,
-    governing_token_owner: &Pubkey,
 ) -> Result<NftVoteRecord> {
     let nft_vote_record = get_nft_vote_record_data(nft_vote_record_info)?;
 
@@ -75,6 +101,16 @@ pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
         NftVoterError::InvalidProposalForNftVoteRecord
     );
 
+    Ok(nft_vote_record)
+}
+
+pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
+    nft_vote_record_info: &AccountInfo,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Result<NftVoteRecord> {
+    let nft_vote_record = get_nft_vote_record_data_for_proposal(nft_vote_record_info, proposal)?;
+
     require!(
         nft_vote_record.governing_token_owner == *governing_token_owner,
         NftVoterError::InvalidTokenOwnerForNftVoteRecord
@@ -82,3 +118,51 @@ pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
 
     Ok(nft_vote_record)
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_lang::solana_program::{borsh1::get_instance_packed_len, pubkey::PUBKEY_BYTES};
+
+    #[test]
+    fn test_get_nft_vote_record_data_for_legacy_record() {
+        // Arrange
+        let nft_vote_record = NftVoteRecord {
+            account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::new_unique(),
+            nft_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            reserved: [0; 8],
+            payer: Pubkey::default(),
+        };
+
+        let mut data = borsh_1::to_vec(&nft_vote_record).unwrap();
+        data.truncate(NftVoteRecord::LEGACY_ACCOUNT_SIZE);
+
+        let key = Pubkey::new_unique();
+        let owner = id();
+        let mut lamports = 0;
+
+        let nft_vote_record_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let actual_nft_vote_record = get_nft_vote_record_data(&nft_vote_record_info).unwrap();
+
+        // Assert
+        assert_eq!(
+            NftVoteRecord::LEGACY_ACCOUNT_SIZE + PUBKEY_BYTES,
+            get_instance_packed_len(&nft_vote_record).unwrap()
+        );
+        assert_eq!(nft_vote_record, actual_nft_vote_record);
+    }
+}
@@ -1,16 +1,20 @@
 use crate::{
     error::NftVoterError,
     id,
-    state::{CollectionConfig, VoterWeightRecord},
+    state::{CollectionConfig, CompressedNftArgs, VoterWeightRecord},
     tools::{
-        anchor::DISCRIMINATOR_SIZE, spl_token::get_spl_token_amount,
-        token_metadata::get_token_metadata_for_mint,
+        anchor::DISCRIMINATOR_SIZE,
+        bubblegum::{assert_bubblegum_merkle_tree, get_asset_id, hash_leaf, verify_leaf},
+        spl_token::{
+            get_spl_token_amount, get_spl_token_delegate, get_spl_token_mint, get_spl_token_owner,
+        },
+        token_metadata::{get_token_metadata_for_mint, get_token_record_for_nft},
     },
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use mpl_token_metadata::types::{TokenDelegateRole, TokenStandard, TokenState};
 use spl_governance::state::token_owner_record;
-use spl_governance::tools::spl_token::{get_spl_token_mint, get_spl_token_owner};
 
 /// Registrar which stores NFT voting configuration for the given Realm
 #[account]
@@ -31,8 +35,17 @@ pub struct Registrar {
     /// MPL Collection used for voting
     pub collection_configs: Vec<CollectionConfig>,
 
+    /// Opts out of collection size sync
+    /// When set the collection sizes are only changed by configure_collection
+    /// and update_max_voter_weight_record can't refresh them from the collection metadata
+    pub fixed_collection_sizes: bool,
+
+    /// Allows NFTs delegated to the voter with spl-token approve to be used for voting
+    /// The owner and the delegate can't both vote with the same NFT because NftVoteRecord is keyed by the NFT mint
+    pub allow_token_delegates: bool,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 126],
 }
 
 impl Registrar {
@@ -41,7 +54,9 @@ impl Registrar {
             + PUBKEY_BYTES * 3
             + 4
             + max_collections as usize * (PUBKEY_BYTES + 4 + 8 + 8)
-            + 128
+            + 1
+            + 1
+            + 126
     }
 }
 
@@ -98,20 +113,35 @@ pub fn resolve_governing_token_owner(
 }
 
 /// Resolves vote weight and voting mint for the given NFT
+/// The NFT can be held by either spl-token or spl-token-2022 token account
+/// and must be owned by governing_token_owner or delegated to it if the Registrar allows token delegates
+/// and programmable NFTs must be accompanied by their TokenRecord
 pub fn resolve_nft_vote_weight_and_mint(
     registrar: &Registrar,
     governing_token_owner: &Pubkey,
     nft_info: &AccountInfo,
     nft_metadata_info: &AccountInfo,
+    nft_token_record_info: Option<&AccountInfo>,
     unique_nft_mints: &mut Vec<Pubkey>,
 ) -> Result<(u64, Pubkey)> {
     let nft_owner = get_spl_token_owner(nft_info)?;
 
     // voter_weight_record.governing_token_owner must be the owner of the NFT
-    require!(
-        nft_owner == *governing_token_owner,
-        NftVoterError::VoterDoesNotOwnNft
-    );
+    // or its token delegate if the Registrar allows token delegates
+    if nft_owner != *governing_token_owner {
+        require!(
+            registrar.allow_token_delegates,
+            NftVoterError::VoterDoesNotOwnNft
+        );
+
+        let (nft_delegate, nft_delegated_amount) =
+            get_spl_token_delegate(nft_info)?.ok_or(NftVoterError::VoterDoesNotOwnNft)?;
+
+        require!(
+            nft_delegate == *governing_token_owner && nft_delegated_amount == 1,
+            NftVoterError::VoterDoesNotOwnNft
+        );
+    }
 
     let nft_mint = get_spl_token_mint(nft_info)?;
 
@@ -128,6 +158,37 @@ pub fn resolve_nft_vote_weight_and_mint(
 
     let nft_metadata = get_token_metadata_for_mint(nft_metadata_info, &nft_mint)?;
 
+    // Programmable NFTs keep their token state in TokenRecord and listed pNFTs can't vote
+    if nft_metadata.token_standard == Some(TokenStandard::ProgrammableNonFungible) {
+        let nft_token_record_info =
+            nft_token_record_info.ok_or(NftVoterError::MissingTokenRecord)?;
+
+        let nft_token_record =
+            get_token_record_for_nft(nft_token_record_info, &nft_mint, nft_info.key)?;
+
+        // Only the delegate roles which can't sell or transfer the pNFT carry its voting rights
+        if nft_owner != *governing_token_owner {
+            require!(
+                nft_token_record.delegate == Some(*governing_token_owner)
+                    && matches!(
+                        nft_token_record.delegate_role,
+                        Some(
+                            TokenDelegateRole::Standard
+                                | TokenDelegateRole::Utility
+                                | TokenDelegateRole::Staking
+                                | TokenDelegateRole::LockedTransfer
+                        )
+                    ),
+                NftVoterError::ProgrammableNftDelegateRoleNotAllowed
+            );
+        }
+
+        require!(
+            nft_token_record.state != TokenState::Listed,
+            NftVoterError::ProgrammableNftListed
+        );
+    }
+
     // The NFT must have a collection and the collection must be verified
     let collection = nft_metadata
         .collection
@@ -140,6 +201,48 @@ pub fn resolve_nft_vote_weight_and_mint(
     Ok((collection_config.weight, nft_mint))
 }
 
+/// Resolves vote weight and asset id for the given compressed NFT
+/// Leaf ownership and collection membership are verified with the Merkle proof against the tree
+pub fn resolve_compressed_nft_vote_weight_and_asset_id<'info>(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    merkle_tree_info: &AccountInfo<'info>,
+    compression_program_info: &AccountInfo<'info>,
+    proof_infos: &[AccountInfo<'info>],
+    compressed_nft: &CompressedNftArgs,
+) -> Result<(u64, Pubkey)> {
+    // The compressed NFT must have a collection and the collection must be verified
+    let collection = compressed_nft
+        .metadata
+        .collection
+        .as_ref()
+        .ok_or(NftVoterError::MissingMetadataCollection)?;
+
+    require!(collection.verified, NftVoterError::CollectionMustBeVerified);
+
+    let collection_config = registrar.get_collection_config(collection.key)?;
+
+    // Only Bubblegum can append leaves to its trees and verify the collection of the compressed NFTs
+    assert_bubblegum_merkle_tree(merkle_tree_info)?;
+
+    let asset_id = get_asset_id(merkle_tree_info.key, compressed_nft.nonce);
+
+    // The leaf is hashed with governing_token_owner as the leaf owner and hence
+    // the proof only verifies if governing_token_owner owns the compressed NFT
+    let leaf = hash_leaf(&asset_id, governing_token_owner, compressed_nft)?;
+
+    verify_leaf(
+        compression_program_info,
+        merkle_tree_info,
+        proof_infos,
+        compressed_nft.root,
+        leaf,
+        compressed_nft.index,
+    )?;
+
+    Ok((collection_config.weight, asset_id))
+}
+
 #[cfg(test)]
 mod test {
 
@@ -159,7 +262,9 @@ mod test {
                 CollectionConfig::default(),
                 CollectionConfig::default(),
             ],
-            reserved: [0; 128],
+            fixed_collection_sizes: false,
+            allow_token_delegates: false,
+            reserved: [0; 126],
         };
 
         // Act
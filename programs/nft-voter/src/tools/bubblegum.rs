@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, keccak, program::invoke};
+
+use crate::error::NftVoterError;
+use crate::state::{CompressedNftArgs, Creator, MetadataArgs};
+
+/// mpl-bubblegum program id
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+
+/// spl-account-compression program id
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// spl-account-compression account type of ConcurrentMerkleTree accounts
+const CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE: u8 = 1;
+
+/// Offset of the tree authority in spl-account-compression ConcurrentMerkleTree header
+/// account_type(1) + header_version(1) + max_buffer_size(4) + max_depth(4)
+const MERKLE_TREE_AUTHORITY_OFFSET: usize = 10;
+
+/// Anchor discriminator of spl-account-compression verify_leaf instruction
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+/// Bubblegum LeafSchema V1 version
+const LEAF_SCHEMA_V1_VERSION: u8 = 1;
+
+/// Returns the asset id of the compressed NFT with the given tree and nonce
+pub fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Returns Bubblegum TreeConfig address of the given Merkle tree
+pub fn get_tree_config_address(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_PROGRAM_ID).0
+}
+
+/// Asserts the Merkle tree is managed by Bubblegum, i.e. its authority is the Bubblegum TreeConfig of the tree
+/// Leaves of trees with any other authority can be appended by the authority and can't be trusted
+pub fn assert_bubblegum_merkle_tree(merkle_tree_info: &AccountInfo) -> Result<()> {
+    let data = merkle_tree_info.try_borrow_data()?;
+
+    require!(
+        data.len() >= MERKLE_TREE_AUTHORITY_OFFSET + 32
+            && data[0] == CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE,
+        NftVoterError::InvalidMerkleTreeAuthority
+    );
+
+    let authority = Pubkey::try_from_slice(
+        &data[MERKLE_TREE_AUTHORITY_OFFSET..MERKLE_TREE_AUTHORITY_OFFSET + 32],
+    )?;
+
+    require_keys_eq!(
+        authority,
+        get_tree_config_address(merkle_tree_info.key),
+        NftVoterError::InvalidMerkleTreeAuthority
+    );
+
+    Ok(())
+}
+
+/// Returns Bubblegum data_hash of the given compressed NFT metadata
+pub fn hash_metadata(metadata: &MetadataArgs) -> Result<[u8; 32]> {
+    let metadata_args_hash = keccak::hashv(&[metadata.try_to_vec()?.as_slice()]);
+
+    Ok(keccak::hashv(&[
+        &metadata_args_hash.to_bytes(),
+        &metadata.seller_fee_basis_points.to_le_bytes(),
+    ])
+    .to_bytes())
+}
+
+/// Returns Bubblegum creator_hash of the given creators
+pub fn hash_creators(creators: &[Creator]) -> [u8; 32] {
+    let creator_data = creators
+        .iter()
+        .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+        .collect::<Vec<_>>();
+
+    keccak::hashv(
+        creator_data
+            .iter()
+            .map(|c| c.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_ref(),
+    )
+    .to_bytes()
+}
+
+/// Returns Bubblegum LeafSchema V1 hash for the given compressed NFT owned by leaf_owner
+pub fn hash_leaf(
+    asset_id: &Pubkey,
+    leaf_owner: &Pubkey,
+    args: &CompressedNftArgs,
+) -> Result<[u8; 32]> {
+    let data_hash = hash_metadata(&args.metadata)?;
+    let creator_hash = hash_creators(&args.metadata.creators);
+
+    Ok(keccak::hashv(&[
+        &[LEAF_SCHEMA_V1_VERSION],
+        asset_id.as_ref(),
+        leaf_owner.as_ref(),
+        args.leaf_delegate.as_ref(),
+        &args.nonce.to_le_bytes(),
+        &data_hash,
+        &creator_hash,
+    ])
+    .to_bytes())
+}
+
+/// Verifies the leaf exists in the Merkle tree using spl-account-compression verify_leaf CPI
+/// The proof nodes are passed as accounts in proof_infos
+pub fn verify_leaf<'info>(
+    compression_program_info: &AccountInfo<'info>,
+    merkle_tree_info: &AccountInfo<'info>,
+    proof_infos: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let mut accounts = vec![AccountMeta::new_readonly(*merkle_tree_info.key, false)];
+    accounts.extend(
+        proof_infos
+            .iter()
+            .map(|info| AccountMeta::new_readonly(*info.key, false)),
+    );
+
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    };
+
+    let mut account_infos = vec![merkle_tree_info.clone()];
+    account_infos.extend_from_slice(proof_infos);
+    account_infos.push(compression_program_info.clone());
+
+    invoke(&instruction, &account_infos)?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 pub mod anchor;
+pub mod bubblegum;
 pub mod governance;
 pub mod spl_token;
 pub mod token_metadata;
@@ -1,6 +1,51 @@
 use anchor_lang::prelude::*;
 use arrayref::array_ref;
-use spl_governance::tools::spl_token::assert_is_valid_spl_token_account;
+use spl_token_2022::generic_token_account::GenericTokenAccount;
+use spl_token_2022::state::Account;
+
+use crate::error::NftVoterError;
+
+/// Asserts the given account_info represents a valid initialized token account
+/// which belongs to either spl-token or spl-token-2022 program
+pub fn assert_is_valid_spl_token_account(token_account_info: &AccountInfo) -> Result<()> {
+    if token_account_info.owner != &spl_token::id()
+        && token_account_info.owner != &spl_token_2022::id()
+    {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    // valid_account_data() checks the account is initialized and accepts
+    // both spl-token layout and spl-token-2022 layout with extensions
+    if !Account::valid_account_data(&token_account_info.try_borrow_data()?) {
+        return Err(NftVoterError::InvalidNftTokenAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Computationally cheap method to get mint from a token account
+/// It reads mint without deserializing full account data
+pub fn get_spl_token_mint(token_account_info: &AccountInfo) -> Result<Pubkey> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8), ...
+    let data = token_account_info.try_borrow_data()?;
+    let mint_data = array_ref![data, 0, 32];
+
+    Ok(Pubkey::new_from_array(*mint_data))
+}
+
+/// Computationally cheap method to get owner from a token account
+/// It reads owner without deserializing full account data
+pub fn get_spl_token_owner(token_account_info: &AccountInfo) -> Result<Pubkey> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8), ...
+    let data = token_account_info.try_borrow_data()?;
+    let owner_data = array_ref![data, 32, 32];
+
+    Ok(Pubkey::new_from_array(*owner_data))
+}
 
 /// Computationally cheap method to get amount from a token account
 /// It reads amount without deserializing full account data
@@ -13,3 +58,25 @@ pub fn get_spl_token_amount(token_account_info: &AccountInfo) -> Result<u64> {
 
     Ok(u64::from_le_bytes(*amount_bytes))
 }
+
+/// Computationally cheap method to get delegate and delegated amount from a token account
+/// It reads delegate without deserializing full account data
+pub fn get_spl_token_delegate(token_account_info: &AccountInfo) -> Result<Option<(Pubkey, u64)>> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8), delegate(4+32), state(1), is_native(4+8), delegated_amount(8), ...
+    let data = token_account_info.try_borrow_data()?;
+    let delegate_tag = array_ref![data, 72, 4];
+
+    if u32::from_le_bytes(*delegate_tag) == 0 {
+        return Ok(None);
+    }
+
+    let delegate_data = array_ref![data, 76, 32];
+    let delegated_amount_bytes = array_ref![data, 121, 8];
+
+    Ok(Some((
+        Pubkey::new_from_array(*delegate_data),
+        u64::from_le_bytes(*delegated_amount_bytes),
+    )))
+}
@@ -1,7 +1,8 @@
 use std::convert::TryFrom;
 
 use anchor_lang::prelude::*;
-use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::accounts::{Metadata, TokenRecord};
+use mpl_token_metadata::types::Key;
 
 use crate::error::NftVoterError;
 
@@ -14,7 +15,7 @@ pub fn get_token_metadata(account_info: &AccountInfo) -> Result<Metadata> {
 
     // I'm not sure if this is needed but try_from_slice_checked in from_account_info
     // ignores Key::Uninitialized and hence checking for the exact Key match here
-    if metadata.key != mpl_token_metadata::types::Key::MetadataV1 {
+    if metadata.key != Key::MetadataV1 {
         return Err(NftVoterError::InvalidTokenMetadataAccount.into());
     }
 
@@ -30,3 +31,39 @@ pub fn get_token_metadata_for_mint(account_info: &AccountInfo, mint: &Pubkey) ->
 
     Ok(token_metadata)
 }
+
+/// Returns true if the given account is a TokenRecord of a programmable NFT
+/// Used to detect the optional TokenRecord account supplied after the NFT metadata account
+pub fn is_token_record_account(account_info: &AccountInfo) -> bool {
+    *account_info.owner == mpl_token_metadata::ID
+        && account_info
+            .try_borrow_data()
+            .map(|data| data.first() == Some(&(Key::TokenRecord as u8)))
+            .unwrap_or(false)
+}
+
+pub fn get_token_record_for_nft(
+    account_info: &AccountInfo,
+    mint: &Pubkey,
+    token: &Pubkey,
+) -> Result<TokenRecord> {
+    if *account_info.owner != mpl_token_metadata::ID {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    let token_record = TokenRecord::try_from(account_info)?;
+
+    if token_record.key != Key::TokenRecord {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    // TokenRecord PDA is derived from the NFT mint and the token account holding the NFT
+    let token_record_address = TokenRecord::create_pda(*mint, *token, token_record.bump)
+        .map_err(|_| NftVoterError::InvalidTokenRecord)?;
+
+    if token_record_address != *account_info.key {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    Ok(token_record)
+}
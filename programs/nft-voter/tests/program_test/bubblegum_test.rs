@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::AnchorSerialize;
+use gpl_nft_voter::state::{Collection, CompressedNftArgs, MetadataArgs, TokenProgramVersion};
+use gpl_nft_voter::tools::bubblegum::{
+    get_asset_id, hash_leaf, BUBBLEGUM_PROGRAM_ID, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+};
+use solana_program::keccak;
+use solana_program_test::ProgramTest;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, system_instruction,
+    system_program, transport::TransportError,
+};
+
+use crate::program_test::program_test_bench::{ProgramTestBench, WalletCookie};
+use crate::program_test::token_metadata_test::{NftCollectionCookie, TokenMetadataTest};
+
+/// spl-noop program used by Bubblegum as the log wrapper
+const SPL_NOOP_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Merkle tree depth and buffer size used by the tests
+const MAX_DEPTH: usize = 3;
+const MAX_BUFFER_SIZE: usize = 8;
+
+/// spl-account-compression ConcurrentMerkleTree account size for MAX_DEPTH and MAX_BUFFER_SIZE
+/// header(56) + sequence_number, active_index, buffer_size(24) + change_logs + rightmost_proof
+const MERKLE_TREE_ACCOUNT_SIZE: usize =
+    56 + 24 + MAX_BUFFER_SIZE * (32 + 32 * MAX_DEPTH + 8) + (32 * MAX_DEPTH + 32 + 8);
+
+pub struct MerkleTreeCookie {
+    pub address: Pubkey,
+    pub tree_config: Pubkey,
+    /// Leaves minted into the tree, used to compute proofs
+    pub leaves: Vec<[u8; 32]>,
+}
+
+impl MerkleTreeCookie {
+    /// Returns the current root and the proof for the leaf at the given index
+    pub fn get_root_and_proof(&self, index: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+        let mut level = self.leaves.clone();
+        level.resize(1 << MAX_DEPTH, [0; 32]);
+
+        let mut proof = vec![];
+        let mut index = index;
+
+        while level.len() > 1 {
+            proof.push(level[index ^ 1]);
+            level = level
+                .chunks(2)
+                .map(|pair| keccak::hashv(&[&pair[0], &pair[1]]).to_bytes())
+                .collect();
+            index /= 2;
+        }
+
+        (level[0], proof)
+    }
+}
+
+pub struct CompressedNftCookie {
+    pub asset_id: Pubkey,
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub metadata: MetadataArgs,
+}
+
+impl CompressedNftCookie {
+    /// Returns CastCompressedNftVote args and the proof accounts for the current tree state
+    pub fn get_args_and_proof(
+        &self,
+        merkle_tree_cookie: &MerkleTreeCookie,
+    ) -> (CompressedNftArgs, Vec<AccountMeta>) {
+        let (root, proof) = merkle_tree_cookie.get_root_and_proof(self.nonce as usize);
+
+        let args = CompressedNftArgs {
+            root,
+            leaf_delegate: self.owner,
+            nonce: self.nonce,
+            index: self.nonce as u32,
+            metadata: self.metadata.clone(),
+        };
+
+        let proof = proof
+            .into_iter()
+            .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(node), false))
+            .collect();
+
+        (args, proof)
+    }
+}
+
+pub struct BubblegumTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl BubblegumTest {
+    /// Adds Bubblegum, spl-account-compression and spl-noop programs
+    /// The programs must be dumped into tests/fixtures
+    #[allow(dead_code)]
+    pub fn add_programs(program_test: &mut ProgramTest) {
+        program_test.add_program("mpl_bubblegum", BUBBLEGUM_PROGRAM_ID, None);
+        program_test.add_program(
+            "spl_account_compression",
+            SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            None,
+        );
+        program_test.add_program("spl_noop", SPL_NOOP_PROGRAM_ID, None);
+    }
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        BubblegumTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_merkle_tree(&self) -> Result<MerkleTreeCookie, TransportError> {
+        let payer = self.bench.payer.pubkey();
+        let merkle_tree = Keypair::new();
+
+        let (tree_config, _) =
+            Pubkey::find_program_address(&[merkle_tree.pubkey().as_ref()], &BUBBLEGUM_PROGRAM_ID);
+
+        let create_account_ix = system_instruction::create_account(
+            &payer,
+            &merkle_tree.pubkey(),
+            self.bench.rent.minimum_balance(MERKLE_TREE_ACCOUNT_SIZE),
+            MERKLE_TREE_ACCOUNT_SIZE as u64,
+            &SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        );
+
+        // Bubblegum create_tree(max_depth: u32, max_buffer_size: u32, public: Option<bool>)
+        let mut data = anchor_sighash("create_tree").to_vec();
+        data.extend_from_slice(&(MAX_DEPTH as u32).to_le_bytes());
+        data.extend_from_slice(&(MAX_BUFFER_SIZE as u32).to_le_bytes());
+        data.push(0);
+
+        let create_tree_ix = Instruction {
+            program_id: BUBBLEGUM_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(tree_config, false),
+                AccountMeta::new(merkle_tree.pubkey(), false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(payer, true),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[create_account_ix, create_tree_ix], Some(&[&merkle_tree]))
+            .await?;
+
+        Ok(MerkleTreeCookie {
+            address: merkle_tree.pubkey(),
+            tree_config,
+            leaves: vec![],
+        })
+    }
+
+    /// Creates Merkle tree directly with spl-account-compression and the payer as the tree authority
+    /// The tree isn't managed by Bubblegum and any leaf can be appended to it by the authority
+    #[allow(dead_code)]
+    pub async fn with_merkle_tree_without_bubblegum(
+        &self,
+    ) -> Result<MerkleTreeCookie, TransportError> {
+        let payer = self.bench.payer.pubkey();
+        let merkle_tree = Keypair::new();
+
+        let (tree_config, _) =
+            Pubkey::find_program_address(&[merkle_tree.pubkey().as_ref()], &BUBBLEGUM_PROGRAM_ID);
+
+        let create_account_ix = system_instruction::create_account(
+            &payer,
+            &merkle_tree.pubkey(),
+            self.bench.rent.minimum_balance(MERKLE_TREE_ACCOUNT_SIZE),
+            MERKLE_TREE_ACCOUNT_SIZE as u64,
+            &SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        );
+
+        // spl-account-compression init_empty_merkle_tree(max_depth: u32, max_buffer_size: u32)
+        let mut data = anchor_sighash("init_empty_merkle_tree").to_vec();
+        data.extend_from_slice(&(MAX_DEPTH as u32).to_le_bytes());
+        data.extend_from_slice(&(MAX_BUFFER_SIZE as u32).to_le_bytes());
+
+        let init_tree_ix = Instruction {
+            program_id: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(merkle_tree.pubkey(), false),
+                AccountMeta::new_readonly(payer, true),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[create_account_ix, init_tree_ix], Some(&[&merkle_tree]))
+            .await?;
+
+        Ok(MerkleTreeCookie {
+            address: merkle_tree.pubkey(),
+            tree_config,
+            leaves: vec![],
+        })
+    }
+
+    /// Appends compressed NFT leaf with verified collection directly to the Merkle tree
+    /// created by with_merkle_tree_without_bubblegum without any collection verification
+    #[allow(dead_code)]
+    pub async fn with_forged_compressed_nft(
+        &self,
+        merkle_tree_cookie: &mut MerkleTreeCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+    ) -> Result<CompressedNftCookie, TransportError> {
+        let payer = self.bench.payer.pubkey();
+
+        let metadata = MetadataArgs {
+            name: "TestCNFT".to_string(),
+            symbol: "CNFT".to_string(),
+            uri: "URI".to_string(),
+            seller_fee_basis_points: 10,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: Some(Collection {
+                verified: true,
+                key: nft_collection_cookie.mint,
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        let nonce = merkle_tree_cookie.leaves.len() as u64;
+        let asset_id = get_asset_id(&merkle_tree_cookie.address, nonce);
+
+        let args = CompressedNftArgs {
+            root: [0; 32],
+            leaf_delegate: nft_owner_cookie.address,
+            nonce,
+            index: nonce as u32,
+            metadata: metadata.clone(),
+        };
+
+        let leaf = hash_leaf(&asset_id, &nft_owner_cookie.address, &args).unwrap();
+
+        // spl-account-compression append(leaf: [u8; 32])
+        let mut data = anchor_sighash("append").to_vec();
+        data.extend_from_slice(&leaf);
+
+        let append_ix = Instruction {
+            program_id: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(merkle_tree_cookie.address, false),
+                AccountMeta::new_readonly(payer, true),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        self.bench.process_transaction(&[append_ix], None).await?;
+
+        merkle_tree_cookie.leaves.push(leaf);
+
+        Ok(CompressedNftCookie {
+            asset_id,
+            owner: nft_owner_cookie.address,
+            nonce,
+            metadata,
+        })
+    }
+
+    /// Mints compressed NFT into the given collection using Bubblegum mint_to_collection_v1
+    #[allow(dead_code)]
+    pub async fn with_compressed_nft(
+        &self,
+        merkle_tree_cookie: &mut MerkleTreeCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+    ) -> Result<CompressedNftCookie, TransportError> {
+        let payer = self.bench.payer.pubkey();
+
+        let mut metadata = MetadataArgs {
+            name: "TestCNFT".to_string(),
+            symbol: "CNFT".to_string(),
+            uri: "URI".to_string(),
+            seller_fee_basis_points: 10,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: Some(Collection {
+                verified: false,
+                key: nft_collection_cookie.mint,
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        let (bubblegum_signer, _) =
+            Pubkey::find_program_address(&[b"collection_cpi"], &BUBBLEGUM_PROGRAM_ID);
+
+        let mut data = anchor_sighash("mint_to_collection_v1").to_vec();
+        data.extend_from_slice(&metadata.try_to_vec().unwrap());
+
+        let mint_ix = Instruction {
+            program_id: BUBBLEGUM_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(merkle_tree_cookie.tree_config, false),
+                AccountMeta::new_readonly(nft_owner_cookie.address, false),
+                AccountMeta::new_readonly(nft_owner_cookie.address, false),
+                AccountMeta::new(merkle_tree_cookie.address, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(payer, true),
+                AccountMeta::new_readonly(payer, true),
+                // No collection_authority_record_pda
+                AccountMeta::new_readonly(BUBBLEGUM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(nft_collection_cookie.mint, false),
+                AccountMeta::new(nft_collection_cookie.metadata, false),
+                AccountMeta::new_readonly(nft_collection_cookie.master_edition, false),
+                AccountMeta::new_readonly(bubblegum_signer, false),
+                AccountMeta::new_readonly(SPL_NOOP_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TokenMetadataTest::program_id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        };
+
+        self.bench.process_transaction(&[mint_ix], None).await?;
+
+        // Bubblegum verifies the collection when minting to collection
+        metadata.collection = Some(Collection {
+            verified: true,
+            key: nft_collection_cookie.mint,
+        });
+
+        let nonce = merkle_tree_cookie.leaves.len() as u64;
+        let asset_id = get_asset_id(&merkle_tree_cookie.address, nonce);
+
+        let args = CompressedNftArgs {
+            root: [0; 32],
+            leaf_delegate: nft_owner_cookie.address,
+            nonce,
+            index: nonce as u32,
+            metadata: metadata.clone(),
+        };
+
+        merkle_tree_cookie
+            .leaves
+            .push(hash_leaf(&asset_id, &nft_owner_cookie.address, &args).unwrap());
+
+        Ok(CompressedNftCookie {
+            asset_id,
+            owner: nft_owner_cookie.address,
+            nonce,
+            metadata,
+        })
+    }
+}
+
+/// Returns Anchor instruction discriminator for the given instruction name
+fn anchor_sighash(name: &str) -> [u8; 8] {
+    let mut sighash = [0u8; 8];
+    sighash.copy_from_slice(
+        &solana_program::hash::hash(format!("global:{}", name).as_bytes()).to_bytes()[..8],
+    );
+    sighash
+}
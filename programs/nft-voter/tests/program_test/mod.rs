@@ -1,3 +1,4 @@
+pub mod bubblegum_test;
 pub mod governance_test;
 pub mod nft_voter_test;
 pub mod program_test_bench;
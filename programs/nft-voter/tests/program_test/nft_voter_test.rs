@@ -19,6 +19,7 @@ use solana_sdk::instruction::Instruction;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
+use crate::program_test::bubblegum_test::{BubblegumTest, CompressedNftCookie, MerkleTreeCookie};
 use crate::program_test::governance_test::GovernanceTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
 
@@ -84,6 +85,7 @@ pub struct NftVoterTest {
     pub bench: Arc<ProgramTestBench>,
     pub governance: GovernanceTest,
     pub token_metadata: TokenMetadataTest,
+    pub bubblegum: BubblegumTest,
 }
 
 impl NftVoterTest {
@@ -100,6 +102,23 @@ impl NftVoterTest {
         GovernanceTest::add_program(&mut program_test);
         TokenMetadataTest::add_program(&mut program_test);
 
+        Self::start_with_program_test(program_test).await
+    }
+
+    /// Starts new test with Bubblegum and spl-account-compression programs deployed
+    #[allow(dead_code)]
+    pub async fn start_new_with_bubblegum() -> Self {
+        let mut program_test = ProgramTest::default();
+
+        NftVoterTest::add_program(&mut program_test);
+        GovernanceTest::add_program(&mut program_test);
+        TokenMetadataTest::add_program(&mut program_test);
+        BubblegumTest::add_programs(&mut program_test);
+
+        Self::start_with_program_test(program_test).await
+    }
+
+    async fn start_with_program_test(program_test: ProgramTest) -> Self {
         let program_id = gpl_nft_voter::id();
 
         let bench = ProgramTestBench::start_new(program_test).await;
@@ -108,12 +127,14 @@ impl NftVoterTest {
         let governance_bench =
             GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
         let token_metadata_bench = TokenMetadataTest::new(bench_rc.clone());
+        let bubblegum_bench = BubblegumTest::new(bench_rc.clone());
 
         Self {
             program_id,
             bench: bench_rc,
             governance: governance_bench,
             token_metadata: token_metadata_bench,
+            bubblegum: bubblegum_bench,
         }
     }
 
@@ -176,7 +197,9 @@ impl NftVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             collection_configs: vec![],
-            reserved: [0; 128],
+            fixed_collection_sizes: false,
+            allow_token_delegates: false,
+            reserved: [0; 126],
         };
 
         Ok(RegistrarCookie {
@@ -343,6 +366,10 @@ impl NftVoterTest {
         for nft_cookie in nft_cookies {
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
         }
 
         let instructions = vec![Instruction {
@@ -363,6 +390,29 @@ impl NftVoterTest {
         voter_cookie: &WalletCookie,
         voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
         nft_vote_record_cookies: &Vec<NftVoteRecordCookie>,
+    ) -> Result<(), BanksClientError> {
+        self.relinquish_nft_vote_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            proposal_cookie,
+            voter_cookie,
+            voter_token_owner_record_cookie,
+            nft_vote_record_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn relinquish_nft_vote_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        nft_vote_record_cookies: &Vec<NftVoteRecordCookie>,
+        instruction_override: F,
     ) -> Result<(), BanksClientError> {
         let data =
             anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RelinquishNftVote {});
@@ -390,12 +440,14 @@ impl NftVoterTest {
             account_metas.push(AccountMeta::new(nft_vote_record_cookie.address, false));
         }
 
-        let relinquish_nft_vote_ix = Instruction {
+        let mut relinquish_nft_vote_ix = Instruction {
             program_id: gpl_nft_voter::id(),
             accounts: account_metas,
             data,
         };
 
+        instruction_override(&mut relinquish_nft_vote_ix);
+
         self.bench
             .process_transaction(&[relinquish_nft_vote_ix], Some(&[&voter_cookie.signer]))
             .await?;
@@ -403,6 +455,62 @@ impl NftVoterTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn close_nft_vote_records(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_vote_record_cookies: &[NftVoteRecordCookie],
+    ) -> Result<(), BanksClientError> {
+        self.close_nft_vote_records_using_ix(
+            registrar_cookie,
+            proposal_cookie,
+            nft_vote_record_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_nft_vote_records_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_vote_record_cookies: &[NftVoteRecordCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CloseNftVoteRecords {});
+
+        let accounts = gpl_nft_voter::accounts::CloseNftVoteRecords {
+            registrar: registrar_cookie.address,
+            governance: proposal_cookie.account.governance,
+            proposal: proposal_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for nft_vote_record_cookie in nft_vote_record_cookies {
+            account_metas.push(AccountMeta::new(nft_vote_record_cookie.address, false));
+            account_metas.push(AccountMeta::new(
+                nft_vote_record_cookie.account.payer,
+                false,
+            ));
+        }
+
+        let mut close_nft_vote_records_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut close_nft_vote_records_ix);
+
+        self.bench
+            .process_transaction(&[close_nft_vote_records_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_collection(
         &mut self,
@@ -473,6 +581,195 @@ impl NftVoterTest {
         Ok(CollectionConfigCookie { collection_config })
     }
 
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_collection_cookies: &[&NftCollectionCookie],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for nft_collection_cookie in nft_collection_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                nft_collection_cookie.metadata,
+                false,
+            ));
+        }
+
+        let instructions = vec![Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_fixed_collection_sizes(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        fixed_collection_sizes: bool,
+    ) -> Result<(), BanksClientError> {
+        self.set_fixed_collection_sizes_using_ix(
+            registrar_cookie,
+            fixed_collection_sizes,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_fixed_collection_sizes_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        fixed_collection_sizes: bool,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::SetFixedCollectionSizes {
+                fixed_collection_sizes,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::SetFixedCollectionSizes {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut set_fixed_collection_sizes_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_fixed_collection_sizes_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_fixed_collection_sizes_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_allow_token_delegates(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        allow_token_delegates: bool,
+    ) -> Result<(), BanksClientError> {
+        self.set_allow_token_delegates_using_ix(
+            registrar_cookie,
+            allow_token_delegates,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_allow_token_delegates_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        allow_token_delegates: bool,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::SetAllowTokenDelegates {
+                allow_token_delegates,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::SetAllowTokenDelegates {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut set_allow_token_delegates_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_allow_token_delegates_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_allow_token_delegates_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_collection_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RemoveCollection {});
+
+        let accounts = gpl_nft_voter::accounts::RemoveCollection {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: nft_collection_cookie.mint,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut remove_collection_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_collection_ix], Some(signers))
+            .await
+    }
+
     /// Casts NFT Vote and spl-gov Vote
     #[allow(dead_code)]
     pub async fn cast_nft_vote(
@@ -508,6 +805,10 @@ impl NftVoterTest {
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
 
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
             let nft_vote_record_key = get_nft_vote_record_address(
                 &proposal_cookie.address,
                 &nft_cookie.mint_cookie.address,
@@ -520,6 +821,7 @@ impl NftVoterTest {
                 governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
                 account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
                 reserved: [0; 8],
+                payer: self.bench.payer.pubkey(),
             };
 
             nft_vote_record_cookies.push(NftVoteRecordCookie {
@@ -568,6 +870,100 @@ impl NftVoterTest {
         Ok(nft_vote_record_cookies)
     }
 
+    /// Casts compressed NFT Vote and spl-gov Vote
+    #[allow(dead_code)]
+    pub async fn cast_compressed_nft_vote(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        merkle_tree_cookie: &MerkleTreeCookie,
+        compressed_nft_cookie: &CompressedNftCookie,
+        args: Option<CastNftVoteArgs>,
+    ) -> Result<NftVoteRecordCookie, BanksClientError> {
+        let args = args.unwrap_or_default();
+
+        let (compressed_nft, proof) = compressed_nft_cookie.get_args_and_proof(merkle_tree_cookie);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CastCompressedNftVote {
+                proposal: proposal_cookie.address,
+                compressed_nft,
+            },
+        );
+
+        let nft_vote_record_key =
+            get_nft_vote_record_address(&proposal_cookie.address, &compressed_nft_cookie.asset_id);
+
+        let accounts = gpl_nft_voter::accounts::CastCompressedNftVote {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            voter_token_owner_record: voter_token_owner_record_cookie.address,
+            voter_authority: nft_voter_cookie.address,
+            merkle_tree: merkle_tree_cookie.address,
+            nft_vote_record: nft_vote_record_key,
+            compression_program:
+                gpl_nft_voter::tools::bubblegum::SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        account_metas.extend(proof);
+
+        let cast_compressed_nft_vote_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        let mut instruction = vec![cast_compressed_nft_vote_ix];
+
+        if args.cast_spl_gov_vote {
+            // spl-gov cast vote
+            let vote = Vote::Approve(vec![VoteChoice {
+                rank: 0,
+                weight_percentage: 100,
+            }]);
+
+            let cast_vote_ix = cast_vote(
+                &self.governance.program_id,
+                &registrar_cookie.account.realm,
+                &proposal_cookie.account.governance,
+                &proposal_cookie.address,
+                &proposal_cookie.account.token_owner_record,
+                &voter_token_owner_record_cookie.address,
+                &nft_voter_cookie.address,
+                &proposal_cookie.account.governing_token_mint,
+                &self.bench.payer.pubkey(),
+                Some(voter_weight_record_cookie.address),
+                Some(max_voter_weight_record_cookie.address),
+                vote,
+            );
+
+            instruction.push(cast_vote_ix);
+        }
+
+        self.bench
+            .process_transaction(&instruction, Some(&[&nft_voter_cookie.signer]))
+            .await?;
+
+        Ok(NftVoteRecordCookie {
+            address: nft_vote_record_key,
+            account: NftVoteRecord {
+                proposal: proposal_cookie.address,
+                nft_mint: compressed_nft_cookie.asset_id,
+                governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
+                account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+                reserved: [0; 8],
+                payer: self.bench.payer.pubkey(),
+            },
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
         self.bench.get_anchor_account::<Registrar>(*registrar).await
@@ -1,9 +1,13 @@
 use std::{str::FromStr, sync::Arc};
 
 use anchor_lang::prelude::Pubkey;
-use mpl_token_metadata::{types::Collection, types::DataV2};
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata, TokenRecord},
+    instructions::{CreateV1Builder, DelegateBuilder, MintV1Builder, VerifyCollectionV1Builder},
+    types::{Collection, CollectionDetails, DataV2, DelegateArgs, PrintSupply, TokenStandard},
+};
 use solana_program_test::ProgramTest;
-use solana_sdk::{signer::Signer, system_program, transport::TransportError};
+use solana_sdk::{signature::Keypair, signer::Signer, system_program, transport::TransportError};
 
 use crate::program_test::program_test_bench::{MintCookie, ProgramTestBench, WalletCookie};
 
@@ -11,12 +15,16 @@ pub struct NftCookie {
     pub address: Pubkey,
     pub metadata: Pubkey,
     pub mint_cookie: MintCookie,
+    /// TokenRecord of the NFT holder, only set for programmable NFTs
+    pub token_record: Option<Pubkey>,
 }
 
 pub struct NftCollectionCookie {
     pub mint: Pubkey,
     pub metadata: Pubkey,
     pub master_edition: Pubkey,
+    /// Sized collections track their size in CollectionDetails
+    pub sized: bool,
 }
 
 pub struct CreateNftArgs {
@@ -58,6 +66,22 @@ impl TokenMetadataTest {
 
     #[allow(dead_code)]
     pub async fn with_nft_collection(&self) -> Result<NftCollectionCookie, TransportError> {
+        self.with_nft_collection_using_details(None).await
+    }
+
+    /// Creates sized collection which tracks the number of verified NFTs in CollectionDetails
+    #[allow(dead_code)]
+    pub async fn with_sized_nft_collection(&self) -> Result<NftCollectionCookie, TransportError> {
+        self.with_nft_collection_using_details(Some(CollectionDetails::V1 { size: 0 }))
+            .await
+    }
+
+    async fn with_nft_collection_using_details(
+        &self,
+        collection_details: Option<CollectionDetails>,
+    ) -> Result<NftCollectionCookie, TransportError> {
+        let sized = collection_details.is_some();
+
         let update_authority = self.bench.context.borrow().payer.pubkey();
         let payer = self.bench.context.borrow().payer.pubkey();
 
@@ -91,7 +115,7 @@ impl TokenMetadataTest {
                 uses: None,
             },
             is_mutable: true,
-            collection_details: None,
+            collection_details,
         };
 
         // instruction accounts
@@ -159,6 +183,7 @@ impl TokenMetadataTest {
             mint: coll_mint_cookie.address,
             metadata: coll_metadata_key,
             master_edition: master_edition_key,
+            sized,
         })
     }
 
@@ -231,7 +256,23 @@ impl TokenMetadataTest {
             .process_transaction(&[create_metadata_ix], Some(&[&mint_cookie.mint_authority]))
             .await?;
 
-        if verify_collection {
+        if verify_collection && nft_collection_cookie.sized {
+            let verify_collection_accounts =
+                mpl_token_metadata::instructions::VerifySizedCollectionItem {
+                    metadata: metadata_key,
+                    collection_authority: self.bench.payer.pubkey(),
+                    payer: self.bench.payer.pubkey(),
+                    collection_mint: nft_collection_cookie.mint,
+                    collection: nft_collection_cookie.metadata,
+                    collection_master_edition_account: nft_collection_cookie.master_edition,
+                    collection_authority_record: None,
+                };
+            let verify_collection = verify_collection_accounts.instruction();
+
+            self.bench
+                .process_transaction(&[verify_collection], None)
+                .await?;
+        } else if verify_collection {
             let verify_collection_accounts = mpl_token_metadata::instructions::VerifyCollection {
                 metadata: metadata_key,
                 collection_authority: self.bench.payer.pubkey(),
@@ -252,6 +293,188 @@ impl TokenMetadataTest {
             address: nft_account_cookie.address,
             metadata: metadata_key,
             mint_cookie,
+            token_record: None,
+        })
+    }
+
+    /// Creates programmable NFT (pNFT) with verified collection
+    #[allow(dead_code)]
+    pub async fn with_programmable_nft(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+    ) -> Result<NftCookie, TransportError> {
+        self.with_nft_v1(
+            nft_collection_cookie,
+            nft_owner_cookie,
+            TokenStandard::ProgrammableNonFungible,
+            &spl_token::id(),
+        )
+        .await
+    }
+
+    /// Creates NFT held in spl-token-2022 token account with verified collection
+    #[allow(dead_code)]
+    pub async fn with_token_2022_nft(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+    ) -> Result<NftCookie, TransportError> {
+        self.with_nft_v1(
+            nft_collection_cookie,
+            nft_owner_cookie,
+            TokenStandard::NonFungible,
+            &spl_token_2022::id(),
+        )
+        .await
+    }
+
+    /// Approves the given delegate for the NFT token account using spl-token approve
+    #[allow(dead_code)]
+    pub async fn with_nft_delegate(
+        &self,
+        nft_cookie: &NftCookie,
+        nft_owner_cookie: &WalletCookie,
+        delegate: &Pubkey,
+    ) -> Result<(), TransportError> {
+        let approve_ix = spl_token::instruction::approve(
+            &spl_token::id(),
+            &nft_cookie.address,
+            delegate,
+            &nft_owner_cookie.address,
+            &[],
+            1,
+        )
+        .unwrap();
+
+        self.bench
+            .process_transaction(&[approve_ix], Some(&[&nft_owner_cookie.signer]))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Approves the given delegate for the programmable NFT using token-metadata Delegate instruction
+    /// The delegate role is defined by the provided DelegateArgs
+    #[allow(dead_code)]
+    pub async fn with_programmable_nft_delegate(
+        &self,
+        nft_cookie: &NftCookie,
+        nft_owner_cookie: &WalletCookie,
+        delegate: &Pubkey,
+        delegate_args: DelegateArgs,
+    ) -> Result<(), TransportError> {
+        let mint = nft_cookie.mint_cookie.address;
+        let (master_edition_key, _) = MasterEdition::find_pda(&mint);
+
+        let delegate_ix = DelegateBuilder::new()
+            .delegate(*delegate)
+            .metadata(nft_cookie.metadata)
+            .master_edition(Some(master_edition_key))
+            .token_record(nft_cookie.token_record)
+            .mint(mint)
+            .token(Some(nft_cookie.address))
+            .authority(nft_owner_cookie.address)
+            .payer(self.bench.payer.pubkey())
+            .spl_token_program(Some(spl_token::id()))
+            .delegate_args(delegate_args)
+            .instruction();
+
+        self.bench
+            .process_transaction(&[delegate_ix], Some(&[&nft_owner_cookie.signer]))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Creates NFT using the token-metadata Create/Mint/Verify V1 instructions
+    /// which support programmable NFTs and spl-token-2022 mints
+    async fn with_nft_v1(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+        token_standard: TokenStandard,
+        spl_token_program_id: &Pubkey,
+    ) -> Result<NftCookie, TransportError> {
+        let payer = self.bench.payer.pubkey();
+        let mint = Keypair::new();
+
+        let (metadata_key, _) = Metadata::find_pda(&mint.pubkey());
+        let (master_edition_key, _) = MasterEdition::find_pda(&mint.pubkey());
+
+        let create_ix = CreateV1Builder::new()
+            .metadata(metadata_key)
+            .master_edition(Some(master_edition_key))
+            .mint(mint.pubkey(), true)
+            .authority(payer)
+            .payer(payer)
+            .update_authority(payer, true)
+            .spl_token_program(Some(*spl_token_program_id))
+            .name("TestNFT".to_string())
+            .symbol("NFT".to_string())
+            .uri("URI".to_string())
+            .seller_fee_basis_points(10)
+            .token_standard(token_standard)
+            .collection(Collection {
+                verified: false,
+                key: nft_collection_cookie.mint,
+            })
+            .print_supply(PrintSupply::Zero)
+            .instruction();
+
+        self.bench
+            .process_transaction(&[create_ix], Some(&[&mint]))
+            .await?;
+
+        let (token_key, _) = Pubkey::find_program_address(
+            &[
+                nft_owner_cookie.address.as_ref(),
+                spl_token_program_id.as_ref(),
+                mint.pubkey().as_ref(),
+            ],
+            &Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap(),
+        );
+
+        let token_record = if token_standard == TokenStandard::ProgrammableNonFungible {
+            Some(TokenRecord::find_pda(&mint.pubkey(), &token_key).0)
+        } else {
+            None
+        };
+
+        let mint_ix = MintV1Builder::new()
+            .token(token_key)
+            .token_owner(Some(nft_owner_cookie.address))
+            .metadata(metadata_key)
+            .master_edition(Some(master_edition_key))
+            .token_record(token_record)
+            .mint(mint.pubkey())
+            .authority(payer)
+            .payer(payer)
+            .spl_token_program(*spl_token_program_id)
+            .amount(1)
+            .instruction();
+
+        let verify_collection_ix = VerifyCollectionV1Builder::new()
+            .authority(payer)
+            .metadata(metadata_key)
+            .collection_mint(nft_collection_cookie.mint)
+            .collection_metadata(Some(nft_collection_cookie.metadata))
+            .collection_master_edition(Some(nft_collection_cookie.master_edition))
+            .instruction();
+
+        self.bench
+            .process_transaction(&[mint_ix, verify_collection_ix], None)
+            .await?;
+
+        Ok(NftCookie {
+            address: token_key,
+            metadata: metadata_key,
+            mint_cookie: MintCookie {
+                address: mint.pubkey(),
+                mint_authority: Keypair::from_bytes(&self.bench.payer.to_bytes()).unwrap(),
+                freeze_authority: None,
+            },
+            token_record,
         })
     }
 }
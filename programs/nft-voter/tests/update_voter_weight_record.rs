@@ -573,3 +573,75 @@ async fn test_update_voter_weight_record_with_no_nft_error() -> Result<(), Trans
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_programmable_and_token_2022_nfts(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let _collection_config_cookie = nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let mut voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(&nft_collection_cookie, &voter_cookie)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_token_2022_nft(&nft_collection_cookie, &voter_cookie)
+        .await?;
+
+    let nft3_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie, &nft3_cookie],
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 30);
+
+    Ok(())
+}
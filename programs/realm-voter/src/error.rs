@@ -22,4 +22,43 @@ pub enum RealmVoterError {
 
     #[msg("Governing TokenOwner must match")]
     GoverningTokenOwnerMustMatch,
+
+    #[msg("Realm not configured")]
+    RealmNotConfigured,
+
+    #[msg("Cannot configure the Realm of the Registrar")]
+    OwnRealmNotAllowed,
+
+    #[msg("Duplicated Realm membership")]
+    DuplicatedRealmMembership,
+
+    #[msg("Voter weight overflow")]
+    VoterWeightOverflow,
+
+    #[msg("Invalid Realm weight mode")]
+    InvalidRealmWeightMode,
+
+    #[msg("Source VoterWeightRecord missing")]
+    SourceVoterWeightRecordMissing,
+
+    #[msg("Source VoterWeightRecord expired")]
+    SourceVoterWeightRecordExpired,
+
+    #[msg("Source VoterWeightRecord must not be restricted to an action")]
+    SourceVoterWeightRecordActionNotAllowed,
+
+    #[msg("Previous VoterWeightRecord missing")]
+    PreviousVoterWeightRecordMissing,
+
+    #[msg("Previous VoterWeightRecord expired")]
+    PreviousVoterWeightRecordExpired,
+
+    #[msg("Previous MaxVoterWeightRecord missing")]
+    PreviousMaxVoterWeightRecordMissing,
+
+    #[msg("Previous MaxVoterWeightRecord expired")]
+    PreviousMaxVoterWeightRecordExpired,
+
+    #[msg("Previous VoterWeightRecord provided but no previous voter weight plugin is configured")]
+    PreviousVoterWeightRecordNotAllowed,
 }
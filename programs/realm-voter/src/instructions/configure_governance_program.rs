@@ -56,11 +56,6 @@ pub fn configure_governance_program(
 
     let governance_program_id = &ctx.accounts.governance_program_id;
 
-    let governance_program_config = GovernanceProgramConfig {
-        program_id: governance_program_id.key(),
-        reserved: [0; 8],
-    };
-
     let governance_program_config_idx = registrar
         .governance_program_configs
         .iter()
@@ -68,12 +63,18 @@ pub fn configure_governance_program(
 
     match (change_type, governance_program_config_idx) {
         // Update
-        (CollectionItemChangeType::Upsert, Some(config_idx)) => {
+        (CollectionItemChangeType::Upsert, Some(_config_idx)) => {
             // Note: Update in this version is nop because we only store governance_program_id
-            registrar.governance_program_configs[config_idx] = governance_program_config;
+            // and the configured Realms are managed using ConfigureRealm
         }
         // Insert
         (CollectionItemChangeType::Upsert, None) => {
+            let governance_program_config = GovernanceProgramConfig {
+                program_id: governance_program_id.key(),
+                realm_configs: vec![],
+                reserved: [0; 4],
+            };
+
             // Note: In the current version push() would throw an error if we exceed
             // max_governance_programs specified when the Registrar was created
             registrar
@@ -0,0 +1,70 @@
+use crate::error::RealmVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+/// Configures the previous voter weight plugin the Registrar is chained with
+/// and how the Realm membership voter weight is combined with the voter weight of the previous plugin
+#[derive(Accounts)]
+pub struct ConfigurePreviousVoterWeightPlugin<'info> {
+    /// The Registrar for the given realm and governing_token_mint
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ RealmVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ RealmVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn configure_previous_voter_weight_plugin(
+    ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+    previous_voter_weight_plugin_program_id: Option<Pubkey>,
+    chaining_mode: ChainingMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require_eq!(
+        realm.authority.unwrap(),
+        ctx.accounts.realm_authority.key(),
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    registrar.previous_voter_weight_plugin_program_id = previous_voter_weight_plugin_program_id;
+    registrar.chaining_mode = chaining_mode;
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    if previous_voter_weight_plugin_program_id.is_some() {
+        // The max voter weight must be combined with the max voter weight of the previous plugin using UpdateMaxVoterWeightRecord
+        max_voter_weight_record.max_voter_weight_expiry = Some(0);
+    } else {
+        max_voter_weight_record.max_voter_weight = registrar.max_voter_weight;
+        max_voter_weight_record.max_voter_weight_expiry = None;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use spl_governance::state::realm;
+
+use crate::error::RealmVoterError;
+use crate::state::{CollectionItemChangeType, RealmConfig, RealmWeightMode, Registrar};
+use crate::tools::anchor::DISCRIMINATOR_SIZE;
+
+/// Creates, updates or removes configuration of a Realm whose members are granted governance power
+/// The Realm must belong to one of the spl-governance instances configured using ConfigureGovernanceProgram
+/// and weight_mode defines how the voter weight of the Realm members is evaluated
+///
+/// Realm configurations are not budgeted by Registrar::get_space() and the Registrar is resized when needed, funded by the payer
+/// The resize preserves the space reserved for spl-governance instances which are not configured yet
+#[derive(Accounts)]
+#[instruction(change_type: CollectionItemChangeType)]
+pub struct ConfigureRealm<'info> {
+    /// Registrar which we configure the provided Realm for
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ RealmVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// spl-governance instance the configured Realm belongs to
+    /// CHECK: It must be one of the instances configured in registrar.governance_program_configs
+    pub governance_program_id: UncheckedAccount<'info>,
+
+    /// Realm whose members are granted governance power
+    /// CHECK: Owned by governance_program_id and validated in the instruction
+    #[account(owner = governance_program_id.key())]
+    pub member_realm: UncheckedAccount<'info>,
+
+    /// Pays for the Registrar resize in the event the configuration doesn't fit
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_realm(
+    ctx: Context<ConfigureRealm>,
+    change_type: CollectionItemChangeType,
+    governing_token_mint: Option<Pubkey>,
+    voter_weight: u64,
+    weight_mode: RealmWeightMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require_eq!(
+        realm.authority.unwrap(),
+        ctx.accounts.realm_authority.key(),
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    let governance_program_id = ctx.accounts.governance_program_id.key();
+    let member_realm = &ctx.accounts.member_realm;
+
+    // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
+    require_neq!(
+        member_realm.key(),
+        registrar.realm,
+        RealmVoterError::OwnRealmNotAllowed
+    );
+
+    // Ensure the account is a Realm and the mint, if provided, is one of the Realm mints
+    if let Some(governing_token_mint) = governing_token_mint {
+        realm::get_realm_data_for_governing_token_mint(
+            &governance_program_id,
+            member_realm,
+            &governing_token_mint,
+        )?;
+    } else {
+        realm::get_realm_data(&governance_program_id, member_realm)?;
+    }
+
+    match weight_mode {
+        RealmWeightMode::ProportionalDeposit { scaling_factor }
+        | RealmWeightMode::PluginVoterWeight { scaling_factor, .. } => {
+            require_gt!(scaling_factor, 0, RealmVoterError::InvalidRealmWeightMode);
+        }
+        RealmWeightMode::Membership | RealmWeightMode::DepositThreshold { .. } => {}
+    }
+
+    let governance_program_config_idx = registrar
+        .governance_program_configs
+        .iter()
+        .position(|cc| cc.program_id == governance_program_id)
+        .ok_or(RealmVoterError::GovernanceProgramNotConfigured)?;
+
+    // The space not used by the current configuration is reserved for spl-governance instances
+    // within max_governance_programs and must be kept when the Registrar is resized
+    let unused_space = registrar
+        .to_account_info()
+        .data_len()
+        .saturating_sub(DISCRIMINATOR_SIZE + registrar.try_to_vec()?.len());
+
+    let realm_configs =
+        &mut registrar.governance_program_configs[governance_program_config_idx].realm_configs;
+
+    let realm_config_idx = realm_configs.iter().position(|rc| {
+        rc.realm == member_realm.key() && rc.governing_token_mint == governing_token_mint
+    });
+
+    match (change_type, realm_config_idx) {
+        // Update
+        (CollectionItemChangeType::Upsert, Some(config_idx)) => {
+            realm_configs[config_idx].voter_weight = voter_weight;
+            realm_configs[config_idx].weight_mode = weight_mode;
+        }
+        // Insert
+        (CollectionItemChangeType::Upsert, None) => {
+            realm_configs.push(RealmConfig {
+                realm: member_realm.key(),
+                governing_token_mint,
+                voter_weight,
+                weight_mode,
+                reserved: [0; 8],
+            });
+        }
+        (CollectionItemChangeType::Remove, Some(config_idx)) => {
+            realm_configs.remove(config_idx);
+        }
+        (CollectionItemChangeType::Remove, None) => {
+            return err!(RealmVoterError::RealmNotConfigured)
+        }
+    }
+
+    // Resize the Registrar if the Realm configs no longer fit into the account together with the reserved space
+    let new_size = DISCRIMINATOR_SIZE + registrar.try_to_vec()?.len() + unused_space;
+    let registrar_info = registrar.to_account_info();
+
+    if new_size > registrar_info.data_len() {
+        let lamports_diff = Rent::get()?
+            .minimum_balance(new_size)
+            .saturating_sub(registrar_info.lamports());
+
+        if lamports_diff > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: registrar_info.clone(),
+            };
+
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, lamports_diff)?;
+        }
+
+        registrar_info.realloc(new_size, false)?;
+    }
+
+    Ok(())
+}
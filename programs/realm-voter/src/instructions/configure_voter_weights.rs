@@ -7,6 +7,7 @@ use spl_governance::state::realm;
 /// Configures realm_member_voter_weight and max_voter_weight for Registrar
 /// It also sets MaxVoterWeightRecord.max_voter_weight to the provided value
 /// MaxVoterWeightRecord.max_voter_weight is static and can only be set using this instruction and hence it never expires
+/// unless a previous voter weight plugin is configured and the max voter weight must be updated using UpdateMaxVoterWeightRecord
 #[derive(Accounts)]
 pub struct ConfigureVoterWeights<'info> {
     /// The Registrar for the given realm and governing_token_mint
@@ -61,8 +62,14 @@ pub fn configure_voter_weights(
     let voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
     voter_weight_record.max_voter_weight = max_voter_weight;
 
-    // max_voter_weight can only be updated using this instruction and it never expires
-    voter_weight_record.max_voter_weight_expiry = None;
+    voter_weight_record.max_voter_weight_expiry =
+        if registrar.previous_voter_weight_plugin_program_id.is_some() {
+            // max_voter_weight must be combined with the max voter weight of the previous plugin using UpdateMaxVoterWeightRecord
+            Some(0)
+        } else {
+            // max_voter_weight can only be updated using this instruction and it never expires
+            None
+        };
 
     Ok(())
 }
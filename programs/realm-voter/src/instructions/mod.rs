@@ -15,3 +15,12 @@ mod update_voter_weight_record;
 
 pub use configure_voter_weights::*;
 mod configure_voter_weights;
+
+pub use configure_realm::*;
+mod configure_realm;
+
+pub use configure_previous_voter_weight_plugin::*;
+mod configure_previous_voter_weight_plugin;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
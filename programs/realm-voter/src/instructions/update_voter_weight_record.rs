@@ -1,10 +1,17 @@
 use crate::error::RealmVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use gpl_shared::compose::{combine_voter_weight, resolve_input_voter_weight};
+use gpl_shared::generic_voter_weight::GenericVoterWeight;
+use spl_governance::addins::voter_weight;
 use spl_governance::state::token_owner_record;
 
 /// Updates VoterWeightRecord based on Realm DAO membership
-/// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the configured spl-governance instances
+/// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the Realms configured for the spl-governance instances
+/// and the voter weight for the Realm is evaluated using the configured RealmWeightMode
+/// If a previous voter weight plugin is configured then the Realm membership voter weight is combined with
+/// the voter weight of the previous plugin using the configured ChainingMode
+/// TokenOwnerRecords of additional Realms can be passed as remaining_accounts and the voter weights of the Realms are summed up
 ///
 /// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
 /// and must be executed inside the same transaction as the corresponding spl-gov instruction
@@ -26,52 +33,178 @@ pub struct UpdateVoterWeightRecord<'info> {
     /// TokenOwnerRecord for any of the configured spl-governance instances
     /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
     pub token_owner_record: UncheckedAccount<'info>,
+
+    /// VoterWeightRecord of the previous voter weight plugin
+    /// It's required when registrar.previous_voter_weight_plugin_program_id is set and must not be provided otherwise
+    /// CHECK: Owned by registrar.previous_voter_weight_plugin_program_id and checked in the code
+    pub previous_voter_weight_record: Option<UncheckedAccount<'info>>,
 }
 
-pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+pub fn update_voter_weight_record<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVoterWeightRecord<'info>>,
+) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = ctx.accounts.voter_weight_record.governing_token_owner;
+
+    let mut voter_weight = 0u64;
+    let mut unique_realm_configs = vec![];
+
+    // Membership of several Realms can be provided as additional TokenOwnerRecords in remaining_accounts
+    // Each TokenOwnerRecord for a Realm using PluginVoterWeight mode must be followed by the VoterWeightRecord of the Realm plugin
+    let mut accounts = std::iter::once(ctx.accounts.token_owner_record.as_ref())
+        .chain(ctx.remaining_accounts.iter());
+
+    while let Some(token_owner_record_info) = accounts.next() {
+        let governance_program_id = token_owner_record_info.owner;
+
+        let governance_program_config =
+            registrar.get_governance_program_config(governance_program_id)?;
+
+        let token_owner_record = token_owner_record::get_token_owner_record_data(
+            governance_program_id,
+            token_owner_record_info,
+        )?;
+
+        // Ensure VoterWeightRecord and TokenOwnerRecord are for the same governing_token_owner
+        require_eq!(
+            token_owner_record.governing_token_owner,
+            governing_token_owner,
+            RealmVoterError::GoverningTokenOwnerMustMatch
+        );
+
+        // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
+        require_neq!(
+            token_owner_record.realm,
+            registrar.realm,
+            RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
+        );
+
+        let realm_config = governance_program_config
+            .get_realm_config(
+                &token_owner_record.realm,
+                &token_owner_record.governing_token_mint,
+            )
+            .ok_or(RealmVoterError::RealmNotConfigured)?;
+
+        // Each configured Realm membership can only be counted once
+        if unique_realm_configs.contains(&realm_config) {
+            return Err(RealmVoterError::DuplicatedRealmMembership.into());
+        }
+        unique_realm_configs.push(realm_config);
+
+        let realm_voter_weight = match realm_config.weight_mode {
+            RealmWeightMode::Membership => realm_config.voter_weight,
+            RealmWeightMode::DepositThreshold { min_deposit_amount } => {
+                if token_owner_record.governing_token_deposit_amount >= min_deposit_amount {
+                    realm_config.voter_weight
+                } else {
+                    0
+                }
+            }
+            RealmWeightMode::ProportionalDeposit { scaling_factor } => scale_amount(
+                token_owner_record.governing_token_deposit_amount,
+                scaling_factor,
+            )?,
+            RealmWeightMode::PluginVoterWeight {
+                voter_weight_plugin_program_id,
+                scaling_factor,
+            } => {
+                let source_voter_weight_record_info = accounts
+                    .next()
+                    .ok_or(RealmVoterError::SourceVoterWeightRecordMissing)?;
+
+                let source_voter_weight_record =
+                    voter_weight::get_voter_weight_record_data_for_token_owner_record(
+                        &voter_weight_plugin_program_id,
+                        source_voter_weight_record_info,
+                        &token_owner_record,
+                    )?;
+
+                // The source weight must be up to date and valid for any action in the source Realm
+                if let Some(voter_weight_expiry) = source_voter_weight_record.voter_weight_expiry {
+                    require_gte!(
+                        voter_weight_expiry,
+                        Clock::get()?.slot,
+                        RealmVoterError::SourceVoterWeightRecordExpired
+                    );
+                }
+
+                require!(
+                    source_voter_weight_record.weight_action.is_none()
+                        && source_voter_weight_record.weight_action_target.is_none(),
+                    RealmVoterError::SourceVoterWeightRecordActionNotAllowed
+                );
+
+                scale_amount(source_voter_weight_record.voter_weight, scaling_factor)?
+            }
+        };
+
+        voter_weight = voter_weight
+            .checked_add(realm_voter_weight)
+            .ok_or(RealmVoterError::VoterWeightOverflow)?;
+    }
+
+    let current_slot = Clock::get()?.slot;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    let governance_program_id = ctx.accounts.token_owner_record.owner;
-
-    // Note: We only verify a valid TokenOwnerRecord account exists for one of the configured spl-governance instances
-    // The existence of the account proofs the governing_token_owner has interacted with spl-governance Realm at least once in the past
-    if !registrar
-        .governance_program_configs
-        .iter()
-        .any(|cc| cc.program_id == governance_program_id.key())
-    {
-        return err!(RealmVoterError::GovernanceProgramNotConfigured);
-    };
-
-    let token_owner_record = token_owner_record::get_token_owner_record_data(
-        governance_program_id,
-        &ctx.accounts.token_owner_record,
-    )?;
-
-    // Ensure VoterWeightRecord and TokenOwnerRecord are for the same governing_token_owner
-    require_eq!(
-        token_owner_record.governing_token_owner,
-        voter_weight_record.governing_token_owner,
-        RealmVoterError::GoverningTokenOwnerMustMatch
-    );
-
-    // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
-    require_neq!(
-        token_owner_record.realm,
-        registrar.realm,
-        RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
-    );
-
-    // Setup voter_weight
-    voter_weight_record.voter_weight = registrar.realm_member_voter_weight;
+    match registrar.previous_voter_weight_plugin_program_id {
+        None => {
+            // The previous VoterWeightRecord takes the first positional slot and a TokenOwnerRecord
+            // passed there by mistake would be silently ignored
+            require!(
+                ctx.accounts.previous_voter_weight_record.is_none(),
+                RealmVoterError::PreviousVoterWeightRecordNotAllowed
+            );
 
-    // Record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+            voter_weight_record.voter_weight = voter_weight;
 
-    // Set action and target to None to indicate the weight is valid for any action and target
-    voter_weight_record.weight_action = None;
-    voter_weight_record.weight_action_target = None;
+            // Set action and target to None to indicate the weight is valid for any action and target
+            voter_weight_record.weight_action = None;
+            voter_weight_record.weight_action_target = None;
+        }
+        Some(_) => {
+            let previous_voter_weight_record_info = ctx
+                .accounts
+                .previous_voter_weight_record
+                .as_ref()
+                .ok_or(RealmVoterError::PreviousVoterWeightRecordMissing)?
+                .to_account_info();
+
+            let clone_record = voter_weight_record.clone();
+            let previous_voter_weight_record = resolve_input_voter_weight(
+                &previous_voter_weight_record_info,
+                &clone_record,
+                registrar,
+            )?;
+
+            if let Some(previous_voter_weight_expiry) =
+                previous_voter_weight_record.get_voter_weight_expiry()
+            {
+                require_gte!(
+                    previous_voter_weight_expiry,
+                    current_slot,
+                    RealmVoterError::PreviousVoterWeightRecordExpired
+                );
+            }
+
+            voter_weight_record.voter_weight = combine_voter_weight(
+                registrar.chaining_mode,
+                previous_voter_weight_record.get_voter_weight(),
+                voter_weight,
+            )
+            .ok_or(RealmVoterError::VoterWeightOverflow)?;
+
+            // The action and target of the previous plugin are preserved
+            voter_weight_record.weight_action = previous_voter_weight_record
+                .get_weight_action()
+                .map(|action| action.into());
+            voter_weight_record.weight_action_target =
+                previous_voter_weight_record.get_weight_action_target();
+        }
+    }
+
+    // Record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(current_slot);
 
     Ok(())
 }
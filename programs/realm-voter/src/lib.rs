@@ -8,7 +8,7 @@ use instructions::*;
 pub mod state;
 
 pub mod tools;
-use crate::state::CollectionItemChangeType;
+use crate::state::{ChainingMode, CollectionItemChangeType, RealmWeightMode};
 
 declare_id!("GRmVtfLq2BPeWs5EDoQoZc787VYkhdkA11k63QM1Xemz");
 
@@ -35,7 +35,9 @@ pub mod realm_voter {
         log_version();
         instructions::create_max_voter_weight_record(ctx)
     }
-    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    pub fn update_voter_weight_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVoterWeightRecord<'info>>,
+    ) -> Result<()> {
         log_version();
         instructions::update_voter_weight_record(ctx)
     }
@@ -55,6 +57,41 @@ pub mod realm_voter {
         log_version();
         instructions::configure_governance_program(ctx, change_type)
     }
+
+    pub fn configure_realm(
+        ctx: Context<ConfigureRealm>,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        voter_weight: u64,
+        weight_mode: RealmWeightMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_realm(
+            ctx,
+            change_type,
+            governing_token_mint,
+            voter_weight,
+            weight_mode,
+        )
+    }
+
+    pub fn configure_previous_voter_weight_plugin(
+        ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_previous_voter_weight_plugin(
+            ctx,
+            previous_voter_weight_plugin_program_id,
+            chaining_mode,
+        )
+    }
+
+    pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
 }
 
 fn log_version() {
@@ -1,11 +1,117 @@
 use anchor_lang::prelude::*;
 
+use crate::error::RealmVoterError;
+
 /// Configuration of an spl-governance instance used to grant governance power
-#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Default)]
 pub struct GovernanceProgramConfig {
     /// The program id of the configured spl-governance instance
     pub program_id: Pubkey,
 
+    /// Realms of the spl-governance instance whose members are granted governance power
+    /// Only members of the explicitly configured Realms are eligible
+    pub realm_configs: Vec<RealmConfig>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 4],
+}
+
+impl GovernanceProgramConfig {
+    /// Returns the RealmConfig matching the given realm and governing_token_mint
+    /// A RealmConfig without governing_token_mint matches any mint of the Realm
+    pub fn get_realm_config(
+        &self,
+        realm: &Pubkey,
+        governing_token_mint: &Pubkey,
+    ) -> Option<&RealmConfig> {
+        self.realm_configs.iter().find(|rc| {
+            rc.realm == *realm
+                && rc
+                    .governing_token_mint
+                    .is_none_or(|mint| mint == *governing_token_mint)
+        })
+    }
+}
+
+/// Configuration of a Realm whose members are granted governance power
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Default)]
+pub struct RealmConfig {
+    /// The Realm whose members are granted governance power
+    pub realm: Pubkey,
+
+    /// Governing token mint (community or council) membership is evaluated for
+    /// If not set then membership for any of the Realm mints is accepted
+    pub governing_token_mint: Option<Pubkey>,
+
+    /// Vote weight assigned to a member of the Realm
+    /// It's used by Membership and DepositThreshold weight modes
+    pub voter_weight: u64,
+
+    /// Defines how the voter weight of the Realm member is evaluated
+    pub weight_mode: RealmWeightMode,
+
     /// Reserved for future upgrades
     pub reserved: [u8; 8],
 }
+
+/// Precision of RealmWeightMode scaling_factor, the factor of SCALING_FACTOR_BASE yields the scaled amount unchanged
+pub const SCALING_FACTOR_BASE: u64 = 1_000_000_000;
+
+/// Defines how the voter weight of a Realm member is evaluated
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Default)]
+pub enum RealmWeightMode {
+    /// RealmConfig.voter_weight is granted to any member with a TokenOwnerRecord in the Realm
+    #[default]
+    Membership,
+
+    /// RealmConfig.voter_weight is granted to members with at least min_deposit_amount of governing tokens deposited
+    /// Note: The deposit is not locked by votes cast using the plugin and it can be withdrawn or reused
+    /// hence the weight is only valid as of the current slot
+    DepositThreshold { min_deposit_amount: u64 },
+
+    /// The governing tokens deposited by the member scaled by scaling_factor / SCALING_FACTOR_BASE
+    /// Note: The deposit is not locked by votes cast using the plugin and it can be withdrawn or reused
+    /// hence the weight is only valid as of the current slot
+    ProportionalDeposit { scaling_factor: u64 },
+
+    /// The voter weight provided by the Realm's own voter weight plugin scaled by scaling_factor / SCALING_FACTOR_BASE
+    /// The VoterWeightRecord of the plugin must follow the member's TokenOwnerRecord in the instruction accounts
+    PluginVoterWeight {
+        voter_weight_plugin_program_id: Pubkey,
+        scaling_factor: u64,
+    },
+}
+
+/// Scales the given amount by scaling_factor / SCALING_FACTOR_BASE
+pub fn scale_amount(amount: u64, scaling_factor: u64) -> Result<u64> {
+    let scaled_amount = (amount as u128)
+        .checked_mul(scaling_factor as u128)
+        .ok_or(RealmVoterError::VoterWeightOverflow)?
+        .checked_div(SCALING_FACTOR_BASE as u128)
+        .ok_or(RealmVoterError::VoterWeightOverflow)?;
+
+    if scaled_amount > u64::MAX as u128 {
+        return err!(RealmVoterError::VoterWeightOverflow);
+    }
+
+    Ok(scaled_amount as u64)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_scale_amount() {
+        assert_eq!(scale_amount(150, SCALING_FACTOR_BASE).unwrap(), 150);
+        assert_eq!(scale_amount(150, SCALING_FACTOR_BASE * 2).unwrap(), 300);
+        assert_eq!(scale_amount(150, SCALING_FACTOR_BASE / 100).unwrap(), 1);
+        assert_eq!(scale_amount(0, SCALING_FACTOR_BASE * 2).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_scale_amount_with_overflow_error() {
+        assert!(scale_amount(u64::MAX, SCALING_FACTOR_BASE * 2).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use crate::tools::anchor::DISCRIMINATOR_SIZE;
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use gpl_shared::compose::MaxVoterWeightRecordBase;
 
 /// MaxVoterWeightRecord account as defined in spl-governance-addin-api
 /// It's redefined here without account_discriminator for Anchor to treat it as native account
@@ -52,6 +53,12 @@ impl MaxVoterWeightRecord {
     }
 }
 
+impl<'a> MaxVoterWeightRecordBase<'a> for MaxVoterWeightRecord {
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+}
+
 /// Returns MaxVoterWeightRecord PDA seeds
 pub fn get_max_voter_weight_record_seeds<'a>(
     realm: &'a Pubkey,
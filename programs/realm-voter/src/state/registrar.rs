@@ -1,6 +1,10 @@
-use crate::{id, state::GovernanceProgramConfig, tools::anchor::DISCRIMINATOR_SIZE};
+use crate::{
+    error::RealmVoterError, id, state::GovernanceProgramConfig, tools::anchor::DISCRIMINATOR_SIZE,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+pub use gpl_shared::compose::ChainingMode;
+use gpl_shared::compose::RegistrarBase;
 
 /// Enum defining collection item change type
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
@@ -28,11 +32,13 @@ pub struct Registrar {
     pub governing_token_mint: Pubkey,
 
     /// spl-governance instances used for governance power
-    /// Any DAO member of any DAO created using the configured spl-governances would be given 1 vote
+    /// Members of the Realms configured for the spl-governance instances are given the configured Realm voter weight
     /// TODO: Once we have on-chain spl-governance registry this configuration won't be needed any longer
     pub governance_program_configs: Vec<GovernanceProgramConfig>,
 
     /// Vote weight assigned to a member of any of the Realms from the configured spl-governances
+    ///
+    /// Note: Deprecated and no longer used to grant governance power, the weight is configured for each Realm in GovernanceProgramConfig.realm_configs
     pub realm_member_voter_weight: u64,
 
     /// Max voter weight (expressed in governing_token_mint decimal units) is used to establish the theoretical Max Attendance Quorum which is then used to calculate Approval Quorum
@@ -41,11 +47,29 @@ pub struct Registrar {
     /// Note: This is not a security vulnerability because the plugin is inherently not secure and used only to encourage DAO usage and registration of spl-governance instances
     pub max_voter_weight: u64,
 
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// Defines how the voter weight of the previous plugin is combined with the Realm membership voter weight
+    pub chaining_mode: ChainingMode,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 94],
 }
 
 impl Registrar {
+    /// Returns the configuration of the given spl-governance instance
+    pub fn get_governance_program_config(
+        &self,
+        governance_program_id: &Pubkey,
+    ) -> Result<&GovernanceProgramConfig> {
+        self.governance_program_configs
+            .iter()
+            .find(|cc| cc.program_id == *governance_program_id)
+            .ok_or_else(|| RealmVoterError::GovernanceProgramNotConfigured.into())
+    }
+
     pub fn get_space(max_governance_programs: u8) -> usize {
         DISCRIMINATOR_SIZE
             + PUBKEY_BYTES * 3
@@ -53,7 +77,27 @@ impl Registrar {
             + max_governance_programs as usize * (PUBKEY_BYTES + 8)
             + 8
             + 8
-            + 128
+            + (PUBKEY_BYTES + 1)
+            + 1
+            + 94
+    }
+}
+
+impl<'a> RegistrarBase<'a> for Registrar {
+    fn get_realm(&'a self) -> &'a Pubkey {
+        &self.realm
+    }
+
+    fn get_governance_program_id(&'a self) -> &'a Pubkey {
+        &self.governance_program_id
+    }
+
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_previous_voter_weight_plugin_program_id(&'a self) -> &'a Option<Pubkey> {
+        &self.previous_voter_weight_plugin_program_id
     }
 }
 
@@ -89,9 +133,11 @@ mod test {
                 GovernanceProgramConfig::default(),
                 GovernanceProgramConfig::default(),
             ],
-            reserved: [0; 128],
+            reserved: [0; 94],
             max_voter_weight: 100,
             realm_member_voter_weight: 10,
+            previous_voter_weight_plugin_program_id: Some(Pubkey::default()),
+            chaining_mode: ChainingMode::Gate,
         };
 
         // Act
@@ -0,0 +1,236 @@
+use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::ChainingMode;
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use program_test::realm_voter_test::RealmVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.previous_voter_weight_plugin_program_id,
+        Some(PredecessorPluginTest::program_id())
+    );
+    assert_eq!(registrar.chaining_mode, ChainingMode::Gate);
+
+    // The max voter weight must be updated using UpdateMaxVoterWeightRecord
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_plugin_removed(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+            ChainingMode::Add,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.previous_voter_weight_plugin_program_id, None);
+
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weights_with_previous_voter_weight_plugin(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_invalid_realm_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_previous_voter_weight_plugin_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_previous_voter_weight_plugin_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_previous_voter_weight_plugin_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
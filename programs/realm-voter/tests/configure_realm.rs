@@ -0,0 +1,682 @@
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, RealmWeightMode, SCALING_FACTOR_BASE},
+};
+use program_test::realm_voter_test::RealmVoterTest;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+use spl_governance::error::GovernanceError;
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_gov_err, assert_realm_voter_err};
+
+#[tokio::test]
+async fn test_configure_realm() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            Some(member_realm_cookie.account.community_mint),
+            10,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        vec![realm_config_cookie.realm_config]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_multiple_realms() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut realm_configs = vec![];
+
+    // Act
+    for voter_weight in 1..=5 {
+        let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+        let realm_config_cookie = realm_voter_test
+            .configure_realm(
+                &registrar_cookie,
+                &governance_program_cookie,
+                &member_realm_cookie,
+                CollectionItemChangeType::Upsert,
+                None,
+                voter_weight,
+            )
+            .await?;
+
+        realm_configs.push(realm_config_cookie.realm_config);
+    }
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        realm_configs
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_for_existing_realm() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            20,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        vec![realm_config_cookie.realm_config]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_preserved_by_governance_program_update() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        vec![realm_config_cookie.realm_config]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_governance_program_after_realm_configured() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie1 = realm_voter_test.with_governance_program(None).await;
+
+    // Create config with gpl_realm_voter::id() to have some other executable program, the actual program is irrelevant here
+    let governance_program_cookie2 = realm_voter_test
+        .with_governance_program(Some(gpl_realm_voter::id()))
+        .await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie1,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let realm_config_cookie = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie1,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie2,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.governance_program_configs.len(), 2);
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        vec![realm_config_cookie.realm_config]
+    );
+    assert_eq!(
+        registrar.governance_program_configs[1].program_id,
+        gpl_realm_voter::id()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_realm_configuration() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Remove,
+            None,
+            0,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs.len(),
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_realm_configuration_with_realm_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Remove,
+            None,
+            0,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_governance_program_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::GovernanceProgramNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_own_realm_not_allowed_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::OwnRealmNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_invalid_governing_token_mint_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            Some(realm_cookie.account.community_mint),
+            10,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_gov_err(err, GovernanceError::InvalidGoverningTokenMint);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::Membership,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_realm_authority_must_sign_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::Membership,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_weight_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    // Act
+    let realm_config_cookie = realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::ProportionalDeposit {
+                scaling_factor: SCALING_FACTOR_BASE,
+            },
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.governance_program_configs[0].realm_configs,
+        vec![realm_config_cookie.realm_config]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_realm_with_zero_scaling_factor_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let member_realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    // Act
+    let err = realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &member_realm_cookie,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::ProportionalDeposit { scaling_factor: 0 },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidRealmWeightMode);
+
+    Ok(())
+}
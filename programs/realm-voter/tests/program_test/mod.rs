@@ -1,4 +1,5 @@
 pub mod governance_test;
+pub mod predecessor_plugin_test;
 pub mod program_test_bench;
 pub mod realm_voter_test;
 pub mod tools;
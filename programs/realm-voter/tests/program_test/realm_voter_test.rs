@@ -8,11 +8,12 @@ use gpl_realm_voter::state::max_voter_weight_record::{
 use gpl_realm_voter::state::*;
 
 use solana_program_test::{BanksClientError, ProgramTest};
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::program_test::governance_test::GovernanceTest;
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
 
 use crate::program_test::governance_test::RealmCookie;
@@ -45,6 +46,10 @@ pub struct GovernanceProgramConfigCookie {
     pub program_config: GovernanceProgramConfig,
 }
 
+pub struct RealmConfigCookie {
+    pub realm_config: RealmConfig,
+}
+
 pub struct GovernanceProgramCookie {
     pub program_id: Pubkey,
 }
@@ -53,6 +58,7 @@ pub struct RealmVoterTest {
     pub program_id: Pubkey,
     pub bench: Arc<ProgramTestBench>,
     pub governance: GovernanceTest,
+    pub predecessor_plugin: PredecessorPluginTest,
 }
 
 impl RealmVoterTest {
@@ -67,6 +73,7 @@ impl RealmVoterTest {
 
         RealmVoterTest::add_program(&mut program_test);
         GovernanceTest::add_program(&mut program_test);
+        PredecessorPluginTest::add_program(&mut program_test);
 
         let program_id = gpl_realm_voter::id();
 
@@ -76,10 +83,13 @@ impl RealmVoterTest {
         let governance_bench =
             GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
 
+        let predecessor_plugin = PredecessorPluginTest::new(bench_rc.clone());
+
         Self {
             program_id,
             bench: bench_rc,
             governance: governance_bench,
+            predecessor_plugin,
         }
     }
 
@@ -153,9 +163,11 @@ impl RealmVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             governance_program_configs: vec![],
-            reserved: [0; 128],
+            reserved: [0; 94],
             max_voter_weight: 0,
             realm_member_voter_weight: 0,
+            previous_voter_weight_plugin_program_id: None,
+            chaining_mode: ChainingMode::Add,
         };
 
         Ok(RegistrarCookie {
@@ -300,18 +312,63 @@ impl RealmVoterTest {
         registrar_cookie: &RegistrarCookie,
         voter_weight_record_cookie: &mut VoterWeightRecordCookie,
         token_owner_record_cookie: &TokenOwnerRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_token_owner_records(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            &[token_owner_record_cookie],
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_token_owner_records(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookies: &[&TokenOwnerRecordCookie],
+    ) -> Result<(), BanksClientError> {
+        let token_owner_records: Vec<Pubkey> = token_owner_record_cookies
+            .iter()
+            .map(|tor| tor.address)
+            .collect();
+
+        self.update_voter_weight_record_with_accounts(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            &token_owner_records,
+            None,
+        )
+        .await
+    }
+
+    /// Updates VoterWeightRecord using the first account as the token_owner_record instruction account
+    /// and the rest of the accounts as remaining accounts
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_accounts(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        accounts: &[Pubkey],
+        previous_voter_weight_record: Option<Pubkey>,
     ) -> Result<(), BanksClientError> {
         let data = anchor_lang::InstructionData::data(
             &gpl_realm_voter::instruction::UpdateVoterWeightRecord {},
         );
 
-        let accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
+        let update_accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
             registrar: registrar_cookie.address,
             voter_weight_record: voter_weight_record_cookie.address,
-            token_owner_record: token_owner_record_cookie.address,
+            token_owner_record: accounts[0],
+            previous_voter_weight_record,
         };
 
-        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut account_metas =
+            anchor_lang::ToAccountMetas::to_account_metas(&update_accounts, None);
+
+        for account in accounts.iter().skip(1) {
+            account_metas.push(AccountMeta::new_readonly(*account, false));
+        }
 
         let instructions = vec![Instruction {
             program_id: gpl_realm_voter::id(),
@@ -436,7 +493,8 @@ impl RealmVoterTest {
 
         let governance_program_config = GovernanceProgramConfig {
             program_id: governance_program_cookie.program_id.clone(),
-            reserved: [0; 8],
+            realm_configs: vec![],
+            reserved: [0; 4],
         };
 
         Ok(GovernanceProgramConfigCookie {
@@ -444,6 +502,199 @@ impl RealmVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn configure_realm(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        governance_program_cookie: &GovernanceProgramCookie,
+        member_realm_cookie: &RealmCookie,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        voter_weight: u64,
+    ) -> Result<RealmConfigCookie, BanksClientError> {
+        self.configure_realm_with_weight_mode(
+            registrar_cookie,
+            governance_program_cookie,
+            member_realm_cookie,
+            change_type,
+            governing_token_mint,
+            voter_weight,
+            RealmWeightMode::Membership,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn configure_realm_with_weight_mode(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        governance_program_cookie: &GovernanceProgramCookie,
+        member_realm_cookie: &RealmCookie,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        voter_weight: u64,
+        weight_mode: RealmWeightMode,
+    ) -> Result<RealmConfigCookie, BanksClientError> {
+        self.configure_realm_using_ix(
+            registrar_cookie,
+            governance_program_cookie,
+            member_realm_cookie,
+            change_type,
+            governing_token_mint,
+            voter_weight,
+            weight_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn configure_realm_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        governance_program_cookie: &GovernanceProgramCookie,
+        member_realm_cookie: &RealmCookie,
+        change_type: CollectionItemChangeType,
+        governing_token_mint: Option<Pubkey>,
+        voter_weight: u64,
+        weight_mode: RealmWeightMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<RealmConfigCookie, BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::ConfigureRealm {
+                change_type,
+                governing_token_mint,
+                voter_weight,
+                weight_mode: weight_mode.clone(),
+            });
+
+        let accounts = gpl_realm_voter::accounts::ConfigureRealm {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            governance_program_id: governance_program_cookie.program_id,
+            member_realm: member_realm_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut configure_realm_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_realm_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_realm_ix], Some(signers))
+            .await?;
+
+        let realm_config = RealmConfig {
+            realm: member_realm_cookie.address,
+            governing_token_mint,
+            voter_weight,
+            weight_mode,
+            reserved: [0; 8],
+        };
+
+        Ok(RealmConfigCookie { realm_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_previous_voter_weight_plugin_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            previous_voter_weight_plugin_program_id,
+            chaining_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::ConfigurePreviousVoterWeightPlugin {
+                previous_voter_weight_plugin_program_id,
+                chaining_mode,
+            },
+        );
+
+        let accounts = gpl_realm_voter::accounts::ConfigurePreviousVoterWeightPlugin {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_previous_voter_weight_plugin_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_previous_voter_weight_plugin_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_previous_voter_weight_plugin_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_max_voter_weight_record: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            previous_max_voter_weight_record,
+        };
+
+        let update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
         self.bench.get_anchor_account::<Registrar>(*registrar).await
@@ -0,0 +1,248 @@
+use gpl_realm_voter::error::RealmVoterError;
+use gpl_realm_voter::state::ChainingMode;
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use program_test::realm_voter_test::RealmVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_plugin_and_add_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let previous_max_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 1000, None)
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(previous_max_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1110);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_plugin_and_gate_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let previous_max_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 1000, None)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(previous_max_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_max_voter_weight_record_missing_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::PreviousMaxVoterWeightRecordMissing);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_max_voter_weight_record_expired_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let previous_max_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 1000, Some(0))
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = realm_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(previous_max_voter_weight_record_cookie.address),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::PreviousMaxVoterWeightRecordExpired);
+
+    Ok(())
+}
@@ -1,5 +1,13 @@
 use crate::program_test::realm_voter_test::RealmVoterTest;
-use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{
+        ChainingMode, CollectionItemChangeType, RealmWeightMode, VoterWeightAction,
+        SCALING_FACTOR_BASE,
+    },
+};
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use solana_sdk::pubkey::Pubkey;
 use program_test::tools::*;
 use solana_program_test::*;
 use solana_sdk::transport::TransportError;
@@ -32,6 +40,17 @@ async fn test_update_voter_weight_record() -> Result<(), TransportError> {
         )
         .await?;
 
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
     let mut voter_weight_record_cookie = realm_voter_test
         .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
         .await?;
@@ -212,3 +231,1289 @@ async fn test_update_voter_weight_record_with_token_owner_record_must_match_erro
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_multiple_realms() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Create TokenOwnerRecords for other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie3,
+            CollectionItemChangeType::Upsert,
+            Some(realm_cookie3.account.community_mint),
+            25,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_token_owner_records(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_owner_record_cookie2, &token_owner_record_cookie3],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 35);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_realm_not_configured_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord for other Realm which is not configured
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_realm_configured_for_other_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Create community TokenOwnerRecord for other Realm configured for the council mint only
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            realm_cookie2.account.config.council_mint,
+            10,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::RealmNotConfigured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_duplicated_realm_membership_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_token_owner_records(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[&token_owner_record_cookie, &token_owner_record_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::DuplicatedRealmMembership);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_deposit_threshold() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_deposit_below_threshold() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 99)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_proportional_deposit() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::ProportionalDeposit {
+                scaling_factor: SCALING_FACTOR_BASE / 2,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 150)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 75);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_plugin_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    // Use RealmVoter as the plugin of the source Realm which grants weight for membership of yet another Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let registrar_cookie2 = realm_voter_test.with_registrar(&realm_cookie2).await?;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie2,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_realm(
+            &registrar_cookie2,
+            &governance_program_cookie,
+            &realm_cookie3,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie2 = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie2, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::PluginVoterWeight {
+                voter_weight_plugin_program_id: gpl_realm_voter::id(),
+                scaling_factor: SCALING_FACTOR_BASE * 3,
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie2,
+            &mut voter_weight_record_cookie2,
+            &token_owner_record_cookie3,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[
+                token_owner_record_cookie2.address,
+                voter_weight_record_cookie2.address,
+            ],
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 30);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_source_voter_weight_record_missing_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::PluginVoterWeight {
+                voter_weight_plugin_program_id: gpl_realm_voter::id(),
+                scaling_factor: SCALING_FACTOR_BASE,
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::SourceVoterWeightRecordMissing);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_source_voter_weight_record_expired_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let registrar_cookie2 = realm_voter_test.with_registrar(&realm_cookie2).await?;
+
+    // The source VoterWeightRecord is never updated and expired as of slot 0
+    let voter_weight_record_cookie2 = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie2, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            0,
+            RealmWeightMode::PluginVoterWeight {
+                voter_weight_plugin_program_id: gpl_realm_voter::id(),
+                scaling_factor: SCALING_FACTOR_BASE,
+            },
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[
+                token_owner_record_cookie2.address,
+                voter_weight_record_cookie2.address,
+            ],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::SourceVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_add_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &token_owner_cookie, 100)
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 110);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_gate_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &token_owner_cookie, 100)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_gate_mode_without_membership_weight(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 50)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &token_owner_cookie, 100)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_action_preserved(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let weight_action_target = Pubkey::new_unique();
+
+    let previous_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_for_action(
+            &realm_cookie,
+            &token_owner_cookie,
+            100,
+            None,
+            Some(spl_governance_addin_api::voter_weight::VoterWeightAction::CastVote),
+            Some(weight_action_target),
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 110);
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CastVote)
+    );
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(weight_action_target)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_record_missing_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::PreviousVoterWeightRecordMissing);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_record_expired_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+
+    realm_voter_test
+        .configure_realm_with_weight_mode(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &realm_cookie2,
+            CollectionItemChangeType::Upsert,
+            None,
+            10,
+            RealmWeightMode::DepositThreshold {
+                min_deposit_amount: 100,
+            },
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_council_token_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = realm_voter_test
+        .predecessor_plugin
+        .with_voter_weight_record_for_action(
+            &realm_cookie,
+            &token_owner_cookie,
+            100,
+            Some(0),
+            None,
+            None,
+        )
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::PreviousVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_record_not_allowed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+
+    for realm_cookie in [&realm_cookie2, &realm_cookie3] {
+        realm_voter_test
+            .configure_realm(
+                &registrar_cookie,
+                &governance_program_cookie,
+                realm_cookie,
+                CollectionItemChangeType::Upsert,
+                None,
+                10,
+            )
+            .await?;
+    }
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+
+    // Without the previous plugin configured the TokenOwnerRecord passed in the previous_voter_weight_record slot
+    // must not be silently ignored
+    let err = realm_voter_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_owner_record_cookie2.address],
+            Some(token_owner_record_cookie3.address),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::PreviousVoterWeightRecordNotAllowed);
+
+    Ok(())
+}
@@ -28,4 +28,31 @@ pub enum TokenHaverError {
 
     #[msg("All token accounts' mints must be unique")]
     TokenAccountDuplicateMint,
+
+    #[msg("Voter weight overflow")]
+    VoterWeightOverflow,
+
+    #[msg("Mint is configured more than once")]
+    DuplicatedMintConfig,
+
+    #[msg("Invalid mint config")]
+    InvalidMintConfig,
+
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+
+    #[msg("Previous VoterWeightRecord missing")]
+    PreviousVoterWeightRecordMissing,
+
+    #[msg("Previous VoterWeightRecord expired")]
+    PreviousVoterWeightRecordExpired,
+
+    #[msg("Previous MaxVoterWeightRecord missing")]
+    PreviousMaxVoterWeightRecordMissing,
+
+    #[msg("Previous MaxVoterWeightRecord expired")]
+    PreviousMaxVoterWeightRecordExpired,
+
+    #[msg("Registrar has already been migrated to the current version")]
+    RegistrarAlreadyMigrated,
 }
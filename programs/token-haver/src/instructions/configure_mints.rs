@@ -1,12 +1,14 @@
 use crate::error::TokenHaverError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
 use crate::state::*;
 use anchor_lang::system_program::Transfer;
 use anchor_lang::{prelude::*, system_program};
 use spl_governance::state::realm;
 
 /// Configures mints for Registrar
+/// MaxVoterWeightRecord.max_voter_weight is kept in sync with the new configuration
 #[derive(Accounts)]
-#[instruction(mints: Vec<Pubkey>)]
+#[instruction(mints: Vec<MintConfig>)]
 pub struct ConfigureMints<'info> {
     /// The Registrar for the given realm and governing_token_mint
     #[account(mut)]
@@ -26,9 +28,22 @@ pub struct ConfigureMints<'info> {
     pub realm_authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// MaxVoterWeightRecord for the given registrar.realm and registrar.governing_token_mint
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ TokenHaverError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenHaverError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
 }
 
-pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Result<()> {
+pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<MintConfig>) -> Result<()> {
+    assert_valid_mint_configs(&mints)?;
+
     let new_size = Registrar::get_space(mints.len() as u8);
 
     let rent = Rent::get()?;
@@ -55,7 +70,7 @@ pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Resu
     let registrar = &mut ctx.accounts.registrar;
     registrar.to_account_info().realloc(new_size, false)?;
 
-    registrar.mints = mints;
+    registrar.mint_configs = mints;
 
     let realm = realm::get_realm_data_for_governing_token_mint(
         &registrar.governance_program_id,
@@ -69,5 +84,9 @@ pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Resu
         TokenHaverError::InvalidRealmAuthority
     );
 
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight()?;
+    max_voter_weight_record.max_voter_weight_expiry = registrar.get_max_voter_weight_expiry();
+
     Ok(())
 }
@@ -7,7 +7,7 @@ use spl_governance::state::realm;
 /// Creates Registrar storing Realm Voter configuration for spl-governance Realm
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
 #[derive(Accounts)]
-#[instruction(mints: Vec<Pubkey>)]
+#[instruction(mints: Vec<MintConfig>)]
 pub struct CreateRegistrar<'info> {
     /// The Realm Voter Registrar
     /// There can only be a single registrar per governance Realm and governing mint of the Realm
@@ -57,13 +57,20 @@ pub struct CreateRegistrar<'info> {
 /// To use the registrar, call ConfigureGovernanceProgram to register spl-governance instance which will be
 /// used for governance
 ///
-pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<Pubkey>) -> Result<()> {
+pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<MintConfig>) -> Result<()> {
+    assert_valid_mint_configs(&mints)?;
+
     let registrar = &mut ctx.accounts.registrar;
     **registrar = Registrar {
         governance_program_id: ctx.accounts.governance_program_id.key(),
         realm: ctx.accounts.realm.key(),
         governing_token_mint: ctx.accounts.governing_token_mint.key(),
-        mints,
+        mints: vec![],
+        version: CURRENT_REGISTRAR_VERSION,
+        max_voter_weight: None,
+        previous_voter_weight_plugin_program_id: None,
+        chaining_mode: ChainingMode::Add,
+        mint_configs: mints,
     };
 
     // Verify that realm_authority is the expected authority of the Realm
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::error::TokenHaverError;
+use crate::state::*;
+use crate::tools::anchor::{resize_account, DISCRIMINATOR_SIZE};
+
+/// Migrates version 0 Registrar to the current Registrar layout
+/// Version 0 Registrars store the mints as Vec<Pubkey> and each mint is migrated to MintConfig
+/// granting the same voter weight as version 0, see MintConfig::from_version_0_mint
+/// The account is resized to fit the current layout
+///
+/// The instruction is permissionless because it doesn't change the voter weight granted by the Registrar
+#[derive(Accounts)]
+pub struct MigrateRegistrar<'info> {
+    /// The Registrar to migrate
+    /// CHECK: Version 0 Registrar can't be deserialized using the current layout and it's deserialized in the instruction
+    #[account(mut, owner = crate::id())]
+    pub registrar: UncheckedAccount<'info>,
+
+    /// Pays for the Registrar resize
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+    let registrar_info = ctx.accounts.registrar.to_account_info();
+
+    let registrar_v0 = {
+        let data = registrar_info.try_borrow_data()?;
+
+        require!(
+            data.len() >= DISCRIMINATOR_SIZE
+                && &data[..DISCRIMINATOR_SIZE] == Registrar::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let mut data = &data[DISCRIMINATOR_SIZE..];
+        let registrar_v0 = RegistrarV0::deserialize(&mut data)?;
+
+        // Version 0 layout ends with the mints and the current layout stores the version right after them
+        require!(
+            data.first().copied().unwrap_or(0) == 0,
+            TokenHaverError::RegistrarAlreadyMigrated
+        );
+
+        registrar_v0
+    };
+
+    let registrar = Registrar {
+        governance_program_id: registrar_v0.governance_program_id,
+        realm: registrar_v0.realm,
+        governing_token_mint: registrar_v0.governing_token_mint,
+        mints: vec![],
+        version: CURRENT_REGISTRAR_VERSION,
+        max_voter_weight: None,
+        previous_voter_weight_plugin_program_id: None,
+        chaining_mode: ChainingMode::Add,
+        mint_configs: registrar_v0
+            .mints
+            .into_iter()
+            .map(MintConfig::from_version_0_mint)
+            .collect(),
+    };
+
+    let new_size = Registrar::get_space(registrar.mint_configs.len() as u8);
+
+    if new_size > registrar_info.data_len() {
+        resize_account(
+            &registrar_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_size,
+        )?;
+    }
+
+    let mut data = registrar_info.try_borrow_mut_data()?;
+    registrar.serialize(&mut &mut data[DISCRIMINATOR_SIZE..])?;
+
+    Ok(())
+}
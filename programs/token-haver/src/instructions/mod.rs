@@ -9,3 +9,18 @@ mod update_voter_weight_record;
 
 pub use configure_mints::*;
 mod configure_mints;
+
+pub use create_max_voter_weight_record::*;
+mod create_max_voter_weight_record;
+
+pub use configure_max_voter_weight::*;
+mod configure_max_voter_weight;
+
+pub use configure_previous_voter_weight_plugin::*;
+mod configure_previous_voter_weight_plugin;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
+pub use migrate_registrar::*;
+mod migrate_registrar;
@@ -0,0 +1,87 @@
+use crate::error::TokenHaverError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use gpl_shared::compose::{combine_max_voter_weight, resolve_input_max_voter_weight};
+use gpl_shared::generic_max_voter_weight::GenericMaxVoterWeight;
+
+/// Updates MaxVoterWeightRecord by combining the max voter weight of the Registrar with the max voter weight of the previous plugin
+///
+/// If a previous voter weight plugin is configured then the instruction sets MaxVoterWeightRecord.max_voter_weight
+/// which is valid for the current slot only and must be executed inside the same transaction as the corresponding spl-gov instruction
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The TokenHaver voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ TokenHaverError::InvalidVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenHaverError::InvalidVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// MaxVoterWeightRecord of the previous voter weight plugin or the governing token Mint
+    /// It's required only when registrar.previous_voter_weight_plugin_program_id is set
+    /// CHECK: Checked in the code depending on the registrar
+    pub previous_max_voter_weight_record: Option<UncheckedAccount<'info>>,
+}
+
+pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    if registrar.previous_voter_weight_plugin_program_id.is_none() {
+        let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+        // Without the previous plugin max_voter_weight is static and it never expires
+        max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight()?;
+        max_voter_weight_record.max_voter_weight_expiry = None;
+
+        return Ok(());
+    }
+
+    let previous_max_voter_weight_record_info = ctx
+        .accounts
+        .previous_max_voter_weight_record
+        .as_ref()
+        .ok_or(TokenHaverError::PreviousMaxVoterWeightRecordMissing)?
+        .to_account_info();
+
+    let clone_record = ctx.accounts.max_voter_weight_record.clone();
+    let previous_max_voter_weight_record = resolve_input_max_voter_weight(
+        &previous_max_voter_weight_record_info,
+        &clone_record,
+        registrar,
+    )?;
+
+    let current_slot = Clock::get()?.slot;
+
+    if let Some(previous_max_voter_weight_expiry) =
+        previous_max_voter_weight_record.get_max_voter_weight_expiry()
+    {
+        require_gte!(
+            previous_max_voter_weight_expiry,
+            current_slot,
+            TokenHaverError::PreviousMaxVoterWeightRecordExpired
+        );
+    }
+
+    let max_voter_weight = combine_max_voter_weight(
+        registrar.chaining_mode,
+        previous_max_voter_weight_record.get_max_voter_weight(),
+        registrar.get_max_voter_weight()?,
+    )
+    .ok_or(TokenHaverError::VoterWeightOverflow)?;
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = max_voter_weight;
+
+    // Record is only valid as of the current slot
+    max_voter_weight_record.max_voter_weight_expiry = Some(current_slot);
+
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use crate::error::TokenHaverError;
 use crate::state::*;
+use crate::tools::spl_token::assert_token_account_locked;
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
+use gpl_shared::compose::{combine_voter_weight, resolve_input_voter_weight};
+use gpl_shared::generic_voter_weight::GenericVoterWeight;
 
-/// Updates VoterWeightRecord based on Realm DAO membership
-/// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the configured spl-governance instances
+/// Updates VoterWeightRecord based on the locked tokens of the configured mints held by the voter
+/// The token accounts are passed as remaining_accounts
+/// If a previous voter weight plugin is configured then the token holding voter weight is combined with
+/// the voter weight of the previous plugin using the configured ChainingMode
+/// and the VoterWeightRecord of the previous plugin must be passed as the first remaining account ahead of the token accounts
 ///
 /// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
 /// and must be executed inside the same transaction as the corresponding spl-gov instruction
@@ -30,12 +36,35 @@ pub fn update_voter_weight_record<'info>(
     let registrar = &ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    let nonzero_token_accounts: Vec<Account<TokenAccount>> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|account| Account::<TokenAccount>::try_from(account).unwrap())
-        .filter(|account| account.amount > 0) // filter out zero balance accounts
-        .collect();
+    // The previous VoterWeightRecord is only passed when the previous plugin is configured
+    // and the account list of the Registrars without it stays the same
+    let (previous_voter_weight_record_info, token_account_infos) =
+        match registrar.previous_voter_weight_plugin_program_id {
+            Some(_) => {
+                let (previous_voter_weight_record_info, token_account_infos) = ctx
+                    .remaining_accounts
+                    .split_first()
+                    .ok_or(TokenHaverError::PreviousVoterWeightRecordMissing)?;
+
+                (Some(previous_voter_weight_record_info), token_account_infos)
+            }
+            None => (None, ctx.remaining_accounts),
+        };
+
+    let mut nonzero_token_accounts: Vec<InterfaceAccount<TokenAccount>> = vec![];
+
+    for account_info in token_account_infos.iter() {
+        // Both spl-token and Token-2022 accounts are accepted
+        let account = InterfaceAccount::<TokenAccount>::try_from(account_info)
+            .map_err(|_| TokenHaverError::InvalidTokenAccount)?;
+
+        // filter out zero balance accounts
+        if account.amount > 0 {
+            nonzero_token_accounts.push(account);
+        }
+    }
+
+    let mut voter_weight = 0u64;
 
     for account in nonzero_token_accounts.iter() {
         // Throw an error if a token account's owner doesnt match token_owner_record.governing_token_owner
@@ -56,18 +85,68 @@ pub fn update_voter_weight_record<'info>(
         );
 
         // Throw an error if a token account's mint isn't in registrar.mints
-        require!(
-            registrar.mints.contains(&account.mint),
-            TokenHaverError::TokenAccountWrongMint
-        );
-        // Throw an error if a token account is not frozen
-        require!(account.is_frozen(), TokenHaverError::TokenAccountNotLocked);
+        let mint_config = registrar
+            .get_mint_config(&account.mint)
+            .ok_or(TokenHaverError::TokenAccountWrongMint)?;
+
+        // Throw an error if a token account is not locked as required by the mint's lock policy
+        assert_token_account_locked(
+            mint_config.lock_policy,
+            account.is_frozen(),
+            &account.to_account_info(),
+        )?;
+
+        voter_weight = voter_weight
+            .checked_add(mint_config.get_voter_weight(account.amount)?)
+            .ok_or(TokenHaverError::VoterWeightOverflow)?;
     }
 
-    // Setup voter_weight
-    voter_weight_record.voter_weight = (nonzero_token_accounts.len() as u64) * 1_000_000;
+    let current_slot = Clock::get()?.slot;
+
+    match previous_voter_weight_record_info {
+        None => {
+            // Setup voter_weight
+            voter_weight_record.voter_weight = voter_weight;
+
+            // Set action and target to None to indicate the weight is valid for any action and target
+            voter_weight_record.weight_action = None;
+            voter_weight_record.weight_action_target = None;
+        }
+        Some(previous_voter_weight_record_info) => {
+            let clone_record = voter_weight_record.clone();
+            let previous_voter_weight_record = resolve_input_voter_weight(
+                previous_voter_weight_record_info,
+                &clone_record,
+                registrar,
+            )?;
+
+            if let Some(previous_voter_weight_expiry) =
+                previous_voter_weight_record.get_voter_weight_expiry()
+            {
+                require_gte!(
+                    previous_voter_weight_expiry,
+                    current_slot,
+                    TokenHaverError::PreviousVoterWeightRecordExpired
+                );
+            }
+
+            voter_weight_record.voter_weight = combine_voter_weight(
+                registrar.chaining_mode,
+                previous_voter_weight_record.get_voter_weight(),
+                voter_weight,
+            )
+            .ok_or(TokenHaverError::VoterWeightOverflow)?;
+
+            // The action and target of the previous plugin are preserved
+            voter_weight_record.weight_action = previous_voter_weight_record
+                .get_weight_action()
+                .map(|action| action.into());
+            voter_weight_record.weight_action_target =
+                previous_voter_weight_record.get_weight_action_target();
+        }
+    }
 
     // Record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+    voter_weight_record.voter_weight_expiry = Some(current_slot);
     Ok(())
 }
@@ -8,6 +8,7 @@ use instructions::*;
 pub mod state;
 
 pub mod tools;
+use crate::state::{ChainingMode, MintConfig};
 
 declare_id!("7gobfUihgoxA14RUnVaseoah89ggCgYAzgz1JoaPAXam");
 
@@ -16,7 +17,7 @@ pub mod token_haver {
 
     use super::*;
 
-    pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<Pubkey>) -> Result<()> {
+    pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<MintConfig>) -> Result<()> {
         log_version();
         instructions::create_registrar(ctx, mints)
     }
@@ -33,10 +34,41 @@ pub mod token_haver {
         log_version();
         instructions::update_voter_weight_record(ctx)
     }
-    pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Result<()> {
+    pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<MintConfig>) -> Result<()> {
         log_version();
         instructions::configure_mints(ctx, mints)
     }
+    pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::create_max_voter_weight_record(ctx)
+    }
+    pub fn configure_max_voter_weight(
+        ctx: Context<ConfigureMaxVoterWeight>,
+        max_voter_weight: Option<u64>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_max_voter_weight(ctx, max_voter_weight)
+    }
+    pub fn configure_previous_voter_weight_plugin(
+        ctx: Context<ConfigurePreviousVoterWeightPlugin>,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_previous_voter_weight_plugin(
+            ctx,
+            previous_voter_weight_plugin_program_id,
+            chaining_mode,
+        )
+    }
+    pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
+    pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+        log_version();
+        instructions::migrate_registrar(ctx)
+    }
 }
 
 fn log_version() {
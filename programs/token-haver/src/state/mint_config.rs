@@ -0,0 +1,190 @@
+use crate::error::TokenHaverError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Defines how the voter weight of a mint is derived from the voter's locked balance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MintWeightMode {
+    /// The voter gets MintConfig.voter_weight regardless of the locked balance
+    #[default]
+    Fixed,
+
+    /// The voter gets MintConfig.voter_weight for each token (in the mint's base units) up to the given cap
+    ProportionalUpToCap {
+        /// The max voter weight a single voter can get for the mint
+        max_voter_weight: u64,
+    },
+}
+
+/// Defines when the voter's tokens are considered locked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MintLockPolicy {
+    /// The token account must be frozen
+    #[default]
+    Frozen,
+
+    /// The token account must be frozen or have the Token-2022 NonTransferableAccount extension
+    FrozenOrNonTransferable,
+
+    /// The tokens don't have to be locked
+    /// Note: The tokens can be moved to another voter hence the weight is only valid as of the current slot
+    NotRequired,
+}
+
+/// Configuration of a mint whose locked tokens grant voter weight
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct MintConfig {
+    /// The mint of the tokens the voter must hold
+    pub mint: Pubkey,
+
+    /// Voter weight granted for holding the mint
+    /// For MintWeightMode::ProportionalUpToCap it's the weight granted per token base unit
+    pub voter_weight: u64,
+
+    /// Min balance (in the mint's base units) the voter must hold for the mint to grant any weight
+    /// Balances below the threshold are treated as dust and ignored
+    pub min_balance: u64,
+
+    /// The mode used to derive the voter weight from the locked balance
+    pub weight_mode: MintWeightMode,
+
+    /// The policy used to decide whether the voter's tokens are locked
+    pub lock_policy: MintLockPolicy,
+}
+
+/// Voter weight granted for holding any of the mints of version 0 Registrars
+pub const VERSION_0_MINT_VOTER_WEIGHT: u64 = 1_000_000;
+
+impl MintConfig {
+    pub const SPACE: usize = PUBKEY_BYTES + 8 + 8 + 1 + 8 + 1;
+
+    /// Returns MintConfig granting the same voter weight as version 0 Registrars for the given mint
+    /// The token account must be frozen and any nonzero balance grants VERSION_0_MINT_VOTER_WEIGHT
+    pub fn from_version_0_mint(mint: Pubkey) -> Self {
+        MintConfig {
+            mint,
+            voter_weight: VERSION_0_MINT_VOTER_WEIGHT,
+            min_balance: 0,
+            weight_mode: MintWeightMode::Fixed,
+            lock_policy: MintLockPolicy::Frozen,
+        }
+    }
+
+    /// Returns the voter weight granted for the given locked balance
+    pub fn get_voter_weight(&self, amount: u64) -> Result<u64> {
+        if amount == 0 || amount < self.min_balance {
+            return Ok(0);
+        }
+
+        match self.weight_mode {
+            MintWeightMode::Fixed => Ok(self.voter_weight),
+            MintWeightMode::ProportionalUpToCap { max_voter_weight } => {
+                let voter_weight = (amount as u128)
+                    .checked_mul(self.voter_weight as u128)
+                    .ok_or(TokenHaverError::VoterWeightOverflow)?;
+
+                Ok(voter_weight.min(max_voter_weight as u128) as u64)
+            }
+        }
+    }
+
+    /// Returns the max voter weight a single voter can get for the mint
+    pub fn get_max_voter_weight(&self) -> u64 {
+        match self.weight_mode {
+            MintWeightMode::Fixed => self.voter_weight,
+            MintWeightMode::ProportionalUpToCap { max_voter_weight } => max_voter_weight,
+        }
+    }
+}
+
+/// Asserts the given mint configs are valid
+pub fn assert_valid_mint_configs(mints: &[MintConfig]) -> Result<()> {
+    for (index, mint_config) in mints.iter().enumerate() {
+        require!(
+            !mints[..index].iter().any(|mc| mc.mint == mint_config.mint),
+            TokenHaverError::DuplicatedMintConfig
+        );
+
+        require!(
+            mint_config.voter_weight > 0 && mint_config.get_max_voter_weight() > 0,
+            TokenHaverError::InvalidMintConfig
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_space() {
+        // Arrange
+        let mint_config = MintConfig {
+            weight_mode: MintWeightMode::ProportionalUpToCap {
+                max_voter_weight: 0,
+            },
+            ..Default::default()
+        };
+
+        // Act
+        let actual_space = mint_config.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(MintConfig::SPACE, actual_space);
+    }
+
+    #[test]
+    fn test_get_voter_weight_with_fixed_mode() {
+        // Arrange
+        let mint_config = MintConfig {
+            voter_weight: 10,
+            min_balance: 5,
+            ..Default::default()
+        };
+
+        // Act + Assert
+        assert_eq!(mint_config.get_voter_weight(0).unwrap(), 0);
+        assert_eq!(mint_config.get_voter_weight(4).unwrap(), 0);
+        assert_eq!(mint_config.get_voter_weight(5).unwrap(), 10);
+        assert_eq!(mint_config.get_voter_weight(1_000).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_get_voter_weight_with_proportional_up_to_cap_mode() {
+        // Arrange
+        let mint_config = MintConfig {
+            voter_weight: 2,
+            min_balance: 0,
+            weight_mode: MintWeightMode::ProportionalUpToCap {
+                max_voter_weight: 100,
+            },
+            ..Default::default()
+        };
+
+        // Act + Assert
+        assert_eq!(mint_config.get_voter_weight(0).unwrap(), 0);
+        assert_eq!(mint_config.get_voter_weight(30).unwrap(), 60);
+        assert_eq!(mint_config.get_voter_weight(u64::MAX).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_from_version_0_mint() {
+        // Arrange
+        let mint_config = MintConfig::from_version_0_mint(Pubkey::new_unique());
+
+        // Act + Assert
+        assert_eq!(mint_config.get_voter_weight(0).unwrap(), 0);
+        assert_eq!(
+            mint_config.get_voter_weight(1).unwrap(),
+            VERSION_0_MINT_VOTER_WEIGHT
+        );
+        assert_eq!(
+            mint_config.get_voter_weight(u64::MAX).unwrap(),
+            VERSION_0_MINT_VOTER_WEIGHT
+        );
+        assert_eq!(mint_config.lock_policy, MintLockPolicy::Frozen);
+    }
+}
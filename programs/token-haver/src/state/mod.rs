@@ -3,5 +3,8 @@ pub mod registrar;
 
 pub mod max_voter_weight_record;
 
+pub use mint_config::*;
+pub mod mint_config;
+
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
@@ -1,7 +1,11 @@
+use crate::error::TokenHaverError;
+use crate::state::MintConfig;
 use crate::{id, tools::anchor::DISCRIMINATOR_SIZE};
 use anchor_lang::prelude::*;
-use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+pub use gpl_shared::compose::ChainingMode;
+use gpl_shared::compose::RegistrarBase;
 
 /// Enum defining collection item change type
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -28,12 +32,105 @@ pub struct Registrar {
     /// and the actual token of the mint is not used
     pub governing_token_mint: Pubkey,
 
+    /// Mints whose locked tokens granted voter weight in version 0 Registrars
+    ///
+    /// Note: Deprecated and no longer used, the mints are moved to mint_configs by MigrateRegistrar
+    /// and it's always empty for the current version. The field is kept to preserve the layout of version 0 Registrars
+    pub mints: Vec<Pubkey>,
+
+    /// Version of the Registrar account layout
+    pub version: u8,
+
+    /// Max voter weight (expressed in governing_token_mint decimal units) set by the Realm authority
+    /// When None the max voter weight is derived from the configured mints
+    pub max_voter_weight: Option<u64>,
+
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
+    /// Defines how the voter weight of the previous plugin is combined with the token holding voter weight
+    pub chaining_mode: ChainingMode,
+
+    /// Mints whose locked tokens grant voter weight
+    pub mint_configs: Vec<MintConfig>,
+}
+
+/// The current version of the Registrar account layout
+pub const CURRENT_REGISTRAR_VERSION: u8 = 1;
+
+/// Registrar account layout before the mints were configured using MintConfig (version 0)
+/// Version 0 Registrars can't be deserialized as Registrar and are read using this layout by migrate_registrar
+#[derive(AnchorDeserialize, Debug)]
+pub struct RegistrarV0 {
+    pub governance_program_id: Pubkey,
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
     pub mints: Vec<Pubkey>,
 }
 
 impl Registrar {
     pub fn get_space(max_mints: u8) -> usize {
-        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 3 + 4 + max_mints as usize * PUBKEY_BYTES
+        DISCRIMINATOR_SIZE
+            + PUBKEY_BYTES * 3
+            + 4
+            + 1
+            + 1
+            + 8
+            + (PUBKEY_BYTES + 1)
+            + 1
+            + 4
+            + max_mints as usize * MintConfig::SPACE
+    }
+
+    /// Returns the max voter weight used as the quorum denominator
+    /// If the Realm authority didn't set it explicitly then it's the weight of a voter holding all the configured mints
+    pub fn get_max_voter_weight(&self) -> Result<u64> {
+        match self.max_voter_weight {
+            Some(max_voter_weight) => Ok(max_voter_weight),
+            None => self
+                .mint_configs
+                .iter()
+                .try_fold(0u64, |total, mint_config| {
+                    total
+                        .checked_add(mint_config.get_max_voter_weight())
+                        .ok_or_else(|| TokenHaverError::VoterWeightOverflow.into())
+                }),
+        }
+    }
+
+    /// Returns the expiry of the max voter weight derived from the Registrar configuration
+    /// It never expires unless a previous voter weight plugin is configured
+    /// and the max voter weight must be updated using UpdateMaxVoterWeightRecord
+    pub fn get_max_voter_weight_expiry(&self) -> Option<u64> {
+        if self.previous_voter_weight_plugin_program_id.is_some() {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the configuration for the given mint
+    pub fn get_mint_config(&self, mint: &Pubkey) -> Option<&MintConfig> {
+        self.mint_configs.iter().find(|mc| mc.mint == *mint)
+    }
+}
+
+impl<'a> RegistrarBase<'a> for Registrar {
+    fn get_realm(&'a self) -> &'a Pubkey {
+        &self.realm
+    }
+
+    fn get_governance_program_id(&'a self) -> &'a Pubkey {
+        &self.governance_program_id
+    }
+
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_previous_voter_weight_plugin_program_id(&'a self) -> &'a Option<Pubkey> {
+        &self.previous_voter_weight_plugin_program_id
     }
 }
 
@@ -54,6 +151,7 @@ pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> P
 mod test {
 
     use super::*;
+    use crate::state::MintWeightMode;
 
     #[test]
     fn test_get_space() {
@@ -64,7 +162,20 @@ mod test {
             governance_program_id: Pubkey::default(),
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
-            mints: vec![Pubkey::default(), Pubkey::default(), Pubkey::default()],
+            mints: vec![],
+            version: CURRENT_REGISTRAR_VERSION,
+            max_voter_weight: Some(0),
+            previous_voter_weight_plugin_program_id: Some(Pubkey::default()),
+            chaining_mode: ChainingMode::Gate,
+            mint_configs: vec![
+                MintConfig {
+                    weight_mode: MintWeightMode::ProportionalUpToCap {
+                        max_voter_weight: 0,
+                    },
+                    ..Default::default()
+                };
+                3
+            ],
         };
 
         // Act
@@ -73,4 +184,74 @@ mod test {
         // Assert
         assert_eq!(expected_space, actual_space);
     }
+
+    #[test]
+    fn test_get_max_voter_weight() {
+        // Arrange
+        let mut registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            mints: vec![],
+            version: CURRENT_REGISTRAR_VERSION,
+            max_voter_weight: None,
+            previous_voter_weight_plugin_program_id: None,
+            chaining_mode: ChainingMode::Add,
+            mint_configs: vec![
+                MintConfig {
+                    voter_weight: 10,
+                    ..Default::default()
+                },
+                MintConfig {
+                    voter_weight: 1,
+                    weight_mode: MintWeightMode::ProportionalUpToCap {
+                        max_voter_weight: 100,
+                    },
+                    ..Default::default()
+                },
+            ],
+        };
+
+        // Act
+        let derived_max_voter_weight = registrar.get_max_voter_weight().unwrap();
+
+        registrar.max_voter_weight = Some(10);
+        let configured_max_voter_weight = registrar.get_max_voter_weight().unwrap();
+
+        // Assert
+        assert_eq!(derived_max_voter_weight, 110);
+        assert_eq!(configured_max_voter_weight, 10);
+    }
+
+    #[test]
+    fn test_deserialize_version_0_registrar() {
+        // Arrange
+        let mints = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            mints: mints.clone(),
+            version: 0,
+            max_voter_weight: None,
+            previous_voter_weight_plugin_program_id: None,
+            chaining_mode: ChainingMode::Add,
+            mint_configs: vec![],
+        };
+
+        // Version 0 layout ends with the mints
+        let mut registrar_data = registrar.try_to_vec().unwrap();
+        registrar_data.truncate(registrar_data.len() - (1 + 1 + 1 + 1 + 4));
+
+        // Act
+        let registrar_v0 = RegistrarV0::deserialize(&mut &registrar_data[..]).unwrap();
+        let registrar_current = Registrar::deserialize(&mut &registrar_data[..]);
+
+        // Assert
+        assert_eq!(registrar_v0.realm, registrar.realm);
+        assert_eq!(registrar_v0.mints, mints);
+
+        assert!(registrar_current.is_err());
+    }
 }
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
 
 use crate::tools::anchor::DISCRIMINATOR_SIZE;
+use gpl_shared::compose::VoterWeightRecordBase;
 
 /// VoterWeightAction enum as defined in spl-governance-addin-api
 /// It's redefined here for Anchor to export it to IDL
@@ -75,6 +76,30 @@ impl VoterWeightRecord {
     }
 }
 
+impl<'a> VoterWeightRecordBase<'a> for VoterWeightRecord {
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_governing_token_owner(&'a self) -> &'a Pubkey {
+        &self.governing_token_owner
+    }
+}
+
+impl From<spl_governance_addin_api::voter_weight::VoterWeightAction> for VoterWeightAction {
+    fn from(action: spl_governance_addin_api::voter_weight::VoterWeightAction) -> Self {
+        use spl_governance_addin_api::voter_weight::VoterWeightAction as AddinVoterWeightAction;
+
+        match action {
+            AddinVoterWeightAction::CastVote => VoterWeightAction::CastVote,
+            AddinVoterWeightAction::CommentProposal => VoterWeightAction::CommentProposal,
+            AddinVoterWeightAction::CreateGovernance => VoterWeightAction::CreateGovernance,
+            AddinVoterWeightAction::CreateProposal => VoterWeightAction::CreateProposal,
+            AddinVoterWeightAction::SignOffProposal => VoterWeightAction::SignOffProposal,
+        }
+    }
+}
+
 impl Default for VoterWeightRecord {
     fn default() -> Self {
         Self {
@@ -0,0 +1,299 @@
+//! General purpose SPL token utility functions
+
+use {
+    crate::{error::TokenHaverError, state::MintLockPolicy},
+    anchor_lang::prelude::*,
+    spl_token_2022::extension::{
+        non_transferable::NonTransferableAccount, BaseStateWithExtensions, StateWithExtensions,
+    },
+    spl_token_2022::state::Account,
+};
+
+/// Returns true if the given Token-2022 account has the NonTransferableAccount extension
+/// spl-token accounts can't have extensions and are never non-transferable
+pub fn is_non_transferable_token_account(token_account_info: &AccountInfo) -> Result<bool> {
+    if *token_account_info.owner != spl_token_2022::id() {
+        return Ok(false);
+    }
+
+    let data = token_account_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<Account>::unpack(&data)?;
+
+    Ok(token_account
+        .get_extension::<NonTransferableAccount>()
+        .is_ok())
+}
+
+/// Asserts the given token account is locked as required by the mint's lock policy
+pub fn assert_token_account_locked(
+    lock_policy: MintLockPolicy,
+    is_frozen: bool,
+    token_account_info: &AccountInfo,
+) -> Result<()> {
+    let is_locked = match lock_policy {
+        MintLockPolicy::Frozen => is_frozen,
+        MintLockPolicy::FrozenOrNonTransferable => {
+            is_frozen || is_non_transferable_token_account(token_account_info)?
+        }
+        MintLockPolicy::NotRequired => true,
+    };
+
+    require!(is_locked, TokenHaverError::TokenAccountNotLocked);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use spl_token_2022::{
+        extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+        state::AccountState,
+    };
+
+    fn create_token_account_data(extension_types: &[ExtensionType]) -> Vec<u8> {
+        let account = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+
+        if extension_types.is_empty() {
+            let mut data = vec![0; Account::LEN];
+            Account::pack(account, &mut data).unwrap();
+            return data;
+        }
+
+        let account_len =
+            ExtensionType::try_calculate_account_len::<Account>(extension_types).unwrap();
+        let mut data = vec![0; account_len];
+
+        let mut token_account =
+            StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+
+        for extension_type in extension_types {
+            match extension_type {
+                ExtensionType::NonTransferableAccount => {
+                    token_account
+                        .init_extension::<NonTransferableAccount>(true)
+                        .unwrap();
+                }
+                _ => panic!("{:?} is not supported", extension_type),
+            }
+        }
+
+        token_account.base = account;
+        token_account.pack_base();
+        token_account.init_account_type().unwrap();
+
+        data
+    }
+
+    fn assert_token_account_not_locked_err(result: Result<()>) {
+        assert_eq!(
+            result.err().unwrap(),
+            TokenHaverError::TokenAccountNotLocked.into()
+        );
+    }
+
+    #[test]
+    fn test_is_non_transferable_token_account_with_spl_token_account() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let owner = spl_token::id();
+        let mut lamports = 0;
+        let mut data = create_token_account_data(&[]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let is_non_transferable = is_non_transferable_token_account(&token_account_info).unwrap();
+
+        // Assert
+        assert!(!is_non_transferable);
+    }
+
+    #[test]
+    fn test_is_non_transferable_token_account_with_token_2022_account() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let owner = spl_token_2022::id();
+        let mut lamports = 0;
+        let mut data = create_token_account_data(&[]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let is_non_transferable = is_non_transferable_token_account(&token_account_info).unwrap();
+
+        // Assert
+        assert!(!is_non_transferable);
+    }
+
+    #[test]
+    fn test_is_non_transferable_token_account_with_non_transferable_token_2022_account() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let owner = spl_token_2022::id();
+        let mut lamports = 0;
+        let mut data = create_token_account_data(&[ExtensionType::NonTransferableAccount]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let is_non_transferable = is_non_transferable_token_account(&token_account_info).unwrap();
+
+        // Assert
+        assert!(is_non_transferable);
+    }
+
+    #[test]
+    fn test_assert_token_account_locked_with_frozen_policy() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let owner = spl_token_2022::id();
+        let mut lamports = 0;
+        let mut data = create_token_account_data(&[ExtensionType::NonTransferableAccount]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let frozen_result =
+            assert_token_account_locked(MintLockPolicy::Frozen, true, &token_account_info);
+
+        // Non transferable accounts are not locked unless frozen
+        let not_frozen_result =
+            assert_token_account_locked(MintLockPolicy::Frozen, false, &token_account_info);
+
+        // Assert
+        assert!(frozen_result.is_ok());
+        assert_token_account_not_locked_err(not_frozen_result);
+    }
+
+    #[test]
+    fn test_assert_token_account_locked_with_frozen_or_non_transferable_policy() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let spl_token_owner = spl_token::id();
+        let token_2022_owner = spl_token_2022::id();
+        let mut lamports = 0;
+        let mut non_transferable_lamports = 0;
+        let mut data = create_token_account_data(&[]);
+        let mut non_transferable_data =
+            create_token_account_data(&[ExtensionType::NonTransferableAccount]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &spl_token_owner,
+            false,
+            0,
+        );
+
+        let non_transferable_token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut non_transferable_lamports,
+            &mut non_transferable_data,
+            &token_2022_owner,
+            false,
+            0,
+        );
+
+        // Act
+        let frozen_result = assert_token_account_locked(
+            MintLockPolicy::FrozenOrNonTransferable,
+            true,
+            &token_account_info,
+        );
+
+        let non_transferable_result = assert_token_account_locked(
+            MintLockPolicy::FrozenOrNonTransferable,
+            false,
+            &non_transferable_token_account_info,
+        );
+
+        let not_locked_result = assert_token_account_locked(
+            MintLockPolicy::FrozenOrNonTransferable,
+            false,
+            &token_account_info,
+        );
+
+        // Assert
+        assert!(frozen_result.is_ok());
+        assert!(non_transferable_result.is_ok());
+        assert_token_account_not_locked_err(not_locked_result);
+    }
+
+    #[test]
+    fn test_assert_token_account_locked_with_not_required_policy() {
+        // Arrange
+        let key = Pubkey::new_unique();
+        let owner = spl_token::id();
+        let mut lamports = 0;
+        let mut data = create_token_account_data(&[]);
+
+        let token_account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // Act
+        let result =
+            assert_token_account_locked(MintLockPolicy::NotRequired, false, &token_account_info);
+
+        // Assert
+        // TokenAccountNotLocked is never returned when the lock is not required
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,225 @@
+use gpl_token_haver::{
+    error::TokenHaverError,
+    state::{MintConfig, MintWeightMode},
+};
+use program_test::token_haver_test::TokenHaverTest;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+mod program_test;
+use program_test::tools::{assert_anchor_err, assert_token_haver_err};
+
+#[tokio::test]
+async fn test_configure_mints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let mint_configs = vec![
+        MintConfig {
+            mint: Pubkey::new_unique(),
+            voter_weight: 10,
+            ..Default::default()
+        },
+        MintConfig {
+            mint: Pubkey::new_unique(),
+            voter_weight: 2,
+            weight_mode: MintWeightMode::ProportionalUpToCap {
+                max_voter_weight: 100,
+            },
+            ..Default::default()
+        },
+    ];
+
+    // Act
+    token_haver_test
+        .configure_mints(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            mint_configs.clone(),
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs, mint_configs);
+
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 110);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mints_with_fewer_mints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_config1 = MintConfig {
+        mint: Pubkey::new_unique(),
+        voter_weight: 10,
+        ..Default::default()
+    };
+
+    let mint_config2 = MintConfig {
+        mint: Pubkey::new_unique(),
+        voter_weight: 20,
+        ..Default::default()
+    };
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(&realm_cookie, vec![mint_config1, mint_config2])
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .configure_mints(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            vec![mint_config2],
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.mint_configs, vec![mint_config2]);
+
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mints_with_duplicated_mint_config_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let mint_config = MintConfig {
+        mint: Pubkey::new_unique(),
+        voter_weight: 10,
+        ..Default::default()
+    };
+
+    // Act
+    let err = token_haver_test
+        .configure_mints(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            vec![mint_config, mint_config],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::DuplicatedMintConfig);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mints_with_invalid_realm_authority_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    registrar_cookie.realm_authority = Keypair::new();
+
+    // Act
+    let err = token_haver_test
+        .configure_mints(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            vec![MintConfig {
+                mint: Pubkey::new_unique(),
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_mints_with_realm_authority_must_sign_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .configure_mints_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            vec![MintConfig {
+                mint: Pubkey::new_unique(),
+                voter_weight: 10,
+                ..Default::default()
+            }],
+            |i| i.accounts[3].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
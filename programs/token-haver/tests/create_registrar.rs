@@ -2,26 +2,26 @@ mod program_test;
 
 use anchor_lang::prelude::{ErrorCode, Pubkey};
 use gpl_token_haver::error::TokenHaverError;
-use program_test::token_haver_test::RealmVoterTest;
+use program_test::token_haver_test::TokenHaverTest;
 
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, transport::TransportError};
 
-use program_test::tools::{assert_anchor_err, assert_ix_err, assert_realm_voter_err};
+use program_test::tools::{assert_anchor_err, assert_ix_err, assert_token_haver_err};
 
 #[tokio::test]
 async fn test_create_registrar() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
     // Act
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
 
     // Assert
-    let registrar = realm_voter_test
+    let registrar = token_haver_test
         .get_registrar_account(&registrar_cookie.address)
         .await;
 
@@ -33,19 +33,19 @@ async fn test_create_registrar() -> Result<(), TransportError> {
 #[tokio::test]
 async fn test_create_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let mut realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let mut realm_cookie = token_haver_test.governance.with_realm().await?;
     realm_cookie.realm_authority = Keypair::new();
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar(&realm_cookie)
         .await
         .err()
         .unwrap();
 
-    assert_realm_voter_err(err, TokenHaverError::InvalidRealmAuthority);
+    assert_token_haver_err(err, TokenHaverError::InvalidRealmAuthority);
 
     Ok(())
 }
@@ -54,14 +54,15 @@ async fn test_create_registrar_with_invalid_realm_authority_error() -> Result<()
 async fn test_create_registrar_with_realm_authority_must_sign_error() -> Result<(), TransportError>
 {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar_using_ix(
             &realm_cookie,
+            vec![],
             |i| i.accounts[4].is_signer = false, // realm_authority
             Some(&[]),
         )
@@ -78,17 +79,18 @@ async fn test_create_registrar_with_realm_authority_must_sign_error() -> Result<
 async fn test_create_registrar_with_invalid_spl_gov_program_id_error() -> Result<(), TransportError>
 {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
     // Try to use a different program id
-    let governance_program_id = realm_voter_test.program_id;
+    let governance_program_id = token_haver_test.program_id;
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar_using_ix(
             &realm_cookie,
+            vec![],
             |i| i.accounts[1].pubkey = governance_program_id, //governance_program_id
             None,
         )
@@ -104,14 +106,15 @@ async fn test_create_registrar_with_invalid_spl_gov_program_id_error() -> Result
 #[tokio::test]
 async fn test_create_registrar_with_invalid_realm_error() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar_using_ix(
             &realm_cookie,
+            vec![],
             |i| i.accounts[2].pubkey = Pubkey::new_unique(), // realm
             None,
         )
@@ -129,16 +132,17 @@ async fn test_create_registrar_with_invalid_realm_error() -> Result<(), Transpor
 async fn test_create_registrar_with_invalid_governing_token_mint_error(
 ) -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
-    let mint_cookie = realm_voter_test.bench.with_mint().await?;
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar_using_ix(
             &realm_cookie,
+            vec![],
             |i| i.accounts[3].pubkey = mint_cookie.address, // governing_token_mint
             None,
         )
@@ -155,17 +159,17 @@ async fn test_create_registrar_with_invalid_governing_token_mint_error(
 #[tokio::test]
 async fn test_create_registrar_with_registrar_already_exists_error() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
-    realm_voter_test.with_registrar(&realm_cookie).await?;
+    token_haver_test.with_registrar(&realm_cookie).await?;
 
-    realm_voter_test.bench.advance_clock().await;
+    token_haver_test.bench.advance_clock().await;
 
     // Act
 
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_registrar(&realm_cookie)
         .await
         .err()
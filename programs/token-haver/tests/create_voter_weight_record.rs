@@ -1,4 +1,4 @@
-use crate::program_test::token_haver_test::RealmVoterTest;
+use crate::program_test::token_haver_test::TokenHaverTest;
 use program_test::tools::assert_ix_err;
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
@@ -9,22 +9,22 @@ mod program_test;
 #[tokio::test]
 async fn test_create_voter_weight_record() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
 
-    let voter_cookie = realm_voter_test.bench.with_wallet().await;
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
 
     // Act
-    let voter_weight_record_cookie = realm_voter_test
+    let voter_weight_record_cookie = token_haver_test
         .with_voter_weight_record(&registrar_cookie, &voter_cookie)
         .await?;
 
     // Assert
 
-    let voter_weight_record = realm_voter_test
+    let voter_weight_record = token_haver_test
         .get_voter_weight_record(&voter_weight_record_cookie.address)
         .await;
 
@@ -36,22 +36,22 @@ async fn test_create_voter_weight_record() -> Result<(), TransportError> {
 #[tokio::test]
 async fn test_create_voter_weight_record_with_already_exists_error() -> Result<(), TransportError> {
     // Arrange
-    let mut realm_voter_test = RealmVoterTest::start_new().await;
+    let mut token_haver_test = TokenHaverTest::start_new().await;
 
-    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
 
-    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
 
-    let voter_cookie = realm_voter_test.bench.with_wallet().await;
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
 
-    realm_voter_test
+    token_haver_test
         .with_voter_weight_record(&registrar_cookie, &voter_cookie)
         .await?;
 
-    realm_voter_test.bench.advance_clock().await;
+    token_haver_test.bench.advance_clock().await;
 
     // Act
-    let err = realm_voter_test
+    let err = token_haver_test
         .with_voter_weight_record(&registrar_cookie, &voter_cookie)
         .await
         .err()
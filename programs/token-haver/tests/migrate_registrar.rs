@@ -0,0 +1,138 @@
+use gpl_token_haver::{
+    error::TokenHaverError,
+    state::{MintConfig, CURRENT_REGISTRAR_VERSION, VERSION_0_MINT_VOTER_WEIGHT},
+};
+use program_test::token_haver_test::TokenHaverTest;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+mod program_test;
+use program_test::tools::assert_token_haver_err;
+
+#[tokio::test]
+async fn test_migrate_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mints = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    token_haver_test
+        .with_registrar_version_0(&registrar_cookie, mints.clone())
+        .await?;
+
+    // Act
+    token_haver_test
+        .migrate_registrar(&registrar_cookie)
+        .await?;
+
+    // Assert
+    let registrar = token_haver_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.version, CURRENT_REGISTRAR_VERSION);
+    assert_eq!(registrar.mints, vec![]);
+    assert_eq!(
+        registrar.mint_configs,
+        mints
+            .into_iter()
+            .map(MintConfig::from_version_0_mint)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(registrar.realm, registrar_cookie.account.realm);
+    assert_eq!(
+        registrar.governing_token_mint,
+        registrar_cookie.account.governing_token_mint
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_registrar_with_registrar_already_migrated_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = token_haver_test
+        .migrate_registrar(&registrar_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::RegistrarAlreadyMigrated);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_migrated_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let mint_cookie1 = token_haver_test.bench.with_mint().await?;
+    let mint_cookie2 = token_haver_test.bench.with_mint().await?;
+
+    token_haver_test
+        .with_registrar_version_0(
+            &registrar_cookie,
+            vec![mint_cookie1.address, mint_cookie2.address],
+        )
+        .await?;
+
+    token_haver_test
+        .migrate_registrar(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie1 = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie1, &voter_cookie.address, 1)
+        .await?;
+
+    let token_account_cookie2 = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie2, &voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie1.address, token_account_cookie2.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // Version 0 Registrars granted the same weight for each mint regardless of the balance
+    assert_eq!(
+        voter_weight_record.voter_weight,
+        2 * VERSION_0_MINT_VOTER_WEIGHT
+    );
+
+    Ok(())
+}
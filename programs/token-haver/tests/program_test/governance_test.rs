@@ -117,6 +117,8 @@ impl GovernanceTest {
             realm_name.clone(),
             min_community_weight_to_create_governance,
             community_mint_max_voter_weight_source.clone(),
+            false,
+            false,
         );
 
         self.bench
@@ -198,6 +200,7 @@ impl GovernanceTest {
             &self.bench.payer.pubkey(),
             1,
             &governing_token_mint,
+            false,
         );
 
         self.bench.process_transaction(&[deposit_ix], None).await?;
@@ -362,6 +365,66 @@ impl GovernanceTest {
         })
     }
 
+    /// Deposits the given amount of council tokens for the token owner which creates the TokenOwnerRecord
+    #[allow(dead_code)]
+    pub async fn with_council_token_deposit(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        token_owner_cookie: &WalletCookie,
+        amount: u64,
+    ) -> Result<TokenOwnerRecordCookie, TransportError> {
+        let council_mint_cookie = realm_cookie.council_mint_cookie.as_ref().unwrap();
+        let governing_token_mint = council_mint_cookie.address;
+
+        let governing_token_account_cookie = self
+            .bench
+            .with_tokens(council_mint_cookie, &token_owner_cookie.address, amount)
+            .await?;
+
+        let token_owner_record_key = get_token_owner_record_address(
+            &self.program_id,
+            &realm_cookie.address,
+            &governing_token_mint,
+            &token_owner_cookie.address,
+        );
+
+        let deposit_ix = deposit_governing_tokens(
+            &self.program_id,
+            &realm_cookie.address,
+            &governing_token_account_cookie.address,
+            &token_owner_cookie.address,
+            &token_owner_cookie.address,
+            &self.bench.payer.pubkey(),
+            amount,
+            &governing_token_mint,
+            false,
+        );
+
+        self.bench
+            .process_transaction(&[deposit_ix], Some(&[&token_owner_cookie.signer]))
+            .await?;
+
+        let account = TokenOwnerRecordV2 {
+            account_type: GovernanceAccountType::TokenOwnerRecordV2,
+            realm: realm_cookie.address,
+            governing_token_mint,
+            governing_token_owner: token_owner_cookie.address,
+            governing_token_deposit_amount: amount,
+            unrelinquished_votes_count: 0,
+
+            outstanding_proposal_count: 0,
+            reserved: [0; 6],
+            governance_delegate: None,
+            reserved_v2: [0; 128],
+            version: TOKEN_OWNER_RECORD_LAYOUT_VERSION,
+        };
+
+        Ok(TokenOwnerRecordCookie {
+            address: token_owner_record_key,
+            account,
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn relinquish_vote(
         &mut self,
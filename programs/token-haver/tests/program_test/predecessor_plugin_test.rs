@@ -0,0 +1,129 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+
+use solana_program_test::{BanksClientError, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_governance_addin_api::voter_weight::VoterWeightAction;
+use spl_governance_addin_mock::instruction::*;
+
+use crate::program_test::{
+    governance_test::RealmCookie,
+    program_test_bench::{ProgramTestBench, WalletCookie},
+};
+
+pub struct PredecessorVoterWeightRecordCookie {
+    pub address: Pubkey,
+}
+
+pub struct PredecessorMaxVoterWeightRecordCookie {
+    pub address: Pubkey,
+}
+
+/// Voter weight plugin used as the previous plugin in the chain of plugins
+pub struct PredecessorPluginTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl PredecessorPluginTest {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("GovAddinMock1111111111111111111111111111111").unwrap()
+    }
+
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("spl_governance_addin_mock", Self::program_id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        PredecessorPluginTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+    ) -> Result<PredecessorVoterWeightRecordCookie, BanksClientError> {
+        self.with_voter_weight_record_for_action(
+            realm_cookie,
+            voter_cookie,
+            voter_weight,
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_for_action(
+        &self,
+        realm_cookie: &RealmCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight: u64,
+        voter_weight_expiry: Option<u64>,
+        weight_action: Option<VoterWeightAction>,
+        weight_action_target: Option<Pubkey>,
+    ) -> Result<PredecessorVoterWeightRecordCookie, BanksClientError> {
+        let voter_weight_record_account = Keypair::new();
+
+        let setup_voter_weight_record_ix = setup_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &voter_cookie.address,
+            &voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            voter_weight,
+            voter_weight_expiry,
+            weight_action,
+            weight_action_target,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_voter_weight_record_ix],
+                Some(&[&voter_weight_record_account]),
+            )
+            .await?;
+
+        Ok(PredecessorVoterWeightRecordCookie {
+            address: voter_weight_record_account.pubkey(),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &self,
+        realm_cookie: &RealmCookie,
+        max_voter_weight: u64,
+        max_voter_weight_expiry: Option<u64>,
+    ) -> Result<PredecessorMaxVoterWeightRecordCookie, BanksClientError> {
+        let max_voter_weight_record_account = Keypair::new();
+
+        let setup_max_voter_weight_record_ix = setup_max_voter_weight_record(
+            &Self::program_id(),
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &max_voter_weight_record_account.pubkey(),
+            &self.bench.payer.pubkey(),
+            max_voter_weight,
+            max_voter_weight_expiry,
+        );
+
+        self.bench
+            .process_transaction(
+                &[setup_max_voter_weight_record_ix],
+                Some(&[&max_voter_weight_record_account]),
+            )
+            .await?;
+
+        Ok(PredecessorMaxVoterWeightRecordCookie {
+            address: max_voter_weight_record_account.pubkey(),
+        })
+    }
+}
@@ -5,7 +5,7 @@ use anchor_lang::{
     AccountDeserialize,
 };
 
-use solana_program::{borsh::try_from_slice_unchecked, system_program};
+use solana_program::system_program;
 use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     account::{Account, ReadableAccount},
@@ -18,8 +18,6 @@ use solana_sdk::{
     transport::TransportError,
 };
 
-use borsh::BorshDeserialize;
-
 use crate::program_test::tools::clone_keypair;
 
 pub struct MintCookie {
@@ -113,8 +111,12 @@ impl ProgramTestBench {
         let mint_authority = Keypair::new();
         let freeze_authority = Keypair::new();
 
-        self.create_mint(&mint_keypair, &mint_authority.pubkey(), None)
-            .await?;
+        self.create_mint(
+            &mint_keypair,
+            &mint_authority.pubkey(),
+            Some(&freeze_authority.pubkey()),
+        )
+        .await?;
 
         Ok(MintCookie {
             address: mint_keypair.pubkey(),
@@ -193,6 +195,21 @@ impl ProgramTestBench {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn with_frozen_tokens(
+        &self,
+        mint_cookie: &MintCookie,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Result<TokenAccountCookie, TransportError> {
+        let token_account_cookie = self.with_tokens(mint_cookie, owner, amount).await?;
+
+        self.freeze_token_account(mint_cookie, &token_account_cookie.address)
+            .await?;
+
+        Ok(token_account_cookie)
+    }
+
     pub async fn mint_tokens(
         &self,
         token_mint: &Pubkey,
@@ -214,6 +231,27 @@ impl ProgramTestBench {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn freeze_token_account(
+        &self,
+        mint_cookie: &MintCookie,
+        token_account: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let freeze_authority = mint_cookie.freeze_authority.as_ref().unwrap();
+
+        let freeze_instruction = spl_token::instruction::freeze_account(
+            &spl_token::id(),
+            token_account,
+            &mint_cookie.address,
+            &freeze_authority.pubkey(),
+            &[],
+        )
+        .unwrap();
+
+        self.process_transaction(&[freeze_instruction], Some(&[freeze_authority]))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn create_token_account(
         &self,
@@ -284,6 +322,13 @@ impl ProgramTestBench {
         }
     }
 
+    #[allow(dead_code)]
+    pub fn set_account(&self, address: &Pubkey, account: &Account) {
+        self.context
+            .borrow_mut()
+            .set_account(address, &account.clone().into());
+    }
+
     #[allow(dead_code)]
     pub async fn get_account(&self, address: &Pubkey) -> Option<Account> {
         self.context
@@ -295,10 +340,10 @@ impl ProgramTestBench {
     }
 
     #[allow(dead_code)]
-    pub async fn get_borsh_account<T: BorshDeserialize>(&self, address: &Pubkey) -> T {
+    pub async fn get_borsh_account<T: borsh_1::BorshDeserialize>(&self, address: &Pubkey) -> T {
         self.get_account(address)
             .await
-            .map(|a| try_from_slice_unchecked(&a.data).unwrap())
+            .map(|a| borsh_1::BorshDeserialize::deserialize(&mut a.data.as_slice()).unwrap())
             .unwrap_or_else(|| panic!("GET-TEST-ACCOUNT-ERROR: Account {} not found", address))
     }
 
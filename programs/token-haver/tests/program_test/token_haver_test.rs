@@ -0,0 +1,586 @@
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+
+use gpl_token_haver::state::max_voter_weight_record::{
+    get_max_voter_weight_record_address, MaxVoterWeightRecord,
+};
+use gpl_token_haver::state::*;
+
+use solana_program_test::{BanksClientError, ProgramTest};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::governance_test::GovernanceTest;
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
+use crate::program_test::program_test_bench::ProgramTestBench;
+
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::WalletCookie;
+
+use crate::program_test::tools::NopOverride;
+
+#[derive(Debug, PartialEq)]
+pub struct RegistrarCookie {
+    pub address: Pubkey,
+    pub account: Registrar,
+
+    pub realm_authority: Keypair,
+}
+
+pub struct VoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: VoterWeightRecord,
+}
+
+pub struct MaxVoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: MaxVoterWeightRecord,
+}
+
+pub struct TokenHaverTest {
+    pub program_id: Pubkey,
+    pub bench: Arc<ProgramTestBench>,
+    pub governance: GovernanceTest,
+    pub predecessor_plugin: PredecessorPluginTest,
+}
+
+impl TokenHaverTest {
+    #[allow(dead_code)]
+    pub fn add_program(program_test: &mut ProgramTest) {
+        program_test.add_program("gpl_token_haver", gpl_token_haver::id(), None);
+    }
+
+    #[allow(dead_code)]
+    pub async fn start_new() -> Self {
+        let mut program_test = ProgramTest::default();
+
+        TokenHaverTest::add_program(&mut program_test);
+        GovernanceTest::add_program(&mut program_test);
+        PredecessorPluginTest::add_program(&mut program_test);
+
+        let program_id = gpl_token_haver::id();
+
+        let bench = ProgramTestBench::start_new(program_test).await;
+        let bench_rc = Arc::new(bench);
+
+        let governance_bench =
+            GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
+
+        let predecessor_plugin = PredecessorPluginTest::new(bench_rc.clone());
+
+        Self {
+            program_id,
+            bench: bench_rc,
+            governance: governance_bench,
+            predecessor_plugin,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar(
+        &mut self,
+        realm_cookie: &RealmCookie,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        self.with_registrar_with_mints(realm_cookie, vec![]).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_with_mints(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        mints: Vec<MintConfig>,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        self.with_registrar_using_ix(realm_cookie, mints, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        mints: Vec<MintConfig>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        let registrar_key =
+            get_registrar_address(&realm_cookie.address, &realm_cookie.account.community_mint);
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::CreateRegistrar {
+                mints: mints.clone(),
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_token_haver::accounts::CreateRegistrar {
+                registrar: registrar_key,
+                realm: realm_cookie.address,
+                governance_program_id: self.governance.program_id,
+                governing_token_mint: realm_cookie.account.community_mint,
+                realm_authority: realm_cookie.get_realm_authority().pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let mut create_registrar_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut create_registrar_ix);
+
+        let default_signers = &[&realm_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_registrar_ix], Some(signers))
+            .await?;
+
+        let account = Registrar {
+            governance_program_id: self.governance.program_id,
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            mints: vec![],
+            version: CURRENT_REGISTRAR_VERSION,
+            max_voter_weight: None,
+            previous_voter_weight_plugin_program_id: None,
+            chaining_mode: ChainingMode::Add,
+            mint_configs: mints,
+        };
+
+        Ok(RegistrarCookie {
+            address: registrar_key,
+            account,
+            realm_authority: realm_cookie.get_realm_authority(),
+        })
+    }
+
+    /// Replaces the Registrar account with the version 0 layout storing the given mints
+    #[allow(dead_code)]
+    pub async fn with_registrar_version_0(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mints: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let registrar = Registrar {
+            mints,
+            version: 0,
+            max_voter_weight: None,
+            previous_voter_weight_plugin_program_id: None,
+            chaining_mode: ChainingMode::Add,
+            mint_configs: vec![],
+            ..self.get_registrar_account(&registrar_cookie.address).await
+        };
+
+        let mut data = vec![];
+        anchor_lang::AccountSerialize::try_serialize(&registrar, &mut data).unwrap();
+
+        // Version 0 layout ends with the mints
+        data.truncate(data.len() - (1 + 1 + 1 + 1 + 4));
+
+        let mut account = self
+            .bench
+            .get_account(&registrar_cookie.address)
+            .await
+            .unwrap();
+
+        account.lamports = self.bench.rent.minimum_balance(data.len());
+        account.data = data;
+
+        self.bench.set_account(&registrar_cookie.address, &account);
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_registrar(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        self.migrate_registrar_using_ix(registrar_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::MigrateRegistrar {});
+
+        let accounts = gpl_token_haver::accounts::MigrateRegistrar {
+            registrar: registrar_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut migrate_registrar_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut migrate_registrar_ix);
+
+        self.bench
+            .process_transaction(&[migrate_registrar_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<VoterWeightRecordCookie, BanksClientError> {
+        self.with_voter_weight_record_using_ix(registrar_cookie, voter_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        instruction_override: F,
+    ) -> Result<VoterWeightRecordCookie, BanksClientError> {
+        let governing_token_owner = voter_cookie.address;
+
+        let (voter_weight_record_key, _) = Pubkey::find_program_address(
+            &[
+                b"voter-weight-record".as_ref(),
+                registrar_cookie.account.realm.as_ref(),
+                registrar_cookie.account.governing_token_mint.as_ref(),
+                governing_token_owner.as_ref(),
+            ],
+            &gpl_token_haver::id(),
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::CreateVoterWeightRecord {
+                governing_token_owner,
+            },
+        );
+
+        let accounts = gpl_token_haver::accounts::CreateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_voter_weight_record_ix], None)
+            .await?;
+
+        let account = VoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            governing_token_owner,
+            voter_weight: 0,
+            voter_weight_expiry: Some(0),
+            weight_action: None,
+            weight_action_target: None,
+            reserved: [0; 8],
+        };
+
+        Ok(VoterWeightRecordCookie {
+            address: voter_weight_record_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        self.with_max_voter_weight_record_using_ix(registrar_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        let max_voter_weight_record_key = get_max_voter_weight_record_address(
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::CreateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::CreateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_max_voter_weight_record_ix], None)
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            max_voter_weight: registrar_cookie.account.get_max_voter_weight().unwrap(),
+            max_voter_weight_expiry: None,
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            account,
+            address: max_voter_weight_record_key,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_mints(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        mints: Vec<MintConfig>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_mints_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            mints,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_mints_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        mints: Vec<MintConfig>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_haver::instruction::ConfigureMints {
+                mints,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_token_haver::accounts::ConfigureMints {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                payer: self.bench.payer.pubkey(),
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+                max_voter_weight_record: max_voter_weight_record_cookie.address,
+            },
+            None,
+        );
+
+        let mut configure_mints_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut configure_mints_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_mints_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_accounts: &[Pubkey],
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_with_accounts(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            token_accounts,
+            None,
+        )
+        .await
+    }
+
+    /// Updates VoterWeightRecord using the token accounts as remaining accounts
+    /// The previous VoterWeightRecord, if provided, is passed ahead of the token accounts
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_accounts(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_accounts: &[Pubkey],
+        previous_voter_weight_record: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        if let Some(previous_voter_weight_record) = previous_voter_weight_record {
+            account_metas.push(AccountMeta::new_readonly(
+                previous_voter_weight_record,
+                false,
+            ));
+        }
+
+        for token_account in token_accounts {
+            account_metas.push(AccountMeta::new_readonly(*token_account, false));
+        }
+
+        let instructions = vec![Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_previous_voter_weight_plugin_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            previous_voter_weight_plugin_program_id,
+            chaining_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_previous_voter_weight_plugin_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        chaining_mode: ChainingMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::ConfigurePreviousVoterWeightPlugin {
+                previous_voter_weight_plugin_program_id,
+                chaining_mode,
+            },
+        );
+
+        let accounts = gpl_token_haver::accounts::ConfigurePreviousVoterWeightPlugin {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_previous_voter_weight_plugin_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_previous_voter_weight_plugin_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_previous_voter_weight_plugin_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        previous_max_voter_weight_record: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            previous_max_voter_weight_record,
+        };
+
+        let update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
+        self.bench.get_anchor_account::<Registrar>(*registrar).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &Pubkey,
+    ) -> MaxVoterWeightRecord {
+        self.bench
+            .get_anchor_account(*max_voter_weight_record)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_record(&self, voter_weight_record: &Pubkey) -> VoterWeightRecord {
+        self.bench.get_anchor_account(*voter_weight_record).await
+    }
+}
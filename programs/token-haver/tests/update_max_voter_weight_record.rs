@@ -0,0 +1,202 @@
+use gpl_token_haver::error::TokenHaverError;
+use gpl_token_haver::state::{ChainingMode, MintConfig};
+use program_test::predecessor_plugin_test::PredecessorPluginTest;
+use program_test::token_haver_test::TokenHaverTest;
+use program_test::tools::*;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: Pubkey::new_unique(),
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_plugin_and_add_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: Pubkey::new_unique(),
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let previous_max_voter_weight_record_cookie = token_haver_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 1000, None)
+        .await?;
+
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(previous_max_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1010);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_plugin_and_gate_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: Pubkey::new_unique(),
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    let previous_max_voter_weight_record_cookie = token_haver_test
+        .predecessor_plugin
+        .with_max_voter_weight_record(&realm_cookie, 1000, None)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(previous_max_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = token_haver_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // Gating doesn't grant any additional voter weight
+    assert_eq!(max_voter_weight_record.max_voter_weight, 1000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_previous_max_voter_weight_record_missing_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_haver_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::PreviousMaxVoterWeightRecordMissing);
+
+    Ok(())
+}
@@ -0,0 +1,715 @@
+use crate::program_test::predecessor_plugin_test::PredecessorPluginTest;
+use crate::program_test::token_haver_test::TokenHaverTest;
+use gpl_token_haver::{
+    error::TokenHaverError,
+    state::{ChainingMode, MintConfig, MintLockPolicy, MintWeightMode},
+};
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_multiple_mints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie1 = token_haver_test.bench.with_mint().await?;
+    let mint_cookie2 = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![
+                MintConfig {
+                    mint: mint_cookie1.address,
+                    voter_weight: 10,
+                    ..Default::default()
+                },
+                MintConfig {
+                    mint: mint_cookie2.address,
+                    voter_weight: 2,
+                    weight_mode: MintWeightMode::ProportionalUpToCap {
+                        max_voter_weight: 100,
+                    },
+                    ..Default::default()
+                },
+            ],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie1 = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie1, &voter_cookie.address, 1)
+        .await?;
+
+    let token_account_cookie2 = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie2, &voter_cookie.address, 30)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie1.address, token_account_cookie2.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // 10 for holding mint1 and 2 per token of mint2
+    assert_eq!(voter_weight_record.voter_weight, 10 + 2 * 30);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_proportional_weight_capped(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 2,
+                weight_mode: MintWeightMode::ProportionalUpToCap {
+                    max_voter_weight: 100,
+                },
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 1_000)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_balance_below_min_balance(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                min_balance: 5,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 4)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_account_not_locked_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                lock_policy: MintLockPolicy::Frozen,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::TokenAccountNotLocked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_lock_not_required() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                lock_policy: MintLockPolicy::NotRequired,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_tokens(&mint_cookie, &voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_account_wrong_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+    let other_mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&other_mint_cookie, &voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::TokenAccountWrongMint);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_account_wrong_owner_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+    let other_voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &other_voter_cookie.address, 100)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::TokenAccountWrongOwner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_add_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                min_balance: 5,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 10)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = token_haver_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 110);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_gate_mode(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                min_balance: 5,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 10)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = token_haver_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_plugin_and_gate_mode_without_token_weight(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                min_balance: 5,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Gate,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let token_account_cookie = token_haver_test
+        .bench
+        .with_frozen_tokens(&mint_cookie, &voter_cookie.address, 4)
+        .await?;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let previous_voter_weight_record_cookie = token_haver_test
+        .predecessor_plugin
+        .with_voter_weight_record(&realm_cookie, &voter_cookie, 100)
+        .await?;
+
+    let clock = token_haver_test.bench.get_clock().await;
+
+    // Act
+    token_haver_test
+        .update_voter_weight_record_with_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[token_account_cookie.address],
+            Some(previous_voter_weight_record_cookie.address),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_haver_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The balance is below min_balance and hence the voter weight of the previous plugin is gated
+    assert_eq!(voter_weight_record.voter_weight, 0);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_record_missing_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_haver_test = TokenHaverTest::start_new().await;
+
+    let realm_cookie = token_haver_test.governance.with_realm().await?;
+
+    let mint_cookie = token_haver_test.bench.with_mint().await?;
+
+    let registrar_cookie = token_haver_test
+        .with_registrar_with_mints(
+            &realm_cookie,
+            vec![MintConfig {
+                mint: mint_cookie.address,
+                voter_weight: 10,
+                ..Default::default()
+            }],
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = token_haver_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_haver_test
+        .configure_previous_voter_weight_plugin(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(PredecessorPluginTest::program_id()),
+            ChainingMode::Add,
+        )
+        .await?;
+
+    let voter_cookie = token_haver_test.bench.with_wallet().await;
+
+    let mut voter_weight_record_cookie = token_haver_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    // Act
+    let err = token_haver_test
+        .update_voter_weight_record(&registrar_cookie, &mut voter_weight_record_cookie, &[])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_haver_err(err, TokenHaverError::PreviousVoterWeightRecordMissing);
+
+    Ok(())
+}
@@ -102,4 +102,34 @@ pub enum TokenVoterError {
 
     #[msg("Inactive Deposit Index!")]
     DepositIndexInactive,
+
+    #[msg("Cannot migrate a Voter to the same Registrar")]
+    MigrationToSameRegistrar,
+
+    #[msg("Checkpoints capacity must be greater than zero")]
+    InvalidCheckpointsCapacity,
+
+    #[msg("No voter weight checkpoint found for the slot")]
+    CheckpointNotFound,
+
+    #[msg("Invalid Delegate")]
+    InvalidDelegate,
+
+    #[msg("Invalid Delegation weight")]
+    InvalidDelegationWeight,
+
+    #[msg("Deposit weight is delegated")]
+    InsufficientUndelegatedWeight,
+
+    #[msg("Voter has active delegations")]
+    ActiveDelegations,
+
+    #[msg("Voting mint configs changed after the voter weight checkpoint")]
+    CheckpointVotingMintConfigsChanged,
+
+    #[msg("Invalid VoterWeightCheckpoints for Voter")]
+    InvalidVoterWeightCheckpoints,
+
+    #[msg("Delegate must relinquish its votes and finalise its proposals before undelegation")]
+    DelegateVotesNotRelinquished,
 }
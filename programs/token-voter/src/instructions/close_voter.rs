@@ -55,6 +55,7 @@ pub fn close_voter<'info>(ctx: Context<'_, '_, '_, 'info, CloseVoter<'info>>) ->
         sum.checked_add(d.amount_deposited_native).unwrap()
     });
     require_eq!(amount, 0, TokenVoterError::VotingTokenNonZero);
+    require!(!voter.has_delegations(), TokenVoterError::ActiveDelegations);
     let voter_authority_key = voter_authority.key();
     let voter_seeds = voter_seeds!(voter, voter_authority_key);
 
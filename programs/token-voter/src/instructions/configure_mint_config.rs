@@ -77,6 +77,12 @@ pub fn configure_mint_config(
         .position(|vmc| vmc.mint == mint.key());
 
     if let Some(mint_config_idx) = mint_config_idx {
+        // The weight of every voter holding the mint changes with its config
+        // and because it's not checkpointed lookups across the change are rejected
+        if registrar.voting_mint_configs[mint_config_idx].digit_shift != digit_shift {
+            registrar.voting_mint_configs_updated_slot = Clock::get()?.slot;
+        }
+
         registrar.voting_mint_configs[mint_config_idx] = voting_mint_config;
     } else {
         // Note: In the current runtime version push() would throw an error if we exceed
@@ -0,0 +1,55 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+/// Creates VoterWeightCheckpoints for a Voter to keep the history of its voter weight
+/// This instruction should only be executed once per voter to create the account
+#[derive(Accounts)]
+#[instruction(capacity: u16)]
+pub struct CreateVoterWeightCheckpoints<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar)]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        init,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+        payer = voter_authority,
+        space = VoterWeightCheckpoints::get_space(capacity),
+    )]
+    pub voter_weight_checkpoints: Box<Account<'info, VoterWeightCheckpoints>>,
+
+    #[account(mut)]
+    pub voter_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the checkpoints ring buffer and records the current voter weight as the first checkpoint
+///
+/// `capacity`: Max number of checkpoints kept before the oldest ones are overwritten.
+pub fn create_voter_weight_checkpoints(
+    ctx: Context<CreateVoterWeightCheckpoints>,
+    capacity: u16,
+) -> Result<()> {
+    require_gt!(capacity, 0, TokenVoterError::InvalidCheckpointsCapacity);
+
+    let registrar = &ctx.accounts.registrar;
+    let voter = &ctx.accounts.voter;
+    let voter_weight_checkpoints = &mut ctx.accounts.voter_weight_checkpoints;
+
+    voter_weight_checkpoints.voter = voter.key();
+    voter_weight_checkpoints.capacity = capacity;
+    voter_weight_checkpoints.next_index = 0;
+    voter_weight_checkpoints.checkpoints = vec![];
+
+    voter_weight_checkpoints.record(Clock::get()?.slot, voter.voting_weight(registrar)?);
+
+    Ok(())
+}
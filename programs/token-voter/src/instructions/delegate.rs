@@ -0,0 +1,174 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+};
+
+/// Delegates a deposit amount of a voter to another voter of the same Registrar
+/// The weight of the delegated amount is added to the delegate's VoterWeightRecord and removed from the delegator's one
+#[derive(Accounts)]
+pub struct Delegate<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar)]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_weight_record_bump,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the voter
+    /// It must be provided even if the voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+    )]
+    pub voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    /// The token_owner_record for the voter_authority. This is needed
+    /// to forbid delegating weight which was already used to vote.
+    ///
+    /// CHECK: token_owner_record is validated in the instruction:
+    /// - owned by registrar.governance_program_id
+    /// - for the registrar.realm
+    /// - for the registrar.realm_governing_token_mint
+    /// - governing_token_owner is voter_authority
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump = delegate_voter.voter_bump,
+        has_one = registrar,
+        constraint = delegate_voter.key() != voter.key() @ TokenVoterError::InvalidDelegate,
+    )]
+    pub delegate_voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump = delegate_voter.voter_weight_record_bump,
+    )]
+    pub delegate_voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the delegate voter
+    /// It must be provided even if the delegate voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump,
+    )]
+    pub delegate_voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    /// Mint of the deposit to delegate
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [registrar.key().as_ref(), b"delegation".as_ref(), voter_authority.key().as_ref(), delegate_voter.voter_authority.as_ref(), mint.key().as_ref()],
+        bump,
+        payer = voter_authority,
+        space = Delegation::get_space(),
+    )]
+    pub delegation: Box<Account<'info, Delegation>>,
+
+    #[account(mut)]
+    pub voter_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Delegates the given deposit amount to the delegate voter
+/// Delegating the same mint to the same delegate again increases the existing Delegation
+///
+/// `amount`: The deposit amount to delegate, in native currency.
+/// It can't exceed the undelegated amount of the voter's deposit for the mint.
+pub fn delegate(ctx: Context<Delegate>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, TokenVoterError::InvalidDelegationWeight);
+
+    let registrar = &ctx.accounts.registrar;
+    let voter_authority_key = ctx.accounts.voter_authority.key();
+    let mint_key = ctx.accounts.mint.key();
+
+    let mint_idx = registrar.voting_mint_config_index(mint_key)?;
+
+    // Weight used in an active vote can't be delegated to be used again by the delegate
+    let voter = &mut ctx.accounts.voter;
+    let token_owner_record = voter.load_token_owner_record(
+        &ctx.accounts.token_owner_record.to_account_info(),
+        registrar,
+        &voter_authority_key,
+    )?;
+    token_owner_record.assert_can_withdraw_governing_tokens()?;
+
+    let deposit_entry = voter.active_deposit_mut(mint_idx as u8)?;
+
+    require_gte!(
+        deposit_entry.amount_undelegated_native(),
+        amount,
+        TokenVoterError::InsufficientUndelegatedWeight
+    );
+
+    deposit_entry.amount_delegated_native = deposit_entry
+        .amount_delegated_native
+        .checked_add(amount)
+        .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+
+    let delegate_voter = &mut ctx.accounts.delegate_voter;
+    let delegate_deposit_entry = &mut delegate_voter.deposits[mint_idx];
+
+    delegate_deposit_entry.amount_received_native = delegate_deposit_entry
+        .amount_received_native
+        .checked_add(amount)
+        .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+    delegate_deposit_entry.voting_mint_config_idx = mint_idx as u8;
+
+    let delegation = &mut ctx.accounts.delegation;
+
+    // Setup the Delegation when it's created
+    if delegation.registrar == Pubkey::default() {
+        delegation.registrar = registrar.key();
+        delegation.delegator = voter_authority_key;
+        delegation.delegate = delegate_voter.voter_authority;
+        delegation.mint = mint_key;
+        delegation.bump = ctx.bumps.delegation;
+    }
+
+    delegation.amount_native = delegation
+        .amount_native
+        .checked_add(amount)
+        .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter.voting_weight(registrar)?;
+    voter_weight_record.voter_weight_expiry = None;
+
+    let delegate_voter_weight_record = &mut ctx.accounts.delegate_voter_weight_record;
+    delegate_voter_weight_record.voter_weight = delegate_voter.voting_weight(registrar)?;
+    delegate_voter_weight_record.voter_weight_expiry = None;
+
+    let current_slot = Clock::get()?.slot;
+
+    record_voter_weight_checkpoint(
+        &ctx.accounts.voter_weight_checkpoints,
+        current_slot,
+        voter_weight_record.voter_weight,
+    )?;
+    record_voter_weight_checkpoint(
+        &ctx.accounts.delegate_voter_weight_checkpoints,
+        current_slot,
+        delegate_voter_weight_record.voter_weight,
+    )?;
+
+    Ok(())
+}
@@ -45,6 +45,16 @@ pub struct Deposit<'info> {
     )]
     pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
 
+    /// VoterWeightCheckpoints of the voter
+    /// It must be provided even if the voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), deposit_authority.key().as_ref()],
+        bump,
+    )]
+    pub voter_weight_checkpoints: UncheckedAccount<'info>,
+
     /// TokenOwnerRecord for any of the configured spl-governance instances
     /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
     pub token_owner_record: UncheckedAccount<'info>,
@@ -148,14 +158,12 @@ pub fn deposit<'info>(
             d_entry.is_used = true;
         }
         None => {
-            let deposit_entry = DepositEntry {
-                deposit_slot_hash: current_slot_hash,
-                amount_deposited_native: deposit_amount,
-                voting_mint_config_idx: mint_idx as u8,
-                is_used: true,
-                reserved: [0; 38],
-            };
-            voter.deposits[mint_idx] = deposit_entry;
+            // The entry can already hold amounts delegated to the voter which must be preserved
+            let d_entry = &mut voter.deposits[mint_idx];
+            d_entry.deposit_slot_hash = current_slot_hash;
+            d_entry.amount_deposited_native = deposit_amount;
+            d_entry.voting_mint_config_idx = mint_idx as u8;
+            d_entry.is_used = true;
         }
     }
 
@@ -176,7 +184,13 @@ pub fn deposit<'info>(
     );
 
     // Setup voter_weight
-    voter_weight_record.voter_weight = voter.weight(registrar)?;
+    voter_weight_record.voter_weight = voter.voting_weight(registrar)?;
+
+    record_voter_weight_checkpoint(
+        &ctx.accounts.voter_weight_checkpoints,
+        current_slot_hash,
+        voter_weight_record.voter_weight,
+    )?;
 
     // Voter Weight Expiry is always set to None after a deposit
     // since no other action other than deposit and withdraw could invalidate it
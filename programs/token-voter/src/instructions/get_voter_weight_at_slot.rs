@@ -0,0 +1,35 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+/// Returns the voter weight recorded in VoterWeightCheckpoints as of the given slot
+/// The instruction is read only and the weight is returned as the instruction return data
+/// Note: Changes to the voting mint configs aren't checkpointed and lookups across them are rejected
+#[derive(Accounts)]
+pub struct GetVoterWeightAtSlot<'info> {
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(has_one = registrar)]
+    pub voter: Account<'info, Voter>,
+
+    #[account(
+        constraint = voter_weight_checkpoints.voter == voter.key() @ TokenVoterError::InvalidVoterWeightCheckpoints,
+    )]
+    pub voter_weight_checkpoints: Account<'info, VoterWeightCheckpoints>,
+}
+
+/// `slot`: The slot to return the voter weight for.
+pub fn get_voter_weight_at_slot(ctx: Context<GetVoterWeightAtSlot>, slot: u64) -> Result<u64> {
+    let voter_weight = ctx
+        .accounts
+        .voter_weight_checkpoints
+        .weight_at_with_configs_updated_slot(
+            slot,
+            ctx.accounts.registrar.voting_mint_configs_updated_slot,
+        )?;
+
+    msg!("VOTER_WEIGHT:{:?} SLOT:{:?}", voter_weight, slot);
+
+    Ok(voter_weight)
+}
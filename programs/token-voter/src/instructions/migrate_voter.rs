@@ -0,0 +1,298 @@
+use {
+    crate::{
+        error::*,
+        state::*,
+        tools::spl_token::{get_current_mint_fee, transfer_spl_tokens_signed_checked},
+        ID,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{Mint, TokenAccount, TokenInterface},
+    },
+    spl_governance::state::realm,
+};
+
+/// Migrates a deposit of a Voter from the source Registrar to a Voter of the same voter_authority
+/// under the target Registrar
+///
+/// It's used when a Realm replaces its governing token mint or moves to another spl-governance instance
+/// and both Realm authorities must sign the transaction
+#[derive(Accounts)]
+pub struct MigrateVoter<'info> {
+    /// The Registrar the deposits are migrated from
+    pub source_registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+       owner = source_registrar.governance_program_id,
+       constraint = source_realm.key() == source_registrar.realm @ TokenVoterError::InvalidRealmForRegistrar,
+    )]
+    /// CHECK: Owned by spl-governance instance specified in source_registrar.governance_program_id
+    pub source_realm: UncheckedAccount<'info>,
+
+    /// Authority of the source Realm must sign the transaction and must match source_realm.authority
+    pub source_realm_authority: Signer<'info>,
+
+    /// The Registrar the deposits are migrated to
+    #[account(
+        constraint = target_registrar.key() != source_registrar.key()
+        @ TokenVoterError::MigrationToSameRegistrar,
+    )]
+    pub target_registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+       owner = target_registrar.governance_program_id,
+       constraint = target_realm.key() == target_registrar.realm @ TokenVoterError::InvalidRealmForRegistrar,
+    )]
+    /// CHECK: Owned by spl-governance instance specified in target_registrar.governance_program_id
+    pub target_realm: UncheckedAccount<'info>,
+
+    /// Authority of the target Realm must sign the transaction and must match target_realm.authority
+    pub target_realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [source_registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = source_voter.voter_bump,
+        constraint = source_voter.registrar == source_registrar.key(),
+    )]
+    pub source_voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [source_registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = source_voter.voter_weight_record_bump,
+    )]
+    pub source_voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the source voter
+    /// It must be provided even if the source voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [source_registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+    )]
+    pub source_voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    /// The token_owner_record for the voter_authority in the source Realm. This is needed
+    /// to forbid migrations while the voter is engaged with a vote or has an open proposal.
+    ///
+    /// CHECK: token_owner_record is validated in the instruction:
+    /// - owned by source_registrar.governance_program_id
+    /// - for the source_registrar.realm
+    /// - for the source_registrar.governing_token_mint
+    /// - governing_token_owner is voter_authority
+    pub source_token_owner_record: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [target_registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump,
+        payer = payer,
+        space = Voter::get_space(target_registrar.max_mints),
+    )]
+    pub target_voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [target_registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump,
+        payer = payer,
+        space = VoterWeightRecord::get_space()
+    )]
+    pub target_voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the target voter
+    /// It must be provided even if the target voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [target_registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+    )]
+    pub target_voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    /// The owner of the migrated deposits
+    /// CHECK: Validated by the source_voter seeds
+    pub voter_authority: UncheckedAccount<'info>,
+
+    /// Tokens of this mint must be included in the Voting Mint Configs of both Registrars
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::authority = source_voter,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    pub source_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::authority = target_voter,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+        payer = payer
+    )]
+    pub target_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Moves a deposit entry and its vault tokens from the source Voter to the target Voter.
+///
+/// The target Voter and VoterWeightRecord are created on the first migrated deposit.
+/// The deposit is placed at the index of the mint in the target Registrar so the deposits
+/// stay aligned with the target voting_mint_configs.
+///
+/// `deposit_entry_index`: The deposit entry of the source Voter to migrate.
+pub fn migrate_voter<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateVoter<'info>>,
+    deposit_entry_index: u8,
+) -> Result<()> {
+    let source_registrar = &ctx.accounts.source_registrar;
+    let target_registrar = &ctx.accounts.target_registrar;
+
+    // Both Realms must authorize the migration
+    // Note: The governing_token_mint isn't asserted because the Realm could have already replaced it
+    for (registrar, realm_info, realm_authority) in [
+        (
+            source_registrar,
+            &ctx.accounts.source_realm,
+            &ctx.accounts.source_realm_authority,
+        ),
+        (
+            target_registrar,
+            &ctx.accounts.target_realm,
+            &ctx.accounts.target_realm_authority,
+        ),
+    ] {
+        let realm = realm::get_realm_data(&registrar.governance_program_id, realm_info)?;
+
+        require!(
+            realm.authority == Some(realm_authority.key()),
+            TokenVoterError::InvalidRealmAuthority
+        );
+    }
+
+    let voter_authority_key = &ctx.accounts.voter_authority.key();
+    let mint_key = ctx.accounts.mint.key();
+
+    // Governance may forbid migrations, for example when engaged in a vote.
+    let source_voter = &ctx.accounts.source_voter;
+    let token_owner_record = source_voter.load_token_owner_record(
+        &ctx.accounts.source_token_owner_record.to_account_info(),
+        source_registrar,
+        voter_authority_key,
+    )?;
+    token_owner_record.assert_can_withdraw_governing_tokens()?;
+
+    let source_mint_idx = source_registrar.voting_mint_config_index(mint_key)?;
+    let target_mint_idx = target_registrar.voting_mint_config_index(mint_key)?;
+
+    let source_voter = &mut ctx.accounts.source_voter;
+    let source_deposit = source_voter.active_deposit_mut(deposit_entry_index)?;
+
+    require_eq!(
+        source_mint_idx,
+        source_deposit.voting_mint_config_idx as usize,
+        TokenVoterError::MintNotFound
+    );
+
+    // Deposits delegated to other voters must be undelegated before they can be migrated
+    require_eq!(
+        source_deposit.amount_delegated_native,
+        0,
+        TokenVoterError::InsufficientUndelegatedWeight
+    );
+
+    let amount = source_deposit.amount_deposited_native;
+    let deposit_slot_hash = source_deposit.deposit_slot_hash;
+
+    source_deposit.amount_deposited_native = 0;
+    source_deposit.is_used = false;
+
+    {
+        transfer_spl_tokens_signed_checked(
+            &ctx.accounts.source_vault.to_account_info(),
+            &ctx.accounts.target_vault.to_account_info(),
+            &source_voter.to_account_info(),
+            voter_seeds_no_seeds!(source_voter, voter_authority_key),
+            &ID,
+            amount,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    let migrated_amount = amount
+        .checked_sub(get_current_mint_fee(
+            &ctx.accounts.mint.to_account_info(),
+            amount,
+        )?)
+        .ok_or(TokenVoterError::TokenAmountOverflow)?;
+
+    let target_voter = &mut ctx.accounts.target_voter;
+
+    // Setup the target Voter when it's created by the migration
+    if target_voter.registrar == Pubkey::default() {
+        target_voter.voter_bump = ctx.bumps.target_voter;
+        target_voter.voter_weight_record_bump = ctx.bumps.target_voter_weight_record;
+        target_voter.voter_authority = *voter_authority_key;
+        target_voter.registrar = target_registrar.key();
+        target_voter.deposits = DepositEntry::init_deposits(target_registrar.max_mints as usize);
+    }
+
+    let target_deposit = &mut target_voter.deposits[target_mint_idx];
+
+    target_deposit.amount_deposited_native = target_deposit
+        .amount_deposited_native
+        .checked_add(migrated_amount)
+        .ok_or(TokenVoterError::TokenAmountOverflow)?;
+    target_deposit.voting_mint_config_idx = target_mint_idx as u8;
+    // Keep the most recent deposit slot to preserve the withdrawal restriction
+    target_deposit.deposit_slot_hash = target_deposit.deposit_slot_hash.max(deposit_slot_hash);
+    target_deposit.is_used = true;
+
+    let target_voter_weight_record = &mut ctx.accounts.target_voter_weight_record;
+
+    // Setup the target VoterWeightRecord when it's created by the migration
+    if target_voter_weight_record.realm == Pubkey::default() {
+        target_voter_weight_record.account_discriminator =
+            spl_governance_addin_api::voter_weight::VoterWeightRecord::ACCOUNT_DISCRIMINATOR;
+        target_voter_weight_record.realm = target_registrar.realm;
+        target_voter_weight_record.governing_token_mint = target_registrar.governing_token_mint;
+        target_voter_weight_record.governing_token_owner = *voter_authority_key;
+    }
+
+    target_voter_weight_record.voter_weight = target_voter.voting_weight(target_registrar)?;
+    target_voter_weight_record.voter_weight_expiry = None;
+    target_voter_weight_record.weight_action = None;
+    target_voter_weight_record.weight_action_target = None;
+
+    let source_voter_weight_record = &mut ctx.accounts.source_voter_weight_record;
+    source_voter_weight_record.voter_weight = source_voter.voting_weight(source_registrar)?;
+    source_voter_weight_record.voter_weight_expiry = None;
+
+    let current_slot = Clock::get()?.slot;
+
+    record_voter_weight_checkpoint(
+        &ctx.accounts.source_voter_weight_checkpoints,
+        current_slot,
+        source_voter_weight_record.voter_weight,
+    )?;
+    record_voter_weight_checkpoint(
+        &ctx.accounts.target_voter_weight_checkpoints,
+        current_slot,
+        target_voter_weight_record.voter_weight,
+    )?;
+
+    Ok(())
+}
@@ -21,3 +21,21 @@ mod withdraw;
 
 pub use deposit::*;
 mod deposit;
+
+pub use update_voter_weight_record::*;
+mod update_voter_weight_record;
+
+pub use migrate_voter::*;
+mod migrate_voter;
+
+pub use create_voter_weight_checkpoints::*;
+mod create_voter_weight_checkpoints;
+
+pub use get_voter_weight_at_slot::*;
+mod get_voter_weight_at_slot;
+
+pub use delegate::*;
+mod delegate;
+
+pub use undelegate::*;
+mod undelegate;
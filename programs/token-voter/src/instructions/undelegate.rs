@@ -0,0 +1,184 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+};
+
+/// Returns a delegated deposit amount from the delegate voter back to the delegator
+#[derive(Accounts)]
+pub struct Undelegate<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar)]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_weight_record_bump,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the voter
+    /// It must be provided even if the voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+    )]
+    pub voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump = delegate_voter.voter_bump,
+        has_one = registrar)]
+    pub delegate_voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump = delegate_voter.voter_weight_record_bump,
+    )]
+    pub delegate_voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// VoterWeightCheckpoints of the delegate voter
+    /// It must be provided even if the delegate voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), delegate_voter.voter_authority.as_ref()],
+        bump,
+    )]
+    pub delegate_voter_weight_checkpoints: UncheckedAccount<'info>,
+
+    /// The token_owner_record for the voter_authority. This is needed to forbid undelegating
+    /// while the delegator is engaged with a vote or has an open proposal.
+    ///
+    /// CHECK: token_owner_record is validated in the instruction:
+    /// - owned by registrar.governance_program_id
+    /// - for the registrar.realm
+    /// - for the registrar.realm_governing_token_mint
+    /// - governing_token_owner is voter_authority
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    /// The token_owner_record for the delegate_voter.voter_authority. This is needed to forbid undelegating
+    /// while the delegate is engaged with a vote or has an open proposal using the delegated weight.
+    ///
+    /// CHECK: delegate_token_owner_record is validated in the instruction:
+    /// - owned by registrar.governance_program_id
+    /// - for the registrar.realm
+    /// - for the registrar.realm_governing_token_mint
+    /// - governing_token_owner is delegate_voter.voter_authority
+    pub delegate_token_owner_record: UncheckedAccount<'info>,
+
+    /// Mint of the delegated deposit
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"delegation".as_ref(), voter_authority.key().as_ref(), delegate_voter.voter_authority.as_ref(), mint.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Box<Account<'info, Delegation>>,
+
+    #[account(mut)]
+    pub voter_authority: Signer<'info>,
+}
+
+/// Undelegates the given deposit amount from the delegate voter
+///
+/// Votes the delegate cast keep the weight they were cast with, hence the delegate must relinquish
+/// all its votes and finalise its proposals before the weight can be undelegated.
+/// Otherwise the same deposit could be delegated to another voter and used to vote twice on the same proposal.
+/// The Delegation account is closed once all its amount is undelegated.
+///
+/// `amount`: The deposit amount to undelegate, in native currency. It can't exceed the Delegation amount.
+pub fn undelegate(ctx: Context<Undelegate>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, TokenVoterError::InvalidDelegationWeight);
+
+    let registrar = &ctx.accounts.registrar;
+    let voter_authority_key = ctx.accounts.voter_authority.key();
+
+    let mint_idx = registrar.voting_mint_config_index(ctx.accounts.mint.key())?;
+
+    let voter = &mut ctx.accounts.voter;
+    let token_owner_record = voter.load_token_owner_record(
+        &ctx.accounts.token_owner_record.to_account_info(),
+        registrar,
+        &voter_authority_key,
+    )?;
+    token_owner_record.assert_can_withdraw_governing_tokens()?;
+
+    let delegate_voter = &ctx.accounts.delegate_voter;
+    let delegate_token_owner_record = delegate_voter.load_token_owner_record(
+        &ctx.accounts.delegate_token_owner_record.to_account_info(),
+        registrar,
+        &delegate_voter.voter_authority,
+    )?;
+
+    // The delegated weight is only released once the delegate is no longer voting with it
+    require!(
+        delegate_token_owner_record.unrelinquished_votes_count == 0
+            && delegate_token_owner_record.outstanding_proposal_count == 0,
+        TokenVoterError::DelegateVotesNotRelinquished
+    );
+
+    let delegation = &mut ctx.accounts.delegation;
+
+    require_gte!(
+        delegation.amount_native,
+        amount,
+        TokenVoterError::InvalidDelegationWeight
+    );
+
+    delegation.amount_native = delegation
+        .amount_native
+        .checked_sub(amount)
+        .ok_or(TokenVoterError::TokenAmountOverflow)?;
+
+    let deposit_entry = &mut voter.deposits[mint_idx];
+    deposit_entry.amount_delegated_native = deposit_entry
+        .amount_delegated_native
+        .checked_sub(amount)
+        .ok_or(TokenVoterError::TokenAmountOverflow)?;
+
+    let delegate_voter = &mut ctx.accounts.delegate_voter;
+    let delegate_deposit_entry = &mut delegate_voter.deposits[mint_idx];
+    delegate_deposit_entry.amount_received_native = delegate_deposit_entry
+        .amount_received_native
+        .checked_sub(amount)
+        .ok_or(TokenVoterError::TokenAmountOverflow)?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter.voting_weight(registrar)?;
+    voter_weight_record.voter_weight_expiry = None;
+
+    let delegate_voter_weight_record = &mut ctx.accounts.delegate_voter_weight_record;
+    delegate_voter_weight_record.voter_weight = delegate_voter.voting_weight(registrar)?;
+    delegate_voter_weight_record.voter_weight_expiry = None;
+
+    let current_slot = Clock::get()?.slot;
+
+    record_voter_weight_checkpoint(
+        &ctx.accounts.voter_weight_checkpoints,
+        current_slot,
+        voter_weight_record.voter_weight,
+    )?;
+    record_voter_weight_checkpoint(
+        &ctx.accounts.delegate_voter_weight_checkpoints,
+        current_slot,
+        delegate_voter_weight_record.voter_weight,
+    )?;
+
+    if delegation.amount_native == 0 {
+        delegation.close(ctx.accounts.voter_authority.to_account_info())?;
+    }
+
+    Ok(())
+}
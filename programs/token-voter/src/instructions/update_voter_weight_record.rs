@@ -0,0 +1,58 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+/// Refreshes the VoterWeightRecord from the Voter deposits without moving any tokens
+/// The resulting weight is scoped to the given action and target and is only valid for the current slot
+/// hence the instruction has to be executed inside the same transaction as the corresponding spl-gov instruction
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter.voter_authority.as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar)]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter.voter_authority.as_ref()],
+        bump = voter.voter_weight_record_bump,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+}
+
+/// Recomputes the voter weight for the given action and target
+///
+/// The weight is derived from the Voter deposits using the current mint configs of the Registrar.
+/// The instruction is permissionless because the weight is fully determined by on-chain state.
+///
+/// `action`: The governance action the weight is evaluated for.
+/// `target`: The target of the action, None if the weight is valid for any target.
+pub fn update_voter_weight_record(
+    ctx: Context<UpdateVoterWeightRecord>,
+    action: VoterWeightAction,
+    target: Option<Pubkey>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter = &ctx.accounts.voter;
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    voter_weight_record.voter_weight = voter.voting_weight(registrar)?;
+
+    // The record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // The record is only valid for the given action and target
+    voter_weight_record.weight_action = Some(action.into());
+    voter_weight_record.weight_action_target = target;
+
+    Ok(())
+}
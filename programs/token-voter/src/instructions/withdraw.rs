@@ -50,6 +50,16 @@ pub struct Withdraw<'info> {
     )]
     pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
 
+    /// VoterWeightCheckpoints of the voter
+    /// It must be provided even if the voter hasn't created it, in which case no checkpoint is recorded
+    /// CHECK: The PDA address is validated by the seeds and the account is deserialized in the instruction if it exists
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-checkpoints".as_ref(), voter_authority.key().as_ref()],
+        bump,
+    )]
+    pub voter_weight_checkpoints: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::authority = voter,
@@ -134,6 +144,13 @@ pub fn withdraw<'info>(
         .checked_sub(amount)
         .unwrap();
 
+    // The deposit must still back the amount delegated to other voters
+    require_gte!(
+        deposit_entry.amount_deposited_native,
+        deposit_entry.amount_delegated_native,
+        TokenVoterError::InsufficientUndelegatedWeight
+    );
+
     if deposit_entry.amount_deposited_native == 0 {
         deposit_entry.is_used = false;
     }
@@ -151,10 +168,21 @@ pub fn withdraw<'info>(
 
     // Update the voter weight record
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
-    voter_weight_record.voter_weight = voter.weight(registrar)?;
+    voter_weight_record.voter_weight = voter.voting_weight(registrar)?;
+
+    record_voter_weight_checkpoint(
+        &ctx.accounts.voter_weight_checkpoints,
+        current_slot_hash,
+        voter_weight_record.voter_weight,
+    )?;
+
     // Voter Weight Expiry is always set to None after a deposit
     // since no other action other than deposit and withdraw could invalidate it
     voter_weight_record.voter_weight_expiry = None;
 
+    // Set action and target to None to indicate the weight is valid for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
     Ok(())
 }
@@ -6,6 +6,7 @@ mod instructions;
 use instructions::*;
 
 pub mod state;
+use state::VoterWeightAction;
 
 mod governance;
 pub mod tools;
@@ -65,6 +66,46 @@ pub mod token_voter {
         instructions::withdraw(ctx, deposit_entry_index, amount)
     }
 
+    pub fn create_voter_weight_checkpoints(
+        ctx: Context<CreateVoterWeightCheckpoints>,
+        capacity: u16,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_voter_weight_checkpoints(ctx, capacity)
+    }
+
+    pub fn get_voter_weight_at_slot(ctx: Context<GetVoterWeightAtSlot>, slot: u64) -> Result<u64> {
+        log_version();
+        instructions::get_voter_weight_at_slot(ctx, slot)
+    }
+
+    pub fn migrate_voter<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateVoter<'info>>,
+        deposit_entry_index: u8,
+    ) -> Result<()> {
+        log_version();
+        instructions::migrate_voter(ctx, deposit_entry_index)
+    }
+
+    pub fn delegate(ctx: Context<Delegate>, amount: u64) -> Result<()> {
+        log_version();
+        instructions::delegate(ctx, amount)
+    }
+
+    pub fn undelegate(ctx: Context<Undelegate>, amount: u64) -> Result<()> {
+        log_version();
+        instructions::undelegate(ctx, amount)
+    }
+
+    pub fn update_voter_weight_record(
+        ctx: Context<UpdateVoterWeightRecord>,
+        action: VoterWeightAction,
+        target: Option<Pubkey>,
+    ) -> Result<()> {
+        log_version();
+        instructions::update_voter_weight_record(ctx, action, target)
+    }
+
     pub fn close_voter<'info>(ctx: Context<'_, '_, '_, 'info, CloseVoter<'info>>) -> Result<()> {
         log_version();
         instructions::close_voter(ctx)
@@ -0,0 +1,95 @@
+use {
+    anchor_lang::{prelude::*, Discriminator},
+    anchor_lang::solana_program::pubkey::PUBKEY_BYTES,
+};
+
+/// Deposit amount of a mint delegated by a voter to another voter of the same Registrar
+/// The voter weight of the amount is computed at read time with the current VotingMintConfig
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct Delegation {
+    /// Registrar the delegation belongs to
+    pub registrar: Pubkey,
+
+    /// Voter authority who delegated the weight
+    pub delegator: Pubkey,
+
+    /// Voter authority the weight is delegated to
+    pub delegate: Pubkey,
+
+    /// Mint of the delegated deposit
+    pub mint: Pubkey,
+
+    /// Delegated deposit amount, in native currency
+    pub amount_native: u64,
+
+    /// Delegation account bump
+    pub bump: u8,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 63],
+}
+
+impl Delegation {
+    pub fn get_space() -> usize {
+        Delegation::DISCRIMINATOR.len() + PUBKEY_BYTES * 4 + 8 + 1 + 63
+    }
+}
+
+/// Returns Delegation PDA seeds
+pub fn get_delegation_seeds<'a>(
+    registrar: &'a Pubkey,
+    delegator: &'a Pubkey,
+    delegate: &'a Pubkey,
+    mint: &'a Pubkey,
+) -> [&'a [u8]; 5] {
+    [
+        registrar.as_ref(),
+        b"delegation",
+        delegator.as_ref(),
+        delegate.as_ref(),
+        mint.as_ref(),
+    ]
+}
+
+/// Returns Delegation PDA address
+pub fn get_delegation_address(
+    registrar: &Pubkey,
+    delegator: &Pubkey,
+    delegate: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_delegation_seeds(registrar, delegator, delegate, mint),
+        &crate::id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = Delegation::get_space();
+
+        let delegation = Delegation {
+            registrar: Pubkey::default(),
+            delegator: Pubkey::default(),
+            delegate: Pubkey::default(),
+            mint: Pubkey::default(),
+            amount_native: 0,
+            bump: 0,
+            reserved: [0; 63],
+        };
+
+        // Act
+        let actual_space = Delegation::DISCRIMINATOR.len() + delegation.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+}
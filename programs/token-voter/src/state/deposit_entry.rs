@@ -1,4 +1,7 @@
-use {crate::state::VotingMintConfig, anchor_lang::prelude::*};
+use {
+    crate::{error::TokenVoterError, state::VotingMintConfig},
+    anchor_lang::prelude::*,
+};
 
 /// Bookkeeping for a single deposit for a given mint.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
@@ -20,11 +23,18 @@ pub struct DepositEntry {
     // True if the deposit entry is being used.
     pub is_used: bool,
 
+    /// Amount of the deposit delegated to other voters, in native currency.
+    /// It can't exceed amount_deposited_native.
+    pub amount_delegated_native: u64,
+
+    /// Amount delegated to the voter by other voters, in native currency.
+    pub amount_received_native: u64,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 38],
+    pub reserved: [u8; 22],
 }
 
-const_assert!(std::mem::size_of::<DepositEntry>() == 8 + 1 + 8 + 1 + 38);
+const_assert!(std::mem::size_of::<DepositEntry>() == 8 + 1 + 8 + 1 + 8 + 8 + 22);
 const_assert!(std::mem::size_of::<DepositEntry>() % 8 == 0);
 
 impl DepositEntry {
@@ -35,7 +45,9 @@ impl DepositEntry {
             voting_mint_config_idx: 0,
             deposit_slot_hash: 0,
             is_used: false,
-            reserved: [0; 38],
+            amount_delegated_native: 0,
+            amount_received_native: 0,
+            reserved: [0; 22],
         }
     }
     /// Initializes a vector of DepositEntry with a given length
@@ -50,6 +62,23 @@ impl DepositEntry {
 
         Ok(vote_weight)
     }
+
+    /// The deposit amount which isn't delegated to other voters, in native currency.
+    pub fn amount_undelegated_native(&self) -> u64 {
+        self.amount_deposited_native
+            .saturating_sub(self.amount_delegated_native)
+    }
+
+    /// Voting power of the undelegated deposit and the amount delegated to the voter.
+    /// It's computed with the current mint config so config changes apply to delegated amounts too.
+    pub fn delegated_voting_power(&self, mint_config: &VotingMintConfig) -> Result<u64> {
+        let amount = self
+            .amount_undelegated_native()
+            .checked_add(self.amount_received_native)
+            .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+
+        mint_config.digit_shift_native(amount)
+    }
 }
 
 impl Default for DepositEntry {
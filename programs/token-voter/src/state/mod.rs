@@ -9,3 +9,12 @@ pub mod deposit_entry;
 
 pub use voter::*;
 pub mod voter;
+
+pub use voter_weight_action::*;
+pub mod voter_weight_action;
+
+pub use voter_weight_checkpoints::*;
+pub mod voter_weight_checkpoints;
+
+pub use delegation::*;
+pub mod delegation;
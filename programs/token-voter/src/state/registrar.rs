@@ -37,8 +37,12 @@ pub struct Registrar {
     /// Max mints that voters can create.
     pub max_mints: u8,
 
+    /// Slot at which the configuration of an existing voting mint was last changed
+    /// Voter weight checkpoints recorded before the slot don't reflect the change
+    pub voting_mint_configs_updated_slot: u64,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 119],
 }
 
 impl Registrar {
@@ -140,7 +144,8 @@ mod test {
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
             max_mints: 0,
-            reserved: [0; 127],
+            voting_mint_configs_updated_slot: 0,
+            reserved: [0; 119],
         };
 
         // Act
@@ -180,7 +185,8 @@ mod test {
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
             max_mints: 3,
-            reserved: [0; 127],
+            voting_mint_configs_updated_slot: 0,
+            reserved: [0; 119],
         };
 
         // Act & Assert - Initial state
@@ -51,6 +51,28 @@ impl Voter {
             })
     }
 
+    /// The vote weight of the voter including the amounts delegated to it
+    /// and excluding the amounts it delegated to other voters
+    pub fn voting_weight(&self, registrar: &Registrar) -> Result<u64> {
+        self.deposits
+            .iter()
+            .filter(|d| d.is_used || d.amount_received_native > 0)
+            .try_fold(0u64, |sum, d| {
+                let vp = d.delegated_voting_power(
+                    &registrar.voting_mint_configs[d.voting_mint_config_idx as usize],
+                )?;
+                sum.checked_add(vp)
+                    .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+            })
+    }
+
+    /// True if the voter delegated any deposit or received any delegation
+    pub fn has_delegations(&self) -> bool {
+        self.deposits
+            .iter()
+            .any(|d| d.amount_delegated_native > 0 || d.amount_received_native > 0)
+    }
+
     pub fn active_deposit_mut(&mut self, index: u8) -> Result<&mut DepositEntry> {
         let index = index as usize;
         require_gt!(
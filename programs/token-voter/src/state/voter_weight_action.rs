@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// VoterWeightAction enum as defined in spl-governance-addin-api
+/// It's redefined here for Anchor to export it to IDL
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VoterWeightAction {
+    /// Cast vote for a proposal. Target: Proposal
+    CastVote,
+
+    /// Comment a proposal. Target: Proposal
+    CommentProposal,
+
+    /// Create Governance within a realm. Target: Realm
+    CreateGovernance,
+
+    /// Create a proposal for a governance. Target: Governance
+    CreateProposal,
+
+    /// Signs off a proposal for a governance. Target: Proposal
+    /// Note: SignOffProposal is not supported in the current version
+    SignOffProposal,
+}
+
+impl From<VoterWeightAction> for spl_governance_addin_api::voter_weight::VoterWeightAction {
+    fn from(action: VoterWeightAction) -> Self {
+        match action {
+            VoterWeightAction::CastVote => Self::CastVote,
+            VoterWeightAction::CommentProposal => Self::CommentProposal,
+            VoterWeightAction::CreateGovernance => Self::CreateGovernance,
+            VoterWeightAction::CreateProposal => Self::CreateProposal,
+            VoterWeightAction::SignOffProposal => Self::SignOffProposal,
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use {
+    crate::error::TokenVoterError,
+    anchor_lang::solana_program::pubkey::PUBKEY_BYTES,
+    anchor_lang::{prelude::*, Discriminator},
+};
+
+/// Voter weight of a Voter as of the given slot
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Checkpoint {
+    /// Slot the weight was recorded at
+    pub slot: u64,
+
+    /// Voter weight as of the slot
+    pub weight: u64,
+}
+
+/// Optional ring buffer of voter weight checkpoints for a Voter
+/// Once the account is created a checkpoint is written by every instruction which changes the voter weight
+/// Note: Changes to the voting mint configs aren't checkpointed because they change the weight of all voters
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct VoterWeightCheckpoints {
+    /// Voter the checkpoints are recorded for
+    pub voter: Pubkey,
+
+    /// Max number of checkpoints kept before the oldest ones are overwritten
+    pub capacity: u16,
+
+    /// Index of the checkpoint which is written next once the buffer is full
+    pub next_index: u16,
+
+    /// Checkpoints ordered by slot, wrapped around next_index once the buffer is full
+    pub checkpoints: Vec<Checkpoint>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 64],
+}
+
+impl VoterWeightCheckpoints {
+    pub fn get_space(capacity: u16) -> usize {
+        VoterWeightCheckpoints::DISCRIMINATOR.len()
+            + PUBKEY_BYTES
+            + 2
+            + 2
+            + 4
+            + capacity as usize * 16
+            + 64
+    }
+
+    /// Records the voter weight for the given slot
+    /// A checkpoint for the same slot as the latest one replaces it
+    pub fn record(&mut self, slot: u64, weight: u64) {
+        let checkpoint = Checkpoint { slot, weight };
+
+        if let Some(latest) = self.latest_mut() {
+            if latest.slot == slot {
+                *latest = checkpoint;
+                return;
+            }
+        }
+
+        if self.checkpoints.len() < self.capacity as usize {
+            self.checkpoints.push(checkpoint);
+        } else {
+            self.checkpoints[self.next_index as usize] = checkpoint;
+            self.next_index = (self.next_index + 1) % self.capacity;
+        }
+    }
+
+    /// Returns the voter weight as of the given slot
+    /// throws an error if the slot precedes the oldest available checkpoint
+    pub fn weight_at(&self, slot: u64) -> Result<u64> {
+        self.checkpoint_at(slot).map(|c| c.weight)
+    }
+
+    /// Returns the voter weight as of the given slot
+    /// throws an error if the voting mint configs were changed between the checkpoint and the slot
+    /// because the weight recorded by the checkpoint doesn't reflect the change
+    pub fn weight_at_with_configs_updated_slot(
+        &self,
+        slot: u64,
+        voting_mint_configs_updated_slot: u64,
+    ) -> Result<u64> {
+        let checkpoint = self.checkpoint_at(slot)?;
+
+        // A change in the same slot as the checkpoint could have happened after it was recorded
+        require!(
+            voting_mint_configs_updated_slot < checkpoint.slot
+                || voting_mint_configs_updated_slot > slot,
+            TokenVoterError::CheckpointVotingMintConfigsChanged
+        );
+
+        Ok(checkpoint.weight)
+    }
+
+    /// Returns the latest checkpoint recorded at or before the given slot
+    fn checkpoint_at(&self, slot: u64) -> Result<&Checkpoint> {
+        self.iter_ordered()
+            .take_while(|c| c.slot <= slot)
+            .last()
+            .ok_or_else(|| error!(TokenVoterError::CheckpointNotFound))
+    }
+
+    /// Iterates the checkpoints from the oldest to the latest
+    fn iter_ordered(&self) -> impl Iterator<Item = &Checkpoint> {
+        let (latest, oldest) = self.checkpoints.split_at(self.next_index as usize);
+        oldest.iter().chain(latest.iter())
+    }
+
+    fn latest_mut(&mut self) -> Option<&mut Checkpoint> {
+        if self.checkpoints.is_empty() {
+            return None;
+        }
+
+        let index = if self.next_index == 0 {
+            self.checkpoints.len() - 1
+        } else {
+            self.next_index as usize - 1
+        };
+
+        self.checkpoints.get_mut(index)
+    }
+}
+
+/// Returns VoterWeightCheckpoints PDA seeds
+pub fn get_voter_weight_checkpoints_seeds<'a>(
+    registrar: &'a Pubkey,
+    voter_authority: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        registrar.as_ref(),
+        b"voter-weight-checkpoints",
+        voter_authority.as_ref(),
+    ]
+}
+
+/// Returns VoterWeightCheckpoints PDA address
+pub fn get_voter_weight_checkpoints_address(
+    registrar: &Pubkey,
+    voter_authority: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_voter_weight_checkpoints_seeds(registrar, voter_authority),
+        &crate::id(),
+    )
+    .0
+}
+
+/// Records the voter weight as a checkpoint if the voter created VoterWeightCheckpoints
+///
+/// The account must be passed at its PDA address by every instruction which changes the voter weight
+/// so the history can't be skipped, and it's ignored if the voter hasn't created it
+pub fn record_voter_weight_checkpoint(
+    voter_weight_checkpoints_info: &AccountInfo,
+    slot: u64,
+    weight: u64,
+) -> Result<()> {
+    if voter_weight_checkpoints_info.owner != &crate::id()
+        || voter_weight_checkpoints_info.data_is_empty()
+    {
+        return Ok(());
+    }
+
+    let mut data = voter_weight_checkpoints_info.try_borrow_mut_data()?;
+    let mut voter_weight_checkpoints = VoterWeightCheckpoints::try_deserialize(&mut &data[..])?;
+
+    voter_weight_checkpoints.record(slot, weight);
+    voter_weight_checkpoints.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn new_checkpoints(capacity: u16) -> VoterWeightCheckpoints {
+        VoterWeightCheckpoints {
+            voter: Pubkey::default(),
+            capacity,
+            next_index: 0,
+            checkpoints: vec![],
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = VoterWeightCheckpoints::get_space(3);
+
+        let mut checkpoints = new_checkpoints(3);
+        for slot in 1..=3 {
+            checkpoints.record(slot, slot * 10);
+        }
+
+        // Act
+        let actual_space =
+            VoterWeightCheckpoints::DISCRIMINATOR.len() + checkpoints.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_weight_at() {
+        // Arrange
+        let mut checkpoints = new_checkpoints(5);
+        checkpoints.record(10, 100);
+        checkpoints.record(20, 200);
+        checkpoints.record(30, 50);
+
+        // Act & Assert
+        assert!(checkpoints.weight_at(9).is_err());
+        assert_eq!(checkpoints.weight_at(10).unwrap(), 100);
+        assert_eq!(checkpoints.weight_at(25).unwrap(), 200);
+        assert_eq!(checkpoints.weight_at(1_000).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_weight_at_with_configs_updated_slot() {
+        // Arrange
+        let mut checkpoints = new_checkpoints(5);
+        checkpoints.record(10, 100);
+        checkpoints.record(20, 200);
+
+        // Act & Assert
+
+        // The configs were changed before the checkpoint
+        assert_eq!(
+            checkpoints
+                .weight_at_with_configs_updated_slot(15, 5)
+                .unwrap(),
+            100
+        );
+
+        // The configs were changed after the slot
+        assert_eq!(
+            checkpoints
+                .weight_at_with_configs_updated_slot(15, 16)
+                .unwrap(),
+            100
+        );
+
+        // The configs were changed between the checkpoint and the slot
+        assert_eq!(
+            checkpoints
+                .weight_at_with_configs_updated_slot(15, 12)
+                .err()
+                .unwrap(),
+            error!(TokenVoterError::CheckpointVotingMintConfigsChanged)
+        );
+
+        // The configs were changed in the same slot as the checkpoint
+        assert_eq!(
+            checkpoints
+                .weight_at_with_configs_updated_slot(25, 20)
+                .err()
+                .unwrap(),
+            error!(TokenVoterError::CheckpointVotingMintConfigsChanged)
+        );
+    }
+
+    #[test]
+    fn test_record_in_same_slot_replaces_latest() {
+        // Arrange
+        let mut checkpoints = new_checkpoints(5);
+        checkpoints.record(10, 100);
+
+        // Act
+        checkpoints.record(10, 150);
+
+        // Assert
+        assert_eq!(checkpoints.checkpoints.len(), 1);
+        assert_eq!(checkpoints.weight_at(10).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_record_overwrites_oldest_when_full() {
+        // Arrange
+        let mut checkpoints = new_checkpoints(3);
+
+        // Act
+        for slot in 1..=5 {
+            checkpoints.record(slot * 10, slot * 100);
+        }
+
+        // Assert
+        assert_eq!(checkpoints.checkpoints.len(), 3);
+        assert_eq!(checkpoints.next_index, 2);
+        assert!(checkpoints.weight_at(25).is_err());
+        assert_eq!(checkpoints.weight_at(30).unwrap(), 300);
+        assert_eq!(checkpoints.weight_at(45).unwrap(), 400);
+        assert_eq!(checkpoints.weight_at(50).unwrap(), 500);
+
+        // A replaced latest checkpoint wraps around the buffer
+        checkpoints.record(50, 550);
+        assert_eq!(checkpoints.checkpoints.len(), 3);
+        assert_eq!(checkpoints.weight_at(50).unwrap(), 550);
+    }
+}
@@ -0,0 +1,472 @@
+use gpl_token_voter::error::TokenVoterError;
+use gpl_token_voter::state::VoterWeightAction;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_delegate_and_undelegate() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let third_user_cookie = token_voter_test.users.get(2).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let second_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, third_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+    let second_token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &second_user_cookie)
+        .await?;
+    let delegate_token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &third_user_cookie)
+        .await?;
+
+    for (voter_cookie, user_cookie, token_owner_record_cookie) in [
+        (&voter_cookie, first_user_cookie, &token_owner_record_cookie),
+        (
+            &second_voter_cookie,
+            second_user_cookie,
+            &second_token_owner_record_cookie,
+        ),
+    ] {
+        token_voter_test
+            .deposit_entry(
+                &registrar_cookie,
+                voter_cookie,
+                user_cookie,
+                token_owner_record_cookie,
+                &first_mint_cookie,
+                &spl_token_2022::id(),
+                0,
+                10,
+                None,
+            )
+            .await?;
+    }
+
+    // Act
+    let delegation = token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await?;
+    token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &second_voter_cookie,
+            second_user_cookie,
+            &second_token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            10,
+        )
+        .await?;
+
+    // Assert
+    let delegation_account = token_voter_test.get_delegation(&delegation).await;
+    assert_eq!(delegation_account.amount_native, 4);
+    assert_eq!(delegation_account.delegate, delegate_voter_cookie.authority);
+    assert_eq!(delegation_account.mint, first_mint_cookie.address);
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 6);
+
+    let second_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&second_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(second_voter_weight_record.voter_weight, 0);
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, 14);
+
+    // Act
+    token_voter_test
+        .undelegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &delegate_token_owner_record_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, 10);
+
+    // Delegation is closed once all its amount is undelegated
+    assert!(token_voter_test
+        .bench
+        .get_account(&delegation)
+        .await
+        .is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delegate_with_insufficient_undelegated_weight_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            8,
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            3,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InsufficientUndelegatedWeight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_undelegate_with_delegate_votes_not_relinquished_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+    let delegate_token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &second_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await?;
+
+    let proposal_cookie = token_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    token_voter_test
+        .governance
+        .cast_vote(
+            &realm_cookie,
+            &proposal_cookie,
+            &delegate_voter_cookie,
+            &second_user_cookie.key,
+            &second_user_cookie.key,
+            &max_voter_weight_record_cookie.address,
+            &delegate_token_owner_record_cookie,
+        )
+        .await?;
+
+    // Act
+
+    // The delegate's vote keeps the delegated weight and undelegating it would allow
+    // the same deposit to be delegated to another voter and vote again on the proposal
+    let err = token_voter_test
+        .undelegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &delegate_token_owner_record_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::DelegateVotesNotRelinquished);
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delegate_with_digit_shift_change() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            1,
+        )
+        .await?;
+
+    // Act
+    for voter_cookie in [&voter_cookie, &delegate_voter_cookie] {
+        token_voter_test
+            .update_voter_weight_record(
+                &registrar_cookie,
+                voter_cookie,
+                VoterWeightAction::CastVote,
+                None,
+            )
+            .await?;
+    }
+
+    // Assert
+    // The delegated amount is weighted with the current digit shift for both voters
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 60);
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, 40);
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use gpl_token_voter::error::TokenVoterError;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_migrate_voter() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let source_realm_cookie = token_voter_test.governance.with_realm().await?;
+    let target_realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let source_registrar_cookie = token_voter_test
+        .with_registrar(&source_realm_cookie)
+        .await?;
+    let target_registrar_cookie = token_voter_test
+        .with_registrar(&target_realm_cookie)
+        .await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+    let second_mint_cookie = token_voter_test.mints.get(1).unwrap();
+
+    let source_max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&source_registrar_cookie)
+        .await?;
+    let target_max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&target_registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &source_registrar_cookie,
+            &governance_program_cookie,
+            &source_max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // The mint is configured at a different index in the target Registrar
+    token_voter_test
+        .configure_mint_config(
+            &target_registrar_cookie,
+            &governance_program_cookie,
+            &target_max_voter_weight_record_cookie,
+            second_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+    token_voter_test
+        .configure_mint_config(
+            &target_registrar_cookie,
+            &governance_program_cookie,
+            &target_max_voter_weight_record_cookie,
+            first_mint_cookie,
+            1, // digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&source_registrar_cookie, first_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&source_realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &source_registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    let target_voter_cookie = token_voter_test
+        .migrate_voter(
+            &source_registrar_cookie,
+            &target_registrar_cookie,
+            &voter_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+        )
+        .await?;
+
+    // Assert
+    let source_voter = token_voter_test.get_voter(&voter_cookie.address).await;
+    assert_eq!(source_voter.deposits[0].amount_deposited_native, 0);
+    assert!(!source_voter.deposits[0].is_used);
+
+    let target_voter = token_voter_test
+        .get_voter(&target_voter_cookie.address)
+        .await;
+    assert_eq!(target_voter.registrar, target_registrar_cookie.address);
+    assert_eq!(target_voter.voter_authority, voter_cookie.authority);
+    assert_eq!(
+        target_voter.deposits[1].amount_deposited_native,
+        amount_deposited
+    );
+    assert_eq!(target_voter.deposits[1].voting_mint_config_idx, 1);
+    assert!(target_voter.deposits[1].is_used);
+
+    let source_vault_balance = token_voter_test
+        .vault_balance(&voter_cookie, first_mint_cookie, &spl_token_2022::id())
+        .await;
+    assert_eq!(source_vault_balance, 0);
+
+    let target_vault_balance = token_voter_test
+        .vault_balance(
+            &target_voter_cookie,
+            first_mint_cookie,
+            &spl_token_2022::id(),
+        )
+        .await;
+    assert_eq!(target_vault_balance, amount_deposited);
+
+    let source_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(source_voter_weight_record.voter_weight, 0);
+
+    let target_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&target_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(target_voter_weight_record.voter_weight, 100);
+    assert_eq!(
+        target_voter_weight_record.realm,
+        target_realm_cookie.address
+    );
+    assert_eq!(
+        target_voter_weight_record.governing_token_owner,
+        voter_cookie.authority
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_voter_with_invalid_target_realm_authority_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let source_realm_cookie = token_voter_test.governance.with_realm().await?;
+    let target_realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let source_registrar_cookie = token_voter_test
+        .with_registrar(&source_realm_cookie)
+        .await?;
+    let target_registrar_cookie = token_voter_test
+        .with_registrar(&target_realm_cookie)
+        .await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    for registrar_cookie in [&source_registrar_cookie, &target_registrar_cookie] {
+        let max_voter_weight_record_cookie = token_voter_test
+            .with_max_voter_weight_record(registrar_cookie)
+            .await?;
+        token_voter_test
+            .configure_mint_config(
+                registrar_cookie,
+                &governance_program_cookie,
+                &max_voter_weight_record_cookie,
+                first_mint_cookie,
+                0, // no digit shift
+            )
+            .await?;
+    }
+
+    let voter_cookie = token_voter_test
+        .with_voter(&source_registrar_cookie, first_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&source_realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &source_registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let target_realm_authority = Keypair::new();
+
+    // Act
+    let err = token_voter_test
+        .migrate_voter_using_ix(
+            &source_registrar_cookie,
+            &target_registrar_cookie,
+            &voter_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            |i| i.accounts[5].pubkey = target_realm_authority.pubkey(),
+            Some(&[
+                &source_registrar_cookie.realm_authority,
+                &target_realm_authority,
+            ]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_voter_to_same_registrar_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .migrate_voter_using_ix(
+            &registrar_cookie,
+            &registrar_cookie,
+            &voter_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            NopOverride,
+            Some(&[&registrar_cookie.realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::MigrationToSameRegistrar);
+
+    Ok(())
+}
@@ -240,7 +240,8 @@ impl TokenVoterTest {
             governing_token_mint: realm_cookie.account.community_mint,
             voting_mint_configs: vec![],
             max_mints,
-            reserved: [0; 127],
+            voting_mint_configs_updated_slot: 0,
+            reserved: [0; 119],
         };
         Ok(RegistrarCookie {
             address: registrar_key,
@@ -311,7 +312,8 @@ impl TokenVoterTest {
             governing_token_mint: realm_cookie.account.community_mint,
             voting_mint_configs: vec![],
             max_mints,
-            reserved: [0; 127],
+            voting_mint_configs_updated_slot: 0,
+            reserved: [0; 119],
         };
         Ok(RegistrarCookie {
             address: registrar_key,
@@ -596,6 +598,10 @@ impl TokenVoterTest {
             registrar: registrar_cookie.address,
             voter: voter_cookie.address,
             voter_weight_record: voter_cookie.voter_weight_record,
+            voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &user_cookie.key.pubkey(),
+            ),
             vault,
             deposit_token,
             deposit_authority: user_cookie.key.pubkey(),
@@ -696,6 +702,10 @@ impl TokenVoterTest {
             registrar: registrar_cookie.address,
             voter: voter_cookie.address,
             voter_weight_record: voter_cookie.voter_weight_record,
+            voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &user_cookie.key.pubkey(),
+            ),
             vault,
             destination: destination_ata,
             voter_authority: user_cookie.key.pubkey(),
@@ -811,6 +821,376 @@ impl TokenVoterTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_checkpoints(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        capacity: u16,
+    ) -> Result<Pubkey, BanksClientError> {
+        let voter_weight_checkpoints_key = get_voter_weight_checkpoints_address(
+            &registrar_cookie.address,
+            &user_cookie.key.pubkey(),
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_voter::instruction::CreateVoterWeightCheckpoints { capacity },
+        );
+
+        let accounts = gpl_token_voter::accounts::CreateVoterWeightCheckpoints {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_checkpoints: voter_weight_checkpoints_key,
+            voter_authority: user_cookie.key.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let create_voter_weight_checkpoints_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[create_voter_weight_checkpoints_ix],
+                Some(&[&user_cookie.key]),
+            )
+            .await?;
+
+        Ok(voter_weight_checkpoints_key)
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_at_slot(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        voter_weight_checkpoints: &Pubkey,
+        slot: u64,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_voter::instruction::GetVoterWeightAtSlot { slot },
+        );
+
+        let accounts = gpl_token_voter::accounts::GetVoterWeightAtSlot {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_checkpoints: *voter_weight_checkpoints,
+        };
+
+        let get_voter_weight_at_slot_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[get_voter_weight_at_slot_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_voter(
+        &self,
+        source_registrar_cookie: &RegistrarCookie,
+        target_registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        mint_cookie: &MintCookie,
+        token_program: &Pubkey,
+        deposit_entry_index: u8,
+    ) -> Result<VoterCookie, BanksClientError> {
+        self.migrate_voter_using_ix(
+            source_registrar_cookie,
+            target_registrar_cookie,
+            voter_cookie,
+            token_owner_record_cookie,
+            mint_cookie,
+            token_program,
+            deposit_entry_index,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_voter_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        source_registrar_cookie: &RegistrarCookie,
+        target_registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        mint_cookie: &MintCookie,
+        token_program: &Pubkey,
+        deposit_entry_index: u8,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<VoterCookie, BanksClientError> {
+        let (target_voter_key, _) = Pubkey::find_program_address(
+            &[
+                &target_registrar_cookie.address.to_bytes(),
+                b"voter".as_ref(),
+                &voter_cookie.authority.to_bytes(),
+            ],
+            &gpl_token_voter::id(),
+        );
+        let (target_voter_weight_record_key, _) = Pubkey::find_program_address(
+            &[
+                &target_registrar_cookie.address.to_bytes(),
+                b"voter-weight-record".as_ref(),
+                &voter_cookie.authority.to_bytes(),
+            ],
+            &gpl_token_voter::id(),
+        );
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_voter::instruction::MigrateVoter {
+                deposit_entry_index,
+            });
+
+        let accounts = gpl_token_voter::accounts::MigrateVoter {
+            source_registrar: source_registrar_cookie.address,
+            source_realm: source_registrar_cookie.account.realm,
+            source_realm_authority: source_registrar_cookie.realm_authority.pubkey(),
+            target_registrar: target_registrar_cookie.address,
+            target_realm: target_registrar_cookie.account.realm,
+            target_realm_authority: target_registrar_cookie.realm_authority.pubkey(),
+            source_voter: voter_cookie.address,
+            source_voter_weight_record: voter_cookie.voter_weight_record,
+            source_voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &source_registrar_cookie.address,
+                &voter_cookie.authority,
+            ),
+            source_token_owner_record: token_owner_record_cookie.address,
+            target_voter: target_voter_key,
+            target_voter_weight_record: target_voter_weight_record_key,
+            target_voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &target_registrar_cookie.address,
+                &voter_cookie.authority,
+            ),
+            voter_authority: voter_cookie.authority,
+            mint: mint_cookie.address,
+            source_vault: associated_token::get_associated_token_address_with_program_id(
+                &voter_cookie.address,
+                &mint_cookie.address,
+                token_program,
+            ),
+            target_vault: associated_token::get_associated_token_address_with_program_id(
+                &target_voter_key,
+                &mint_cookie.address,
+                token_program,
+            ),
+            payer: self.bench.payer.pubkey(),
+            token_program: *token_program,
+            system_program: System::id(),
+            associated_token_program: AssociatedToken::id(),
+        };
+
+        let mut migrate_voter_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut migrate_voter_ix);
+
+        let default_signers = &[
+            &source_registrar_cookie.realm_authority,
+            &target_registrar_cookie.realm_authority,
+        ];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[migrate_voter_ix], Some(signers))
+            .await?;
+
+        let account = VoterWeightRecord::new(
+            target_registrar_cookie.account.realm,
+            target_registrar_cookie.account.governing_token_mint,
+            voter_cookie.authority,
+            0,
+            None,
+            None,
+            None,
+        );
+        let voter_weight_record_cookie = VoterWeightRecordCookie {
+            address: target_voter_weight_record_key,
+            account,
+        };
+
+        Ok(VoterCookie {
+            address: target_voter_key,
+            authority: voter_cookie.authority,
+            voter_weight_record: target_voter_weight_record_key,
+            voter_weight_record_cookie,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn delegate(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        delegate_voter_cookie: &VoterCookie,
+        mint_cookie: &MintCookie,
+        amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let delegation_key = get_delegation_address(
+            &registrar_cookie.address,
+            &user_cookie.key.pubkey(),
+            &delegate_voter_cookie.authority,
+            &mint_cookie.address,
+        );
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_voter::instruction::Delegate { amount });
+
+        let accounts = gpl_token_voter::accounts::Delegate {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_record: voter_cookie.voter_weight_record,
+            voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &user_cookie.key.pubkey(),
+            ),
+            token_owner_record: token_owner_record_cookie.address,
+            delegate_voter: delegate_voter_cookie.address,
+            delegate_voter_weight_record: delegate_voter_cookie.voter_weight_record,
+            delegate_voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &delegate_voter_cookie.authority,
+            ),
+            mint: mint_cookie.address,
+            delegation: delegation_key,
+            voter_authority: user_cookie.key.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let delegate_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[delegate_ix], Some(&[&user_cookie.key]))
+            .await?;
+
+        Ok(delegation_key)
+    }
+
+    #[allow(dead_code)]
+    pub async fn undelegate(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        delegate_voter_cookie: &VoterCookie,
+        delegate_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        mint_cookie: &MintCookie,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let delegation_key = get_delegation_address(
+            &registrar_cookie.address,
+            &user_cookie.key.pubkey(),
+            &delegate_voter_cookie.authority,
+            &mint_cookie.address,
+        );
+
+        let data = anchor_lang::InstructionData::data(&gpl_token_voter::instruction::Undelegate {
+            amount,
+        });
+
+        let accounts = gpl_token_voter::accounts::Undelegate {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_record: voter_cookie.voter_weight_record,
+            voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &user_cookie.key.pubkey(),
+            ),
+            delegate_voter: delegate_voter_cookie.address,
+            delegate_voter_weight_record: delegate_voter_cookie.voter_weight_record,
+            delegate_voter_weight_checkpoints: get_voter_weight_checkpoints_address(
+                &registrar_cookie.address,
+                &delegate_voter_cookie.authority,
+            ),
+            token_owner_record: token_owner_record_cookie.address,
+            delegate_token_owner_record: delegate_token_owner_record_cookie.address,
+            mint: mint_cookie.address,
+            delegation: delegation_key,
+            voter_authority: user_cookie.key.pubkey(),
+        };
+
+        let undelegate_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[undelegate_ix], Some(&[&user_cookie.key]))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        action: VoterWeightAction,
+        target: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_record_using_ix(
+            registrar_cookie,
+            voter_cookie,
+            action,
+            target,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        action: VoterWeightAction,
+        target: Option<Pubkey>,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_voter::instruction::UpdateVoterWeightRecord { action, target },
+        );
+
+        let accounts = gpl_token_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_record: voter_cookie.voter_weight_record,
+        };
+
+        let mut update_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut update_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[update_voter_weight_record_ix], None)
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn get_registrar_account(&self, registrar: &Pubkey) -> Registrar {
         self.bench.get_anchor_account::<Registrar>(*registrar).await
@@ -831,6 +1211,21 @@ impl TokenVoterTest {
         self.bench.get_anchor_account(*voter_weight_record).await
     }
 
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_checkpoints(
+        &self,
+        voter_weight_checkpoints: &Pubkey,
+    ) -> VoterWeightCheckpoints {
+        self.bench
+            .get_anchor_account(*voter_weight_checkpoints)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_delegation(&self, delegation: &Pubkey) -> Delegation {
+        self.bench.get_anchor_account(*delegation).await
+    }
+
     #[allow(dead_code)]
     pub async fn get_voter(&self, voter: &Pubkey) -> Voter {
         self.bench.get_anchor_account(*voter).await
@@ -0,0 +1,129 @@
+use gpl_token_voter::state::VoterWeightAction;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            1, // digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    let proposal = Pubkey::new_unique();
+
+    // Act
+    token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_cookie,
+            VoterWeightAction::CastVote,
+            Some(proposal),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+    assert_eq!(
+        voter_weight_record.voter_weight_expiry,
+        Some(token_voter_test.bench.get_clock().await.slot)
+    );
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(spl_governance_addin_api::voter_weight::VoterWeightAction::CastVote)
+    );
+    assert_eq!(voter_weight_record.weight_action_target, Some(proposal));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_invalid_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let voter_cookie2 = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .update_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            None,
+            |i| i.accounts[2].pubkey = voter_cookie2.voter_weight_record,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    Ok(())
+}
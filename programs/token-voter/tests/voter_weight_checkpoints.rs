@@ -0,0 +1,475 @@
+use gpl_token_voter::error::TokenVoterError;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_voter_weight_checkpoints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let voter_weight_checkpoints = token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 10)
+        .await?;
+
+    let created_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let deposited_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    token_voter_test.bench.advance_clock().await;
+
+    token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            4,
+            None,
+        )
+        .await?;
+
+    let withdrawn_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    // Assert
+    let voter_weight_checkpoints_account = token_voter_test
+        .get_voter_weight_checkpoints(&voter_weight_checkpoints)
+        .await;
+
+    assert_eq!(voter_weight_checkpoints_account.voter, voter_cookie.address);
+    assert_eq!(voter_weight_checkpoints_account.checkpoints.len(), 3);
+    assert_eq!(
+        voter_weight_checkpoints_account
+            .weight_at(created_at_slot)
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        voter_weight_checkpoints_account
+            .weight_at(deposited_at_slot)
+            .unwrap(),
+        10
+    );
+    assert_eq!(
+        voter_weight_checkpoints_account
+            .weight_at(withdrawn_at_slot)
+            .unwrap(),
+        6
+    );
+
+    token_voter_test
+        .get_voter_weight_at_slot(
+            &registrar_cookie,
+            &voter_cookie,
+            &voter_weight_checkpoints,
+            deposited_at_slot,
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_voter_weight_at_slot_before_first_checkpoint_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    let voter_weight_checkpoints = token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 10)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .get_voter_weight_at_slot(
+            &registrar_cookie,
+            &voter_cookie,
+            &voter_weight_checkpoints,
+            0,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::CheckpointNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_voter_weight_checkpoints_with_zero_capacity_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidCheckpointsCapacity);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_without_voter_weight_checkpoints_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 10)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .deposit_entry_using_ix(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &first_mint_cookie,
+            &token_owner_record_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            |i| i.accounts[4].pubkey = Pubkey::new_unique(), // voter_weight_checkpoints
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delegate_records_voter_weight_checkpoints() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let voter_weight_checkpoints = token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 10)
+        .await?;
+    let delegate_voter_weight_checkpoints = token_voter_test
+        .with_voter_weight_checkpoints(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            second_user_cookie,
+            10,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    token_voter_test
+        .delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate_voter_cookie,
+            &first_mint_cookie,
+            4,
+        )
+        .await?;
+
+    let delegated_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    // Assert
+    let voter_weight_checkpoints_account = token_voter_test
+        .get_voter_weight_checkpoints(&voter_weight_checkpoints)
+        .await;
+    assert_eq!(
+        voter_weight_checkpoints_account
+            .weight_at(delegated_at_slot)
+            .unwrap(),
+        6
+    );
+
+    let delegate_voter_weight_checkpoints_account = token_voter_test
+        .get_voter_weight_checkpoints(&delegate_voter_weight_checkpoints)
+        .await;
+    assert_eq!(
+        delegate_voter_weight_checkpoints_account
+            .weight_at(delegated_at_slot)
+            .unwrap(),
+        4
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_voter_weight_at_slot_with_voting_mint_config_changed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new_token_extensions(None).await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let voter_weight_checkpoints = token_voter_test
+        .with_voter_weight_checkpoints(&registrar_cookie, &voter_cookie, first_user_cookie, 10)
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token_2022::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let deposited_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // The weight of the deposit changes without a checkpoint
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            1, // digit shift
+        )
+        .await?;
+
+    let configured_at_slot = token_voter_test.bench.get_clock().await.slot;
+
+    // Act
+    let err = token_voter_test
+        .get_voter_weight_at_slot(
+            &registrar_cookie,
+            &voter_cookie,
+            &voter_weight_checkpoints,
+            configured_at_slot,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::CheckpointVotingMintConfigsChanged);
+
+    // Lookups before the change are still valid
+    token_voter_test
+        .get_voter_weight_at_slot(
+            &registrar_cookie,
+            &voter_cookie,
+            &voter_weight_checkpoints,
+            deposited_at_slot,
+        )
+        .await?;
+
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.voting_mint_configs_updated_slot,
+        configured_at_slot
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::program_test::program_test_bench::MintType;
 use anchor_spl::associated_token;
 use gpl_token_voter::error::TokenVoterError;
+use gpl_token_voter::state::VoterWeightAction;
 use program_test::token_voter_test::TokenVoterTest;
 use program_test::tools::*;
 use solana_program_test::*;
@@ -533,3 +534,92 @@ async fn test_withdraw_multi_deposit_and_withdraw() -> Result<(), TransportError
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_withdraw_resets_voter_weight_action() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_cookie,
+            VoterWeightAction::CastVote,
+            Some(Pubkey::new_unique()),
+        )
+        .await?;
+
+    // Act
+    token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            5,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 5);
+    assert_eq!(voter_weight_record.voter_weight_expiry, None);
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}